@@ -5,476 +5,923 @@ mod reporte {
     use sistema_votacion::{Eleccion, ErrorSistema, Fecha, SistemaVotacion, SistemaVotacionRef, Usuario};
     use ink::{prelude::string::String};
     use sistema_votacion::CandidatoVotos;
+    use sistema_votacion::ListaVotos;
+    use sistema_votacion::Lista;
+    use sistema_votacion::EstadoCandidato;
     use ink::prelude::vec::Vec; // Importa Vec // Importa la macro vec!
     use ink::prelude::vec;
     use ink::prelude::borrow::ToOwned;
+    use ink::storage::Mapping;
+    use core::cmp::Reverse;
+
+    // Cantidad maxima de intervalos de un reporte de participacion temporal, un mes por hora
+    const MAX_INTERVALOS_PARTICIPACION: u64 = 744;
     trait Funciones{
         fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema>;
         fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion>;
+        fn get_admin_id(&self) -> AccountId;
     }
-    //-------------------------------- A -----------------------------------
+    // Sistema de votacion de prueba, cada test lo arma con las elecciones finalizadas que necesita
     #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
     #[cfg_attr(
         feature = "std",
         derive(ink::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
     )]
-    pub struct SistemaVotacionFakeA;
+    pub struct SistemaVotacionFake {
+        elecciones: Vec<Eleccion>,
+        admin: AccountId,
+    }
 
-    impl Funciones for SistemaVotacionFakeA{ //Caso de reporte de votantes aprobados sin votantes aprobados
+    impl Funciones for SistemaVotacionFake{
         fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema>{
-            Ok(Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1)))
+            self.elecciones.iter().find(|eleccion| eleccion.get_id() == id).cloned().ok_or(ErrorSistema::EleccionInvalida)
         }
 
         fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion>{
-            Vec::new()
-        } 
-    }
+            self.elecciones.clone()
+        }
 
-    impl SistemaVotacionFakeA{
-        pub fn new() -> Self{
-            Self{}
+        fn get_admin_id(&self) -> AccountId{
+            self.admin
         }
+    }
 
+    impl SistemaVotacionFake{
+        // El admin es alice, la cuenta que invoca por defecto en los tests
+        pub fn new(elecciones: Vec<Eleccion>) -> Self{
+            Self{ elecciones, admin: AccountId::from([0x1; 32]) }
+        }
     }
-    //-----------------------------------------------------------------------
-    //---------------------------------- B ----------------------------------
-    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
-    #[cfg_attr(
-        feature = "std",
-        derive(ink::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
-    )]
-    pub struct SistemaVotacionFakeB;
 
-    impl Funciones for SistemaVotacionFakeB{ //Caso de reporte de votantes aprobados con votantes aprobados
+//-------------------------------------------------------------------------------------------------------------------------
+    // Fuera de los tests el contrato consulta a los sistemas de votacion reales
+    #[cfg(not(test))]
+    type Sistema = SistemaVotacionRef;
+
+    #[cfg(test)]
+    type Sistema = SistemaVotacionFake;
+
+    impl Funciones for SistemaVotacionRef{
         fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema>{
-            let mut elec = Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1));
-            elec.set_votantes_registrados(vec![Usuario::new(AccountId::from([0x1; 32]), "Pepe".to_owned(), "111".to_owned()), Usuario::new(AccountId::from([0x2; 32]), "Juan".to_owned(), "222".to_owned())]);
-            elec.set_votantes_aprobados(vec![Usuario::new(AccountId::from([0x3; 32]), "Lucas".to_owned(), "333".to_owned())]);
-            Ok(elec)
+            self.get_elecciones_terminadas_especifica(id)
         }
 
         fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion>{
-            Vec::new()
-        } 
-    }
+            self.get_elecciones_finiquitadas()
+        }
 
-    impl SistemaVotacionFakeB{
-        pub fn new() -> Self{
-            Self{}
+        fn get_admin_id(&self) -> AccountId{
+            self.get_admin_id()
         }
     }
-    //------------------------------------------------------------------------
-    //---------------------------------- C -----------------------------------
-    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
-    #[cfg_attr(
-        feature = "std",
-        derive(ink::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
-    )]
-    pub struct SistemaVotacionFakeC;
 
-    impl Funciones for SistemaVotacionFakeC{ //Caso de reporte de votantes aprobados error
-        fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema>{
-            Err(ErrorSistema::EleccionInvalida)
+    #[ink(storage)]
+    pub struct Reporte {
+        owner: AccountId, // Quien desplego el contrato, es el unico que administra los sistemas registrados
+        sistemas: Vec<InstanciaSistema>, // Un sistema de votacion por organizacion, identificado por su nombre
+        reportes_restringidos: bool, // Si es true, solo el owner y los lectores autorizados ven los reportes con datos de votantes
+        lectores: Vec<AccountId>,
+        instantaneas: Mapping<(AccountId, u64), InstantaneaEleccion>, // Reportes ya calculados de elecciones finalizadas, por contrato del sistema y ID de eleccion
+    }
+
+    impl Reporte {
+        /// PERMITE INICIALIZAR EL CONTRATO CON UNA REFERENCIA AL SISTEMA DE VOTACIÓN
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el `nombre` con el que se identifica al sistema y un parámetro `sistema` que es una referencia al sistema de votación,
+        /// y retorna una instancia del contrato `Reporte`.
+        /// 
+        /// # Funcionalidad
+        /// 
+        /// Inicializa el contrato `Reporte` con el sistema de votación proporcionado como primer sistema registrado.
+        /// Quien despliega el contrato queda como su owner, y es el único que puede registrar, reemplazar o quitar sistemas.
+        /// Los reportes con datos de votantes comienzan sin restricciones.
+        /// 
+        /// # Errores
+        /// 
+        /// No se esperan errores en la inicialización.
+        #[cfg(not(test))]
+        #[ink(constructor)]
+        pub fn new(nombre: String, sistema: SistemaVotacionRef) -> Self {
+            Self {
+                owner: Self::env().caller(),
+                sistemas: vec![InstanciaSistema::new(nombre, sistema)],
+                reportes_restringidos: false,
+                lectores: Vec::new(),
+                instantaneas: Mapping::default(),
+            }
         }
 
-        fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion>{
-            Vec::new()
-        } 
-    }
+        #[cfg(test)]
+        fn new_fake(nombre: String, sistema: SistemaVotacionFake) -> Self{
+            Self{
+                owner: Self::env().caller(),
+                sistemas: vec![InstanciaSistema::new(nombre, sistema)],
+                reportes_restringidos: false,
+                lectores: Vec::new(),
+                instantaneas: Mapping::default(),
+            }
+        }
 
-    impl SistemaVotacionFakeC{
-        pub fn new() -> Self{
-            Self{}
+        /// PERMITE AL OWNER REGISTRAR UN NUEVO SISTEMA DE VOTACIÓN
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el `nombre` con el que se identifica al sistema y una referencia al sistema de votación.
+        /// 
+        /// # Funcionalidad
+        /// 
+        /// El sistema queda registrado bajo ese nombre, que es el que se indica al pedir cualquiera de sus reportes.
+        /// 
+        /// # Errores
+        /// 
+        /// La función retorna un error si quien la invoca no es el owner o si ya hay un sistema registrado con ese nombre.
+        #[cfg(not(test))]
+        #[ink(message)]
+        pub fn registrar_sistema_msg(&mut self, nombre: String, sistema: SistemaVotacionRef) -> Result<(), ErrorSistema>{
+            self.registrar_sistema(nombre, sistema)
         }
-    }
-    //---------------------------------------------------------------------
-    //-------------------------------- D ----------------------------------
-    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
-    #[cfg_attr(
-        feature = "std",
-        derive(ink::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
-    )]
-    pub struct SistemaVotacionFakeD;
 
-    impl Funciones for SistemaVotacionFakeD{ //Caso de reporte de participacion retorna informe
-        fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema>{
-            Ok(Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1)))
+        fn registrar_sistema(&mut self, nombre: String, sistema: Sistema) -> Result<(), ErrorSistema> {
+            self.validar_owner()?;
+            if self.sistemas.iter().any(|registrado| registrado.nombre == nombre) {
+                return Err(ErrorSistema::SistemaYaRegistrado);
+            }
+            self.sistemas.push(InstanciaSistema::new(nombre, sistema));
+            Ok(())
         }
 
-        fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion>{
-            let mut elec = Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1));
-            let usuarios = vec![
-                Usuario::new(AccountId::from([0x1; 32]), "Pepe".to_owned(), "111".to_owned()), 
-                Usuario::new(AccountId::from([0x2; 32]), "Juan".to_owned(), "222".to_owned()), 
-                Usuario::new(AccountId::from([0x3; 32]), "Lucia".to_owned(), "333".to_owned()), 
-                Usuario::new(AccountId::from([0x4; 32]), "Franco".to_owned(), "444".to_owned())
-            ];
-            elec.set_votantes_aprobados(usuarios);
+        /// PERMITE AL OWNER REEMPLAZAR LA REFERENCIA DE UN SISTEMA DE VOTACIÓN REGISTRADO
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el `nombre` del sistema registrado y la referencia al sistema de votación que pasa a consultarse.
+        /// 
+        /// # Funcionalidad
+        /// 
+        /// Permite apuntar el nombre a un nuevo contrato, por ejemplo tras actualizar el sistema de votación, sin tener que quitarlo y volver a registrarlo.
+        /// Las instantáneas guardadas del contrato anterior dejan de usarse, ya que el nuevo contrato puede repetir los IDs de elección.
+        /// 
+        /// # Errores
+        /// 
+        /// La función retorna un error si quien la invoca no es el owner o si no hay un sistema registrado con ese nombre.
+        #[cfg(not(test))]
+        #[ink(message)]
+        pub fn actualizar_sistema_msg(&mut self, nombre: String, sistema: SistemaVotacionRef) -> Result<(), ErrorSistema>{
+            self.actualizar_sistema(&nombre, sistema)
+        }
 
-            let mut votos = vec![CandidatoVotos::new("Jorge".to_owned(), "999".to_owned()), CandidatoVotos::new("Mara".to_owned(), "888".to_owned())];
-            votos[0].set_votos_recaudados(2);
-            votos[1].set_votos_recaudados(1);
+        fn actualizar_sistema(&mut self, nombre: &str, sistema: Sistema) -> Result<(), ErrorSistema> {
+            self.validar_owner()?;
+            let registrado = self.sistemas.iter_mut().find(|registrado| registrado.nombre == nombre).ok_or(ErrorSistema::SistemaNoRegistrado)?;
+            *registrado = InstanciaSistema::new(nombre.to_owned(), sistema);
+            Ok(())
+        }
 
-            elec.set_votos(votos);
+        /// PERMITE AL OWNER QUITAR UN SISTEMA DE VOTACIÓN REGISTRADO
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el `nombre` del sistema a quitar.
+        /// 
+        /// # Funcionalidad
+        /// 
+        /// El sistema deja de estar registrado. Las instantáneas de sus elecciones quedan asociadas a la cuenta de su contrato,
+        /// por lo que solo vuelven a usarse si se registra otra vez el mismo contrato.
+        /// 
+        /// # Errores
+        /// 
+        /// La función retorna un error si quien la invoca no es el owner o si no hay un sistema registrado con ese nombre.
+        #[ink(message)]
+        pub fn quitar_sistema_msg(&mut self, nombre: String) -> Result<(), ErrorSistema>{
+            self.quitar_sistema(&nombre)
+        }
 
-            vec![elec]
-        } 
-    }
+        fn quitar_sistema(&mut self, nombre: &str) -> Result<(), ErrorSistema> {
+            self.validar_owner()?;
+            let posicion = self.sistemas.iter().position(|registrado| registrado.nombre == nombre).ok_or(ErrorSistema::SistemaNoRegistrado)?;
+            self.sistemas.remove(posicion);
+            Ok(())
+        }
 
-    impl SistemaVotacionFakeD{
-        pub fn new() -> Self{
-            Self{}
+        /// PERMITE RECUPERAR LOS NOMBRES DE LOS SISTEMAS DE VOTACIÓN REGISTRADOS
+        /// 
+        /// # Uso
+        /// 
+        /// La función no recibe parámetros y retorna un `Vec<String>` con los nombres, en el orden en que se registraron los sistemas.
+        /// 
+        /// # Errores
+        /// 
+        /// No se esperan errores.
+        #[ink(message)]
+        pub fn get_sistemas_registrados_msg(&self) -> Vec<String>{
+            self.get_sistemas_registrados()
         }
-    }
-    //----------------------------------------------------------------------
-    //------------------------------ E -------------------------------------
-    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
-    #[cfg_attr(
-        feature = "std",
-        derive(ink::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
-    )]
-    pub struct SistemaVotacionFakeE;
 
-    impl Funciones for SistemaVotacionFakeE{ //Caso de reporte 
-        fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema>{
-            Ok(Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1)))
+        fn get_sistemas_registrados(&self) -> Vec<String> {
+            self.sistemas.iter().map(|registrado| registrado.nombre.clone()).collect()
         }
 
-        fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion>{
-            Vec::new()
-        } 
-    }
+        /// PERMITE AL OWNER ACTIVAR O DESACTIVAR LA RESTRICCIÓN DE LOS REPORTES CON DATOS DE VOTANTES
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe un `bool` que indica si los reportes con datos de votantes quedan restringidos.
+        /// 
+        /// # Funcionalidad
+        /// 
+        /// Mientras la restricción está activa, esos reportes solo pueden pedirlos el owner y las cuentas autorizadas como lectores.
+        /// Los lectores autorizados se conservan al desactivar la restricción.
+        /// 
+        /// # Errores
+        /// 
+        /// La función retorna un error si quien la invoca no es el owner.
+        #[ink(message)]
+        pub fn set_reportes_restringidos_msg(&mut self, restringidos: bool) -> Result<(), ErrorSistema>{
+            self.set_reportes_restringidos(restringidos)
+        }
 
-    impl SistemaVotacionFakeE{
-        pub fn new() -> Self{
-            Self{}
+        fn set_reportes_restringidos(&mut self, restringidos: bool) -> Result<(), ErrorSistema> {
+            self.validar_owner()?;
+            self.reportes_restringidos = restringidos;
+            Ok(())
         }
-    }
-    //---------------------------------------------------------------------------
-    //---------------------------------- F --------------------------------------
-    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
-    #[cfg_attr(
-        feature = "std",
-        derive(ink::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
-    )]
-    pub struct SistemaVotacionFakeF;
 
-    impl Funciones for SistemaVotacionFakeF{ //Caso de reporte de 
-        fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema>{
-            Ok(Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1)))
+        /// PERMITE AL OWNER AUTORIZAR A UNA CUENTA A VER LOS REPORTES CON DATOS DE VOTANTES
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el `AccountId` de la cuenta a autorizar.
+        /// 
+        /// # Errores
+        /// 
+        /// La función retorna un error si quien la invoca no es el owner o si la cuenta ya está autorizada.
+        #[ink(message)]
+        pub fn agregar_lector_msg(&mut self, cuenta: AccountId) -> Result<(), ErrorSistema>{
+            self.agregar_lector(cuenta)
         }
 
-        fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion>{
-            let mut elec = Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1));
+        fn agregar_lector(&mut self, cuenta: AccountId) -> Result<(), ErrorSistema> {
+            self.validar_owner()?;
+            if self.lectores.contains(&cuenta) {
+                return Err(ErrorSistema::LectorYaAutorizado);
+            }
+            self.lectores.push(cuenta);
+            Ok(())
+        }
 
-            let mut votos = vec![CandidatoVotos::new("Jorge".to_owned(), "999".to_owned()), CandidatoVotos::new("Mara".to_owned(), "888".to_owned())];
-            votos[0].set_votos_recaudados(2);
-            votos[1].set_votos_recaudados(1);
+        /// PERMITE AL OWNER QUITAR LA AUTORIZACIÓN DE UNA CUENTA PARA VER LOS REPORTES CON DATOS DE VOTANTES
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el `AccountId` de la cuenta a desautorizar.
+        /// 
+        /// # Errores
+        /// 
+        /// La función retorna un error si quien la invoca no es el owner o si la cuenta no está autorizada.
+        #[ink(message)]
+        pub fn quitar_lector_msg(&mut self, cuenta: AccountId) -> Result<(), ErrorSistema>{
+            self.quitar_lector(cuenta)
+        }
 
-            elec.set_votos(votos);
+        fn quitar_lector(&mut self, cuenta: AccountId) -> Result<(), ErrorSistema> {
+            self.validar_owner()?;
+            let posicion = self.lectores.iter().position(|lector| *lector == cuenta).ok_or(ErrorSistema::LectorNoAutorizado)?;
+            self.lectores.remove(posicion);
+            Ok(())
+        }
 
-            vec![elec]
-        } 
-    }
+        /// Verifica que quien invoca pueda ver los reportes con datos de votantes, siempre puede si no estan restringidos
+        fn validar_lector(&self) -> Result<(), ErrorSistema> {
+            let caller = Self::env().caller();
+            if self.reportes_restringidos && caller != self.owner && !self.lectores.contains(&caller) {
+                return Err(ErrorSistema::NoSePoseenPermisos);
+            }
+            Ok(())
+        }
 
-    impl SistemaVotacionFakeF{
-        pub fn new() -> Self{
-            Self{}
+        /// Verifica que quien invoca sea el owner del contrato
+        fn validar_owner(&self) -> Result<(), ErrorSistema> {
+            if Self::env().caller() != self.owner {
+                return Err(ErrorSistema::NoSePoseenPermisos);
+            }
+            Ok(())
         }
-    }
-    //------------------------------------------------------------------------
-    //---------------------------------- G -----------------------------------
-    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
-    #[cfg_attr(
-        feature = "std",
-        derive(ink::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
-    )]
-    pub struct SistemaVotacionFakeG;
 
-    impl Funciones for SistemaVotacionFakeG{ //Caso de reporte de 
-        fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema>{
-            Ok(Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1)))
+        /// Busca el sistema de votación registrado con ese nombre, retorna un error si no lo hay
+        fn buscar_instancia(&self, instancia: &str) -> Result<&InstanciaSistema, ErrorSistema> {
+            self.sistemas.iter().find(|registrado| registrado.nombre == instancia).ok_or(ErrorSistema::SistemaNoRegistrado)
         }
 
-        fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion>{
-            let mut elec = Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1));
+        fn buscar_sistema(&self, instancia: &str) -> Result<&Sistema, ErrorSistema> {
+            Ok(&self.buscar_instancia(instancia)?.sistema)
+        }
 
-            let mut votos = vec![
-                CandidatoVotos::new("Jorge".to_owned(), "999".to_owned()), 
-                CandidatoVotos::new("Mara".to_owned(), "888".to_owned()), 
-                CandidatoVotos::new("Esteban".to_owned(), "777".to_owned())
-            ];
-            votos[0].set_votos_recaudados(5);
-            votos[1].set_votos_recaudados(19);
-            votos[2].set_votos_recaudados(3);
+        /// PERMITE GUARDAR O ACTUALIZAR LA INSTANTÁNEA DE LOS REPORTES DE UNA ELECCIÓN FINALIZADA
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el nombre del sistema de votación y el ID de la elección y retorna la `InstantaneaEleccion` guardada.
+        /// 
+        /// # Funcionalidad
+        /// 
+        /// Como los resultados de una elección no cambian una vez finalizada, la función calcula el reporte de participación y el de resultados
+        /// y los guarda en el contrato, reemplazando la instantánea anterior de la elección si la había. A partir de entonces esos reportes
+        /// se responden desde la instantánea, sin volver a consultar al sistema de votación.
+        /// La instantánea también se guarda sola la primera vez que se consulta alguno de esos reportes, esta función permite volver a calcularla.
+        /// La instantánea se guarda para la cuenta del contrato del sistema, así no se confunde con las elecciones de otro contrato con el mismo ID.
+        /// 
+        /// # Errores
+        /// 
+        /// La función retorna un error si quien la invoca no es el owner del contrato, si no hay un sistema registrado con ese nombre,
+        /// o si la elección no está finalizada. En ese caso no se modifica la instantánea que pudiera existir.
+        #[ink(message)]
+        pub fn actualizar_instantanea_msg(&mut self, instancia: String, id: u64) -> Result<InstantaneaEleccion, ErrorSistema>{
+            self.actualizar_instantanea(&instancia, id)
+        }
 
-            elec.set_votos(votos);
+        fn actualizar_instantanea(&mut self, instancia: &str, id: u64) -> Result<InstantaneaEleccion, ErrorSistema> {
+            self.validar_owner()?;
+            self.guardar_instantanea(instancia, id)
+        }
 
-            vec![elec]
-        } 
-    }
+        /// Calcula los reportes de una elección finalizada y los guarda como su instantánea
+        fn guardar_instantanea(&mut self, instancia: &str, id: u64) -> Result<InstantaneaEleccion, ErrorSistema> {
+            let registrado = self.buscar_instancia(instancia)?;
+            let cuenta = registrado.cuenta;
+            let eleccion_buscada = registrado.sistema.get_elecciones_terminadas_especifica(id).map_err(|_| ErrorSistema::ResultadosNoDisponibles)?;
 
-    impl SistemaVotacionFakeG{
-        pub fn new() -> Self{
-            Self{}
+            let instantanea = InstantaneaEleccion::new(
+                id,
+                Self::calcular_participacion(&eleccion_buscada).ok(),
+                Self::calcular_resultado(&eleccion_buscada).ok(),
+            );
+            self.instantaneas.insert((cuenta, id), &instantanea);
+            Ok(instantanea)
         }
-    }
-    //-----------------------------------------------------------------------------
-    //--------------------------------------- H -----------------------------------
-    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
-    #[cfg_attr(
-        feature = "std",
-        derive(ink::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
-    )]
-    pub struct SistemaVotacionFakeH;
 
-    impl Funciones for SistemaVotacionFakeH{ //Caso de reporte de 
-        fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema>{
-            Ok(Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1)))
+        /// Busca la instantánea guardada de una elección, si la hay
+        fn buscar_instantanea(&self, instancia: &str, id: u64) -> Option<InstantaneaEleccion> {
+            let cuenta = self.buscar_instancia(instancia).ok()?.cuenta;
+            self.instantaneas.get((cuenta, id))
         }
 
-        fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion>{
-            Vec::new()
-        } 
-    }
+         /// PERMITE RECUPERAR LA LISTA DE VOTANTES REGISTRADOS Y APROBADOS PARA UNA ELECCIÓN DETERMINADA
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el nombre del sistema de votación y el ID de la elección y retorna un `Vec<Usuario>` que contiene los votantes registrados y aprobados.
+        /// 
+        /// # Funcionalidad
+        /// 
+        /// La función llama al sistema de votación para obtener los votantes registrados y aprobados para la elección con el ID proporcionado.
+        /// Como expone datos de los votantes, si los reportes están restringidos solo pueden pedirlo el owner y los lectores autorizados.
+        /// 
+        /// # Errores
+        /// 
+        /// La función puede retornar un error si el ID de la elección no es válido, o si los reportes están restringidos y quien la invoca no está autorizado.
+        #[ink(message)]
+        pub fn reporte_registrados_aprobados_msg(&self, instancia: String, id: u64) -> Result<ReporteDetalleVotante, ErrorSistema>{
+            self.reporte_registrados_aprobados(&instancia, id)
+        }
+
+        fn reporte_registrados_aprobados(&self, instancia: &str, id: u64) -> Result<ReporteDetalleVotante, ErrorSistema>{
+            self.validar_lector()?;
+            let eleccion_buscada: Eleccion = self.buscar_sistema(instancia)?.get_elecciones_terminadas_especifica(id)?;
+
+            let vec_votantes_aprobados = eleccion_buscada.get_votantes_aprobados();
+            let vec_votantes_registrados = eleccion_buscada.get_votantes_registrados();
+            // Crear el informe detallado de votantes registrados y aprobados
+            let informe_votantes = ReporteDetalleVotante::new(
+                id,
+                vec_votantes_registrados,
+                vec_votantes_aprobados,
+            );
 
-    impl SistemaVotacionFakeH{
-        pub fn new() -> Self{
-            Self{}
+            Ok(informe_votantes)
         }
-    }
-    //--------------------------------------------------------------------------------
-    //--------------------------------------- I --------------------------------------
-    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
-    #[cfg_attr(
-        feature = "std",
-        derive(ink::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
-    )]
-    pub struct SistemaVotacionFakeI;
 
-    impl Funciones for SistemaVotacionFakeI{ //Caso de reporte de 
-        fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema>{
-            Ok(Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1)))
+        /// PERMITE RECUPERAR CUÁNTOS USUARIOS LLEGARON A CADA ETAPA DE LA INSCRIPCIÓN DE UNA ELECCIÓN FINALIZADA
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el nombre del sistema de votación y el ID de la elección y retorna un `EmbudoInscripcion` con la cantidad de usuarios distintos en cada etapa.
+        /// 
+        /// # Funcionalidad
+        /// 
+        /// Para los votantes se cuentan los pendientes, los aprobados y los que votaron, y para los candidatos los pendientes y los aprobados.
+        /// Los miembros de las listas cuentan como candidatos, pendientes o aprobados según el estado de su lista.
+        /// También se calcula el porcentaje de conversión entre etapas: los aprobados sobre todos los que se inscribieron, ya sea que sigan
+        /// pendientes o hayan sido aprobados, y los que votaron sobre los aprobados. Una etapa sin usuarios tiene una conversión del 0%.
+        /// 
+        /// # Errores
+        /// 
+        /// La función retorna un error si la elección no está finalizada o en caso de overflow al calcular los porcentajes.
+        #[ink(message)]
+        pub fn reporte_embudo_inscripcion_msg(&self, instancia: String, id: u64) -> Result<EmbudoInscripcion, ErrorSistema>{
+            self.reporte_embudo_inscripcion(&instancia, id)
         }
 
-        fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion>{
-            let elec = Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1));
+        fn reporte_embudo_inscripcion(&self, instancia: &str, id: u64) -> Result<EmbudoInscripcion, ErrorSistema> {
+            let eleccion: Vec<Eleccion> = self.buscar_sistema(instancia)?.get_elecciones_finiquitadas();
+            let eleccion_buscada = eleccion.iter().find(|eleccion| eleccion.get_id() == id).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
 
-            vec![elec]
-        } 
-    }
+            let votantes_pendientes = eleccion_buscada.get_votantes_registrados();
+            let votantes_aprobados = eleccion_buscada.get_votantes_aprobados();
+            let votantes_inscriptos = Self::contar_usuarios_distintos(&[votantes_pendientes.clone(), votantes_aprobados.clone()].concat());
+            let cant_votantes_aprobados = Self::contar_usuarios_distintos(&votantes_aprobados);
+            let cant_votantes_votados = Self::contar_usuarios_distintos(&eleccion_buscada.get_votantes_votados());
 
-    impl SistemaVotacionFakeI{
-        pub fn new() -> Self{
-            Self{}
+            let candidatos_pendientes = [eleccion_buscada.get_candidatos_registrados(), Self::miembros_de_listas(&eleccion_buscada.get_peticiones_listas())].concat();
+            let candidatos_aprobados = [eleccion_buscada.get_candidatos_aprobados(), Self::miembros_de_listas(&eleccion_buscada.get_listas_aprobadas())].concat();
+            let candidatos_inscriptos = Self::contar_usuarios_distintos(&[candidatos_pendientes.clone(), candidatos_aprobados.clone()].concat());
+            let cant_candidatos_aprobados = Self::contar_usuarios_distintos(&candidatos_aprobados);
+
+            Ok(EmbudoInscripcion::new(
+                id,
+                Self::contar_usuarios_distintos(&votantes_pendientes),
+                cant_votantes_aprobados,
+                cant_votantes_votados,
+                Self::contar_usuarios_distintos(&candidatos_pendientes),
+                cant_candidatos_aprobados,
+                Self::calcular_conversion(cant_votantes_aprobados, votantes_inscriptos)?,
+                Self::calcular_conversion(cant_votantes_votados, cant_votantes_aprobados)?,
+                Self::calcular_conversion(cant_candidatos_aprobados, candidatos_inscriptos)?,
+            ))
         }
-    }
-    //---------------------------------------------------------------------------------
 
-    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
-    #[cfg_attr(
-        feature = "std",
-        derive(ink::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
-    )]
-    enum SistemaMockeado{
-        A(SistemaVotacionFakeA), //Reporte de votantes retorna un informe con vecs vacios
-        B(SistemaVotacionFakeB), //Reporte de votantes retorna un informe con vecs con datos 
-        C(SistemaVotacionFakeC), //Reporte de votantes retorna un error
-        D(SistemaVotacionFakeD), //Reporte participacion retorna informe
-        E(SistemaVotacionFakeE), //Reporte participacion retorna error por inexistencia de eleccion
-        F(SistemaVotacionFakeF), //Reporte participacion retorna error por division por 0
-        G(SistemaVotacionFakeG), //Reporte resultado retorna vec de CandidatoVotos ordenado 
-        H(SistemaVotacionFakeH), //Reporte resultado retorna error por inexistencia de eleccion
-        I(SistemaVotacionFakeI), //Reporte resultado retorna error por falta de votos
-    }
+        /// Junta los miembros de todas las listas
+        fn miembros_de_listas(listas: &[Lista]) -> Vec<Usuario> {
+            listas.iter().flat_map(|lista| lista.get_miembros()).collect()
+        }
 
-    impl Funciones for SistemaMockeado{
-        fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema>{
-            match self{
-                SistemaMockeado::A(a) => a.get_elecciones_terminadas_especifica(id),
-                SistemaMockeado::B(b) => b.get_elecciones_terminadas_especifica(id),
-                SistemaMockeado::C(c) => c.get_elecciones_terminadas_especifica(id),
-                SistemaMockeado::D(d) => d.get_elecciones_terminadas_especifica(id),
-                SistemaMockeado::E(e) => e.get_elecciones_terminadas_especifica(id),
-                SistemaMockeado::F(f) => f.get_elecciones_terminadas_especifica(id),
-                SistemaMockeado::G(g) => g.get_elecciones_terminadas_especifica(id),
-                SistemaMockeado::H(h) => h.get_elecciones_terminadas_especifica(id),
-                SistemaMockeado::I(i) => i.get_elecciones_terminadas_especifica(id),
+        /// Cuenta los usuarios distintos de una lista, identificados por su AccountId
+        fn contar_usuarios_distintos(usuarios: &[Usuario]) -> u64 {
+            let mut cuentas: Vec<AccountId> = usuarios.iter().map(|usuario| usuario.get_account_id()).collect();
+            cuentas.sort();
+            cuentas.dedup();
+            cuentas.len() as u64
+        }
+
+        /// Calcula el porcentaje de usuarios que pasaron a la etapa siguiente, es 0 si la etapa anterior no tiene usuarios
+        fn calcular_conversion(siguiente: u64, anterior: u64) -> Result<u128, ErrorSistema> {
+            let porcentaje: u128 = (siguiente as u128).checked_mul(100).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+            Ok(porcentaje.checked_div(anterior as u128).unwrap_or(0))
+        }
+
+        /// PERMITE RECUPERAR UN REPORTE DE PARTICIPACIÓN EN LAS ELECCIONES FINALIZADAS
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el nombre del sistema de votación y el ID de la elección, y retorna un `Vec<Informe>` que contiene información sobre la participación en cada elección finalizada.
+        /// 
+        /// # Funcionalidad
+        /// 
+        /// La función calcula la cantidad de votos emitidos y el porcentaje de participación para cada elección finalizada y retorna esta información en un `Informe`.
+        /// También calcula la participación ponderada, sumando el peso de los votos emitidos sobre el peso total de los votantes aprobados,
+        /// e informa si la elección alcanzó su quorum. Los votos anulados por la baja de un candidato cuentan como emitidos.
+        /// La primera consulta guarda la instantánea de la elección, y las siguientes obtienen el informe de ella sin consultar al sistema de votación.
+        /// 
+        /// # Errores
+        /// 
+        /// La función puede retornar errores en caso de overflow al calcular los votos emitidos o el porcentaje de participación.
+        #[ink(message)]
+        pub fn reporte_participacion_msg(&mut self, instancia: String, id:u64) -> Result<Informe, ErrorSistema>{
+            self.reporte_participacion(&instancia, id)
+        }
+
+        fn reporte_participacion(&mut self, instancia: &str, id:u64) -> Result<Informe, ErrorSistema> {
+            let instantanea = match self.buscar_instantanea(instancia, id) {
+                Some(instantanea) => instantanea,
+                None => self.guardar_instantanea(instancia, id)?,
+            };
+            instantanea.informe.ok_or(ErrorSistema::ResultadosNoDisponibles)
+        }
+
+        fn calcular_participacion(eleccion_buscada: &Eleccion) -> Result<Informe, ErrorSistema> {
+            let cant_emit = Self::contar_votos_emitidos(eleccion_buscada)?;
+            let cant_total = eleccion_buscada.get_votantes_aprobados().len() as u128;
+            if cant_total == 0 || cant_emit == 0{
+                return Err(ErrorSistema::ResultadosNoDisponibles);
             }
+            let mut porcentaje: u128 = cant_emit.checked_mul(100).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+            porcentaje = porcentaje.checked_div(cant_total).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+
+            // Participacion ponderada por el peso de cada votante
+            let mut peso_emitido: u128 = eleccion_buscada.get_votos_anulados_ponderados() as u128;
+            for votos in eleccion_buscada.get_eleccion_votos().iter(){
+                peso_emitido = peso_emitido.checked_add(votos.get_votos_ponderados() as u128).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+            }
+            for votos in eleccion_buscada.get_votos_listas().iter(){
+                peso_emitido = peso_emitido.checked_add(votos.get_votos_ponderados() as u128).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+            }
+            let peso_total = eleccion_buscada.get_peso_total_votantes_aprobados();
+            let mut porcentaje_ponderado: u128 = peso_emitido.checked_mul(100).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+            porcentaje_ponderado = porcentaje_ponderado.checked_div(peso_total).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+
+            let informe = Informe::new(eleccion_buscada.get_id(), eleccion_buscada.get_cargo(), cant_emit as u64, cant_total as u64, porcentaje, peso_emitido, peso_total, porcentaje_ponderado, eleccion_buscada.get_quorum_alcanzado());
+            Ok(informe)
         }
 
-        fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion>{
-            match self{
-                SistemaMockeado::A(a) => a.get_elecciones_finiquitadas(),
-                SistemaMockeado::B(b) => b.get_elecciones_finiquitadas(),
-                SistemaMockeado::C(c) => c.get_elecciones_finiquitadas(),
-                SistemaMockeado::D(d) => d.get_elecciones_finiquitadas(),
-                SistemaMockeado::E(e) => e.get_elecciones_finiquitadas(),
-                SistemaMockeado::F(f) => f.get_elecciones_finiquitadas(),
-                SistemaMockeado::G(g) => g.get_elecciones_finiquitadas(),
-                SistemaMockeado::H(h) => h.get_elecciones_finiquitadas(),
-                SistemaMockeado::I(i) => i.get_elecciones_finiquitadas(),
+        /// PERMITE COMPARAR LA PARTICIPACIÓN Y LOS RESULTADOS DE VARIAS ELECCIONES FINALIZADAS
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe un `CriterioComparacion`, que puede ser un cargo o una lista de IDs de elecciones, y retorna un `Vec<FilaComparativa>`.
+        /// 
+        /// # Funcionalidad
+        /// 
+        /// La función selecciona las elecciones finalizadas del cargo indicado, o con los IDs indicados, y las ordena por fecha de inicio.
+        /// Para cada una informa sus fechas, los votantes aprobados, los votos emitidos, el porcentaje de participación, el ganador y el margen de victoria.
+        /// El ganador y el margen se calculan con los votos ponderados, entre los candidatos activos o entre las listas.
+        /// Las elecciones que no alcanzaron su quorum o no tuvieron votos figuran sin ganador y con margen 0.
+        /// Por cargo se comparan las elecciones de todos los sistemas registrados, y por IDs cada elección se indica junto con el nombre de su sistema.
+        /// 
+        /// # Errores
+        /// 
+        /// La función retorna un error si alguno de los sistemas indicados no está registrado, si alguno de los IDs no corresponde a una elección finalizada,
+        /// si no hay elecciones para comparar, o en caso de overflow al sumar los votos.
+        #[ink(message)]
+        pub fn reporte_comparativo_msg(&self, criterio: CriterioComparacion) -> Result<Vec<FilaComparativa>, ErrorSistema>{
+            self.reporte_comparativo(criterio)
+        }
+
+        fn reporte_comparativo(&self, criterio: CriterioComparacion) -> Result<Vec<FilaComparativa>, ErrorSistema> {
+            // Cada eleccion seleccionada junto con el nombre de su sistema
+            let mut seleccionadas: Vec<(String, Eleccion)> = Vec::new();
+            match criterio {
+                CriterioComparacion::Cargo(cargo) => {
+                    for registrado in self.sistemas.iter() {
+                        for eleccion in registrado.sistema.get_elecciones_finiquitadas() {
+                            if eleccion.get_cargo() == cargo {
+                                seleccionadas.push((registrado.nombre.clone(), eleccion));
+                            }
+                        }
+                    }
+                }
+                CriterioComparacion::Elecciones(ids) => {
+                    for (instancia, id) in ids {
+                        let elecciones: Vec<Eleccion> = self.buscar_sistema(&instancia)?.get_elecciones_finiquitadas();
+                        let eleccion_buscada = elecciones.into_iter().find(|eleccion| eleccion.get_id() == id).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+                        seleccionadas.push((instancia, eleccion_buscada));
+                    }
+                }
+            }
+            if seleccionadas.is_empty() {
+                return Err(ErrorSistema::ResultadosNoDisponibles);
             }
+            seleccionadas.sort_by_key(|(_, eleccion)| eleccion.get_fecha_inicio());
+
+            let mut filas = Vec::new();
+            for (instancia, eleccion) in seleccionadas {
+                let cant_emit = Self::contar_votos_emitidos(&eleccion)?;
+                let cant_total = eleccion.get_votantes_aprobados().len() as u128;
+                let porcentaje = cant_emit.saturating_mul(100).checked_div(cant_total).unwrap_or(0);
+                let (ganador, margen) = Self::calcular_ganador_y_margen(&eleccion)?;
+                filas.push(FilaComparativa::new(
+                    instancia,
+                    eleccion.get_id(),
+                    eleccion.get_cargo(),
+                    eleccion.get_fecha_inicio_interfaz(),
+                    eleccion.get_fecha_cierre_interfaz(),
+                    cant_total as u64,
+                    cant_emit as u64,
+                    porcentaje,
+                    ganador,
+                    margen,
+                ));
+            }
+            Ok(filas)
         }
-    }
 
-//-------------------------------------------------------------------------------------------------------------------------
-    impl Funciones for SistemaVotacionRef{
-        fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema>{
-            self.get_elecciones_terminadas_especifica(id)
+        /// Cuenta los votos emitidos en una elección, incluyendo los anulados por la baja de un candidato
+        fn contar_votos_emitidos(eleccion: &Eleccion) -> Result<u128, ErrorSistema> {
+            // Los votos anulados de candidatos dados de baja tambien fueron emitidos
+            let mut cant_emit: u128 = eleccion.get_votos_anulados() as u128;
+            for votos in eleccion.get_eleccion_votos().iter(){
+                cant_emit = cant_emit.checked_add(votos.get_votos_recaudados() as u128).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+            }
+            for votos in eleccion.get_votos_listas().iter(){
+                cant_emit = cant_emit.checked_add(votos.get_votos_recaudados() as u128).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+            }
+            Ok(cant_emit)
         }
 
-        fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion>{
-            self.get_elecciones_finiquitadas()
+        /// Devuelve el nombre del ganador de una elección y su diferencia de votos ponderados con el segundo
+        fn calcular_ganador_y_margen(eleccion: &Eleccion) -> Result<(Option<String>, u64), ErrorSistema> {
+            if !eleccion.get_quorum_alcanzado() {
+                return Ok((None, 0));
+            }
+            let mut resultados: Vec<(String, u64)> = if eleccion.get_votos_listas().is_empty() {
+                eleccion.get_eleccion_votos().iter()
+                    .filter(|candidato| candidato.get_estado() == EstadoCandidato::Activo)
+                    .map(|candidato| (candidato.get_candidato_nombre(), candidato.get_votos_ponderados()))
+                    .collect()
+            } else {
+                eleccion.get_votos_listas().iter().map(|lista| (lista.get_lista_nombre(), lista.get_votos_ponderados())).collect()
+            };
+            resultados.sort_by_key(|(_, votos)| Reverse(*votos));
+
+            match resultados.first() {
+                Some((nombre, votos)) if *votos > 0 => {
+                    let segundo = resultados.get(1).map(|(_, votos)| *votos).unwrap_or(0);
+                    Ok((Some(nombre.clone()), votos.saturating_sub(segundo)))
+                }
+                _ => Ok((None, 0)),
+            }
         }
-    }
 
-    #[ink(storage)]
-    pub struct Reporte {
-        #[cfg(not(test))]
-        sistema: SistemaVotacionRef,
+        /// PERMITE RECUPERAR COMO EVOLUCIONÓ LA PARTICIPACIÓN DURANTE EL PERIODO DE VOTACIÓN DE UNA ELECCIÓN FINALIZADA
+        ///
+        /// # Uso
+        ///
+        /// La función recibe el nombre del sistema de votación y el ID de la elección y el intervalo de agrupación (por hora o por día), y retorna un `Vec<ParticipacionIntervalo>`.
+        ///
+        /// # Funcionalidad
+        ///
+        /// El periodo de votación se divide en intervalos consecutivos desde la fecha de inicio de la elección. Para cada intervalo se informa
+        /// su inicio, la cantidad acumulada de votos emitidos hasta su fin y el porcentaje que representa sobre los votantes aprobados.
+        /// Los momentos de los votos no están relacionados con el candidato o lista votada.
+        ///
+        /// # Errores
+        ///
+        /// La función retorna un error si la elección no está finalizada o no tiene votantes aprobados.
+        /// También retorna `IntervalosExcedidos` si el periodo de votación supera los 744 intervalos, en ese caso debe pedirse por día.
+        #[ink(message)]
+        pub fn reporte_participacion_temporal_msg(&self, instancia: String, id: u64, intervalo: IntervaloParticipacion) -> Result<Vec<ParticipacionIntervalo>, ErrorSistema>{
+            self.reporte_participacion_temporal(&instancia, id, intervalo)
+        }
 
-        #[cfg(test)]
-        sistema: SistemaMockeado
-    }
+        fn reporte_participacion_temporal(&self, instancia: &str, id: u64, intervalo: IntervaloParticipacion) -> Result<Vec<ParticipacionIntervalo>, ErrorSistema> {
+            let eleccion: Vec<Eleccion> = self.buscar_sistema(instancia)?.get_elecciones_finiquitadas();
+            let eleccion_buscada = eleccion.iter().find(|eleccion| eleccion.get_id() == id).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
 
-    impl Reporte {
-        /// PERMITE INICIALIZAR EL CONTRATO CON UNA REFERENCIA AL SISTEMA DE VOTACIÓN
+            let cant_total = eleccion_buscada.get_votantes_aprobados().len() as u128;
+            if cant_total == 0 {
+                return Err(ErrorSistema::ResultadosNoDisponibles);
+            }
+
+            let paso: u64 = match intervalo {
+                IntervaloParticipacion::Hora => 3_600_000,
+                IntervaloParticipacion::Dia => 86_400_000,
+            };
+            let duracion = eleccion_buscada.get_fecha_cierre().saturating_sub(eleccion_buscada.get_fecha_inicio());
+            if duracion.div_ceil(paso) > MAX_INTERVALOS_PARTICIPACION {
+                return Err(ErrorSistema::IntervalosExcedidos);
+            }
+            let mut marcas = eleccion_buscada.get_marcas_tiempo_votos();
+            marcas.sort();
+
+            let mut participacion = Vec::new();
+            let mut acumulados: u64 = 0;
+            let mut desde = eleccion_buscada.get_fecha_inicio();
+            while desde < eleccion_buscada.get_fecha_cierre() {
+                let hasta = desde.saturating_add(paso);
+                while marcas.get(acumulados as usize).is_some_and(|marca| *marca < hasta) {
+                    acumulados = acumulados.saturating_add(1);
+                }
+                let porcentaje = (acumulados as u128).saturating_mul(100).checked_div(cant_total).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+                participacion.push(ParticipacionIntervalo::new(desde, acumulados, porcentaje));
+                desde = hasta;
+            }
+
+            Ok(participacion)
+        }
+
+        /// PERMITE RECUPERAR UN REPORTE DE RESULTADOS DE LAS ELECCIONES FINALIZADAS
         /// 
         /// # Uso
         /// 
-        /// La función recibe un parámetro `sistema` que es una referencia al sistema de votación y retorna una instancia del contrato `Reporte`.
+        /// La función recibe el nombre del sistema de votación y el ID de la elección, y retorna un `Vec<CandidatoVotos>` con la información de los votos recibidos por cada candidato, ordenados de manera descendente.
         /// 
         /// # Funcionalidad
         /// 
-        /// Inicializa el contrato `Reporte` con la referencia proporcionada al sistema de votación.
+        /// La función obtiene los votos recibidos por cada candidato en las elecciones finalizadas y los ordena de manera descendente, mostrando al candidato con más votos primero.
+        /// Cada resultado incluye el estado del candidato, indicando si se retiró o fue descalificado.
+        /// La primera consulta guarda la instantánea de la elección, y las siguientes obtienen los resultados de ella sin consultar al sistema de votación.
         /// 
         /// # Errores
         /// 
-        /// No se esperan errores en la inicialización.
-        #[cfg(not(test))]
-        #[ink(constructor)]
-        pub fn new(sistema: SistemaVotacionRef) -> Self {
-            Self { sistema }
+        /// La función puede retornar un error si no se pueden obtener los resultados de las elecciones.
+        
+        #[ink(message)]
+        pub fn reporte_resultado_msg(&mut self, instancia: String, id: u64) -> Result<Vec<CandidatoVotos>, ErrorSistema>{
+            self.reporte_resultado(&instancia, id)
         }
 
-        #[cfg(test)]
-        pub fn new_fake(sistema: SistemaMockeado) -> Self{
-            Self{sistema}
+        fn reporte_resultado(&mut self, instancia: &str, id: u64) -> Result<Vec<CandidatoVotos>, ErrorSistema> {
+            let instantanea = match self.buscar_instantanea(instancia, id) {
+                Some(instantanea) => instantanea,
+                None => self.guardar_instantanea(instancia, id)?,
+            };
+            instantanea.resultado.ok_or(ErrorSistema::ResultadosNoDisponibles)
+        }
+
+        fn calcular_resultado(eleccion_buscada: &Eleccion) -> Result<Vec<CandidatoVotos>, ErrorSistema> {
+            let mut votos: Vec<CandidatoVotos> = Vec::new();
+            for voto in eleccion_buscada.get_eleccion_votos().iter() {
+                votos.push(voto.clone());
+            }
+
+            if votos.is_empty() {
+                return Err(ErrorSistema::ResultadosNoDisponibles);
+            }
+            votos.sort_by(|a, b| b.get_votos_recaudados().cmp(&a.get_votos_recaudados()));
+            Ok(votos)
         }
 
-         /// PERMITE RECUPERAR LA LISTA DE VOTANTES REGISTRADOS Y APROBADOS PARA UNA ELECCIÓN DETERMINADA
+        /// PERMITE RECUPERAR UN REPORTE DEL RENDIMIENTO DE CADA CANDIDATO EN UNA ELECCIÓN FINALIZADA
         /// 
         /// # Uso
         /// 
-        /// La función recibe el ID de la elección y retorna un `Vec<Usuario>` que contiene los votantes registrados y aprobados.
+        /// La función recibe el nombre del sistema de votación y el ID de la elección y retorna un `ReporteRendimiento` con el rendimiento de cada candidato, ordenados de manera descendente por votos ponderados.
         /// 
         /// # Funcionalidad
         /// 
-        /// La función llama al sistema de votación para obtener los votantes registrados y aprobados para la elección con el ID proporcionado.
+        /// Como al finalizar la elección, el ganador se decide por los votos ponderados. Para cada candidato se informa su puesto, sus votos,
+        /// el porcentaje sobre los votos válidos ponderados y el porcentaje sobre el peso total de los votantes aprobados.
+        /// Los votos válidos son los recaudados por los candidatos, sin contar los anulados por bajas. Los candidatos empatados comparten el puesto,
+        /// y los candidatos retirados o descalificados figuran sin puesto, ya que no pueden resultar ganadores.
+        /// Además se informa el ganador, el margen con el segundo, si hubo un empate en el primer puesto y si el ganador obtuvo la mayoría absoluta de los votos válidos.
+        /// Si la elección no alcanzó su quorum no hay ganador: ningún candidato tiene puesto y el margen es 0.
         /// 
         /// # Errores
         /// 
-        /// La función puede retornar un error si el ID de la elección no es válido.
+        /// La función retorna un error si la elección no está finalizada, si no tiene votos válidos o votantes aprobados, o en caso de overflow.
         #[ink(message)]
-        pub fn reporte_registrados_aprobados_msg(&self, id: u64) -> Result<ReporteDetalleVotante, ErrorSistema>{
-            self.reporte_registrados_aprobados(id)
+        pub fn reporte_rendimiento_candidatos_msg(&self, instancia: String, id: u64) -> Result<ReporteRendimiento, ErrorSistema>{
+            self.reporte_rendimiento_candidatos(&instancia, id)
         }
 
-        fn reporte_registrados_aprobados(&self, id: u64) -> Result<ReporteDetalleVotante, ErrorSistema>{
-            let eleccion_buscada: Eleccion = match self.sistema.get_elecciones_terminadas_especifica(id) {
-                Ok(eleccion) => eleccion,
-                Err(e) => return Err(e),
-            };
-            
+        fn reporte_rendimiento_candidatos(&self, instancia: &str, id: u64) -> Result<ReporteRendimiento, ErrorSistema> {
+            let eleccion: Vec<Eleccion> = self.buscar_sistema(instancia)?.get_elecciones_finiquitadas();
+            let eleccion_buscada = eleccion.iter().find(|eleccion| eleccion.get_id() == id).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
 
-            let vec_votantes_aprobados = eleccion_buscada.get_votantes_aprobados();
-            let vec_votantes_registrados = eleccion_buscada.get_votantes_registrados();
-            // Crear el informe detallado de votantes registrados y aprobados
-            let informe_votantes = ReporteDetalleVotante::new(
-                id,
-                vec_votantes_registrados,
-                vec_votantes_aprobados,
-            );
+            let mut votos = eleccion_buscada.get_eleccion_votos();
+            votos.sort_by_key(|candidato| Reverse(candidato.get_votos_ponderados()));
 
-            Ok(informe_votantes)
+            let mut cant_validos: u128 = 0;
+            for voto in votos.iter() {
+                cant_validos = cant_validos.checked_add(voto.get_votos_ponderados() as u128).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+            }
+            let peso_total = eleccion_buscada.get_peso_total_votantes_aprobados();
+            if cant_validos == 0 || peso_total == 0 {
+                return Err(ErrorSistema::ResultadosNoDisponibles);
+            }
+            let quorum_alcanzado = eleccion_buscada.get_quorum_alcanzado();
+
+            let activos: Vec<u64> = votos.iter().filter(|voto| voto.get_estado() == EstadoCandidato::Activo).map(|voto| voto.get_votos_ponderados()).collect();
+            let mut candidatos = Vec::new();
+            for voto in votos {
+                let cant_votos = voto.get_votos_ponderados() as u128;
+                let mut porcentaje_validos: u128 = cant_votos.checked_mul(100).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+                porcentaje_validos = porcentaje_validos.checked_div(cant_validos).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+                let mut porcentaje_aprobados: u128 = cant_votos.checked_mul(100).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+                porcentaje_aprobados = porcentaje_aprobados.checked_div(peso_total).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+
+                // El puesto es uno mas que la cantidad de candidatos activos con mas votos
+                let puesto = match voto.get_estado() {
+                    EstadoCandidato::Activo if quorum_alcanzado => {
+                        let superado_por = activos.iter().filter(|votos| **votos > voto.get_votos_ponderados()).count() as u32;
+                        Some(superado_por.checked_add(1).ok_or(ErrorSistema::ResultadosNoDisponibles)?)
+                    }
+                    _ => None,
+                };
+                candidatos.push(RendimientoCandidato::new(puesto, voto, porcentaje_validos, porcentaje_aprobados));
+            }
+
+            if !quorum_alcanzado {
+                return Ok(ReporteRendimiento::new(eleccion_buscada.get_id(), candidatos, None, 0, false, false));
+            }
+            let primero = activos.first().copied().unwrap_or(0);
+            let segundo = activos.get(1).copied().unwrap_or(0);
+            let margen = primero.checked_sub(segundo).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+            let empate = activos.len() > 1 && margen == 0;
+            let mayoria_absoluta = (primero as u128).checked_mul(2).ok_or(ErrorSistema::ResultadosNoDisponibles)? > cant_validos;
+            let ganador = candidatos.iter()
+                .find(|rendimiento| rendimiento.puesto == Some(1) && !empate && primero > 0)
+                .map(|rendimiento| rendimiento.candidato.get_candidato_nombre());
+
+            Ok(ReporteRendimiento::new(eleccion_buscada.get_id(), candidatos, ganador, margen, empate, mayoria_absoluta))
         }
 
-        /// PERMITE RECUPERAR UN REPORTE DE PARTICIPACIÓN EN LAS ELECCIONES FINALIZADAS
+        /// PERMITE AL ADMIN DEL SISTEMA RECUPERAR LOS VOTANTES APROBADOS QUE NO VOTARON EN UNA ELECCIÓN FINALIZADA
         /// 
         /// # Uso
         /// 
-        /// La función no recibe parámetros y retorna un `Vec<Informe>` que contiene información sobre la participación en cada elección finalizada.
+        /// La función recibe el nombre del sistema de votación y el ID de la elección, el número de página (comenzando en 0) y la cantidad de votantes por página, y retorna un `ReporteAbstencion`.
         /// 
         /// # Funcionalidad
         /// 
-        /// La función calcula la cantidad de votos emitidos y el porcentaje de participación para cada elección finalizada y retorna esta información en un `Informe`.
+        /// La función calcula los votantes aprobados que no figuran entre los votantes que votaron, y devuelve la página solicitada de ese listado
+        /// junto con la cantidad de votantes aprobados, de votantes que votaron, de abstenciones y la cantidad total de páginas.
+        /// Una página posterior a la última se devuelve vacía.
         /// 
         /// # Errores
         /// 
-        /// La función puede retornar errores en caso de overflow al calcular los votos emitidos o el porcentaje de participación.
+        /// La función retorna un error si quien la invoca no es el admin del sistema, si la cantidad por página es cero o si la elección no está finalizada.
         #[ink(message)]
-        pub fn reporte_participacion_msg(&self, id:u64) -> Result<Informe, ErrorSistema>{
-            self.reporte_participacion(id)
+        pub fn reporte_abstencion_msg(&self, instancia: String, id: u64, pagina: u32, cantidad_por_pagina: u32) -> Result<ReporteAbstencion, ErrorSistema>{
+            self.reporte_abstencion(&instancia, id, pagina, cantidad_por_pagina)
         }
 
-        fn reporte_participacion(&self, id:u64) -> Result<Informe, ErrorSistema> {
-            let eleccion: Vec<Eleccion> = self.sistema.get_elecciones_finiquitadas();
-            let eleccion_buscada = eleccion.iter().find(|eleccion| eleccion.get_id() == id).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
-            
-            let mut cant_emit: u128 = 0;
-            for votos in eleccion_buscada.get_eleccion_votos().iter(){
-                cant_emit = cant_emit.checked_add(votos.get_votos_recaudados() as u128).expect("Error: Overflow en la suma de votos.");
+        fn reporte_abstencion(&self, instancia: &str, id: u64, pagina: u32, cantidad_por_pagina: u32) -> Result<ReporteAbstencion, ErrorSistema> {
+            if Self::env().caller() != self.buscar_sistema(instancia)?.get_admin_id() {
+                return Err(ErrorSistema::NoSePoseenPermisos);
             }
-            // Calcular la cantidad de votos emitidos
-            let cant_total = eleccion_buscada.get_votantes_aprobados().len() as u128;
-            if cant_total == 0 || cant_emit == 0{
-                return Err(ErrorSistema::ResultadosNoDisponibles);
+            if cantidad_por_pagina == 0 {
+                return Err(ErrorSistema::PaginacionInvalida);
             }
-            let mut porcentaje: u128 = cant_emit.checked_mul(100).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
-            porcentaje = porcentaje.checked_div(cant_total).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
-            let informe = Informe::new(eleccion_buscada.get_id(), eleccion_buscada.get_cargo(), cant_emit as u64, cant_total as u64, porcentaje);
-            Ok(informe)
+            let eleccion: Vec<Eleccion> = self.buscar_sistema(instancia)?.get_elecciones_finiquitadas();
+            let eleccion_buscada = eleccion.iter().find(|eleccion| eleccion.get_id() == id).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
+
+            let votantes_votados = eleccion_buscada.get_votantes_votados();
+            let votantes_aprobados = eleccion_buscada.get_votantes_aprobados();
+            let abstencionistas: Vec<Usuario> = votantes_aprobados.iter()
+                .filter(|votante| !votantes_votados.iter().any(|v| v.get_account_id() == votante.get_account_id()))
+                .cloned()
+                .collect();
+
+            let total_paginas = (abstencionistas.len() as u32).div_ceil(cantidad_por_pagina);
+            let desde = pagina.saturating_mul(cantidad_por_pagina) as usize;
+            let pagina_abstencionistas = abstencionistas.iter().skip(desde).take(cantidad_por_pagina as usize).cloned().collect();
+
+            Ok(ReporteAbstencion::new(
+                id,
+                votantes_aprobados.len() as u64,
+                votantes_votados.len() as u64,
+                abstencionistas.len() as u64,
+                pagina,
+                total_paginas,
+                pagina_abstencionistas,
+            ))
         }
 
-        /// PERMITE RECUPERAR UN REPORTE DE RESULTADOS DE LAS ELECCIONES FINALIZADAS
+        /// PERMITE RECUPERAR UN REPORTE DE RESULTADOS POR LISTA DE UNA ELECCIÓN FINALIZADA
         /// 
         /// # Uso
         /// 
-        /// La función no recibe parámetros y retorna un `Vec<CandidatoVotos>` con la información de los votos recibidos por cada candidato, ordenados de manera descendente.
+        /// La función recibe el nombre del sistema de votación y el ID de una elección votada por listas y retorna un `Vec<ListaVotos>` con los votos recibidos por cada lista, ordenados de manera descendente.
         /// 
         /// # Funcionalidad
         /// 
-        /// La función obtiene los votos recibidos por cada candidato en las elecciones finalizadas y los ordena de manera descendente, mostrando al candidato con más votos primero.
+        /// La función obtiene los votos recibidos por cada lista en la elección finalizada y los ordena de manera descendente por votos ponderados,
+        /// que coinciden con la cantidad de votos cuando no se asignaron pesos a los votantes.
         /// 
         /// # Errores
         /// 
-        /// La función puede retornar un error si no se pueden obtener los resultados de las elecciones.
-        
+        /// La función puede retornar un error si la elección no está finalizada o no tiene listas votadas.
         #[ink(message)]
-        pub fn reporte_resultado_msg(&self, id: u64) -> Result<Vec<CandidatoVotos>, ErrorSistema>{
-            self.reporte_resultado(id)
+        pub fn reporte_resultado_listas_msg(&self, instancia: String, id: u64) -> Result<Vec<ListaVotos>, ErrorSistema>{
+            self.reporte_resultado_listas(&instancia, id)
         }
 
-        fn reporte_resultado(&self, id: u64) -> Result<Vec<CandidatoVotos>, ErrorSistema> {
-            let mut votos: Vec<CandidatoVotos> = Vec::new();
-            let eleccion: Vec<Eleccion> = self.sistema.get_elecciones_finiquitadas();
+        fn reporte_resultado_listas(&self, instancia: &str, id: u64) -> Result<Vec<ListaVotos>, ErrorSistema> {
+            let eleccion: Vec<Eleccion> = self.buscar_sistema(instancia)?.get_elecciones_finiquitadas();
             let eleccion_buscada = eleccion.iter().find(|eleccion| eleccion.get_id() == id).ok_or(ErrorSistema::ResultadosNoDisponibles)?;
-            
-            for voto in eleccion_buscada.get_eleccion_votos().iter() {
-                votos.push(voto.clone());
-            }
 
+            let mut votos = eleccion_buscada.get_votos_listas();
             if votos.is_empty() {
                 return Err(ErrorSistema::ResultadosNoDisponibles);
             }
-            votos.sort_by(|a, b| b.get_votos_recaudados().cmp(&a.get_votos_recaudados()));
+            votos.sort_by_key(|lista| Reverse(lista.get_votos_ponderados()));
             Ok(votos)
         }
 
         #[cfg(test)]
-        pub fn set_sistema(&mut self, sistema: SistemaMockeado){
-            self.sistema = sistema;
+        fn set_sistema(&mut self, instancia: &str, sistema: SistemaVotacionFake){
+            if let Some(registrado) = self.sistemas.iter_mut().find(|registrado| registrado.nombre == instancia) {
+                registrado.sistema = sistema;
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    struct InstanciaSistema {
+        nombre: String,
+        cuenta: AccountId, // Cuenta del contrato del sistema, identifica a que contrato pertenecen las instantaneas
+        sistema: Sistema,
+    }
+
+    impl InstanciaSistema {
+        fn new(nombre: String, sistema: Sistema) -> Self {
+            InstanciaSistema { nombre, cuenta: Self::cuenta_de(&sistema), sistema }
+        }
+
+        #[cfg(not(test))]
+        fn cuenta_de(sistema: &Sistema) -> AccountId {
+            ink::ToAccountId::<Environment>::to_account_id(sistema)
+        }
+
+        // En los tests cada sistema de prueba con datos distintos hace de un contrato distinto
+        #[cfg(test)]
+        fn cuenta_de(sistema: &Sistema) -> AccountId {
+            let mut cuenta = [0x0; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(sistema, &mut cuenta);
+            AccountId::from(cuenta)
         }
     }
 
@@ -487,6 +934,10 @@ mod reporte {
         votos_emitidos: u64,
         votos_totales: u64,
         porcentaje: u128,
+        votos_ponderados_emitidos: u128,
+        peso_total: u128,
+        porcentaje_ponderado: u128,
+        quorum_alcanzado: bool,
     }
     
     impl Informe {
@@ -494,7 +945,8 @@ mod reporte {
         /// 
         /// # Uso
         /// 
-        /// La función recibe los parámetros `eleccion_id`, `cargo`, `votos_emitidos`, `votos_totales` y `porcentaje` para inicializar un `Informe`.
+        /// La función recibe los parámetros `eleccion_id`, `cargo`, `votos_emitidos`, `votos_totales`, `porcentaje`, `votos_ponderados_emitidos`,
+        /// `peso_total`, `porcentaje_ponderado` y `quorum_alcanzado` para inicializar un `Informe`.
         /// 
         /// # Funcionalidad
         /// 
@@ -503,16 +955,245 @@ mod reporte {
         /// # Errores
         /// 
         /// No se esperan errores en la inicialización.
-        fn new(eleccion_id: u64, cargo: String, votos_emitidos: u64, votos_totales: u64, porcentaje: u128) -> Self {
+        #[allow(clippy::too_many_arguments)]
+        fn new(eleccion_id: u64, cargo: String, votos_emitidos: u64, votos_totales: u64, porcentaje: u128, votos_ponderados_emitidos: u128, peso_total: u128, porcentaje_ponderado: u128, quorum_alcanzado: bool) -> Self {
             Informe {
                 eleccion_id,
                 cargo,
                 votos_emitidos,
                 votos_totales,
                 porcentaje,
+                votos_ponderados_emitidos,
+                peso_total,
+                porcentaje_ponderado,
+                quorum_alcanzado,
+            }
+        }
+    }
+    #[derive(Clone, Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct InstantaneaEleccion {
+        eleccion_id: u64,
+        informe: Option<Informe>, // None si la eleccion no tiene un reporte de participacion disponible
+        resultado: Option<Vec<CandidatoVotos>>, // None si la eleccion no tiene votos a candidatos
+    }
+
+    impl InstantaneaEleccion {
+        /// PERMITE CREAR UNA NUEVA `INSTANTANEA ELECCION`
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el ID de la elección y sus reportes de participación y de resultados, si están disponibles.
+        /// 
+        /// # Errores
+        /// 
+        /// No se esperan errores en la inicialización.
+        fn new(eleccion_id: u64, informe: Option<Informe>, resultado: Option<Vec<CandidatoVotos>>) -> Self {
+            InstantaneaEleccion { eleccion_id, informe, resultado }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct RendimientoCandidato {
+        puesto: Option<u32>, // Los candidatos retirados o descalificados no tienen puesto
+        candidato: CandidatoVotos, // Incluye los votos ponderados, con los que se ordena, y la cantidad de votos sin ponderar
+        porcentaje_validos: u128,
+        porcentaje_aprobados: u128,
+    }
+
+    impl RendimientoCandidato {
+        /// PERMITE CREAR UN NUEVO `RENDIMIENTO CANDIDATO`
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el puesto del candidato, sus votos y sus porcentajes sobre los votos válidos y sobre los votantes aprobados.
+        /// 
+        /// # Errores
+        /// 
+        /// No se esperan errores en la inicialización.
+        fn new(puesto: Option<u32>, candidato: CandidatoVotos, porcentaje_validos: u128, porcentaje_aprobados: u128) -> Self {
+            RendimientoCandidato { puesto, candidato, porcentaje_validos, porcentaje_aprobados }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct ReporteRendimiento {
+        eleccion_id: u64,
+        candidatos: Vec<RendimientoCandidato>,
+        ganador: Option<String>, // None si no se alcanzo el quorum o hubo un empate en el primer puesto
+        margen: u64, // Diferencia de votos ponderados entre el ganador y el segundo
+        empate: bool,
+        mayoria_absoluta: bool,
+    }
+
+    impl ReporteRendimiento {
+        /// PERMITE CREAR UN NUEVO `REPORTE RENDIMIENTO`
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el ID de la elección, el rendimiento de cada candidato, el ganador, el margen de victoria y si hubo empate o mayoría absoluta.
+        /// 
+        /// # Errores
+        /// 
+        /// No se esperan errores en la inicialización.
+        fn new(eleccion_id: u64, candidatos: Vec<RendimientoCandidato>, ganador: Option<String>, margen: u64, empate: bool, mayoria_absoluta: bool) -> Self {
+            ReporteRendimiento { eleccion_id, candidatos, ganador, margen, empate, mayoria_absoluta }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct EmbudoInscripcion {
+        eleccion_id: u64,
+        votantes_pendientes: u64,
+        votantes_aprobados: u64,
+        votantes_votados: u64,
+        candidatos_pendientes: u64,
+        candidatos_aprobados: u64,
+        conversion_votantes_aprobados: u128, // Aprobados sobre pendientes y aprobados
+        conversion_votantes_votados: u128, // Votaron sobre aprobados
+        conversion_candidatos_aprobados: u128, // Aprobados sobre pendientes y aprobados
+    }
+
+    impl EmbudoInscripcion {
+        /// PERMITE CREAR UN NUEVO `EMBUDO INSCRIPCION`
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el ID de la elección, la cantidad de votantes y candidatos en cada etapa y los porcentajes de conversión entre etapas.
+        /// 
+        /// # Errores
+        /// 
+        /// No se esperan errores en la inicialización.
+        #[allow(clippy::too_many_arguments)]
+        fn new(eleccion_id: u64, votantes_pendientes: u64, votantes_aprobados: u64, votantes_votados: u64, candidatos_pendientes: u64, candidatos_aprobados: u64, conversion_votantes_aprobados: u128, conversion_votantes_votados: u128, conversion_candidatos_aprobados: u128) -> Self {
+            EmbudoInscripcion {
+                eleccion_id,
+                votantes_pendientes,
+                votantes_aprobados,
+                votantes_votados,
+                candidatos_pendientes,
+                candidatos_aprobados,
+                conversion_votantes_aprobados,
+                conversion_votantes_votados,
+                conversion_candidatos_aprobados,
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct ReporteAbstencion {
+        eleccion_id: u64,
+        votantes_aprobados: u64,
+        votantes_votados: u64,
+        abstenciones: u64,
+        pagina: u32,
+        total_paginas: u32,
+        abstencionistas: Vec<Usuario>, // Solo los de la pagina solicitada
+    }
+
+    impl ReporteAbstencion {
+        /// PERMITE CREAR UN NUEVO `REPORTE ABSTENCION`
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el ID de la elección, las cantidades de votantes aprobados, que votaron y que se abstuvieron, la página informada,
+        /// la cantidad total de páginas y los votantes que se abstuvieron dentro de esa página.
+        /// 
+        /// # Errores
+        /// 
+        /// No se esperan errores en la inicialización.
+        #[allow(clippy::too_many_arguments)]
+        fn new(eleccion_id: u64, votantes_aprobados: u64, votantes_votados: u64, abstenciones: u64, pagina: u32, total_paginas: u32, abstencionistas: Vec<Usuario>) -> Self {
+            ReporteAbstencion { eleccion_id, votantes_aprobados, votantes_votados, abstenciones, pagina, total_paginas, abstencionistas }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum CriterioComparacion { Cargo(String), Elecciones(Vec<(String, u64)>) } // Cada eleccion con el nombre de su sistema
+
+    #[derive(Clone, Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct FilaComparativa {
+        instancia: String, // Nombre del sistema de votacion de la eleccion
+        eleccion_id: u64,
+        cargo: String,
+        fecha_inicio: Fecha,
+        fecha_cierre: Fecha,
+        votantes_aprobados: u64,
+        votos_emitidos: u64,
+        porcentaje: u128,
+        ganador: Option<String>, // Candidato o lista ganadora, si la eleccion tuvo ganador
+        margen: u64, // Diferencia de votos ponderados entre el ganador y el segundo
+    }
+
+    impl FilaComparativa {
+        /// PERMITE CREAR UNA NUEVA `FILA COMPARATIVA`
+        /// 
+        /// # Uso
+        /// 
+        /// La función recibe el nombre del sistema y los datos de una elección finalizada junto con su participación, su ganador y el margen de victoria.
+        /// 
+        /// # Errores
+        /// 
+        /// No se esperan errores en la inicialización.
+        #[allow(clippy::too_many_arguments)]
+        fn new(instancia: String, eleccion_id: u64, cargo: String, fecha_inicio: Fecha, fecha_cierre: Fecha, votantes_aprobados: u64, votos_emitidos: u64, porcentaje: u128, ganador: Option<String>, margen: u64) -> Self {
+            FilaComparativa {
+                instancia,
+                eleccion_id,
+                cargo,
+                fecha_inicio,
+                fecha_cierre,
+                votantes_aprobados,
+                votos_emitidos,
+                porcentaje,
+                ganador,
+                margen,
             }
         }
     }
+
+    #[derive(Clone, Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum IntervaloParticipacion { Hora, Dia }
+
+    #[derive(Clone, Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct ParticipacionIntervalo {
+        desde: Timestamp,
+        votos_acumulados: u64,
+        porcentaje_acumulado: u128,
+    }
+
+    impl ParticipacionIntervalo {
+        /// PERMITE CREAR UNA NUEVA `PARTICIPACION INTERVALO`
+        ///
+        /// # Uso
+        ///
+        /// La función recibe el inicio del intervalo, los votos acumulados hasta su fin y el porcentaje de participación acumulado.
+        ///
+        /// # Errores
+        ///
+        /// No se esperan errores en la inicialización.
+        fn new(desde: Timestamp, votos_acumulados: u64, porcentaje_acumulado: u128) -> Self {
+            ParticipacionIntervalo { desde, votos_acumulados, porcentaje_acumulado }
+        }
+    }
+
     #[derive(Clone, Debug, PartialEq)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
@@ -549,54 +1230,129 @@ mod reporte {
         use std::vec;
 
         use super::*;
+        use sistema_votacion::PesoVotante;
+
+        const INSTANCIA: &str = "Organizacion";
+
+        fn usuario(cuenta: u8, nombre: &str, dni: &str) -> Usuario {
+            Usuario::new(AccountId::from([cuenta; 32]), nombre.to_owned(), dni.to_owned(), Hash::default())
+        }
+
+        // Los primeros votantes de Pepe, Juan, Lucia, Franco y Sofia
+        fn votantes(cantidad: usize) -> Vec<Usuario> {
+            let mut todos = vec![usuario(0x1, "Pepe", "111"), usuario(0x2, "Juan", "222"), usuario(0x3, "Lucia", "333"), usuario(0x4, "Franco", "444"), usuario(0x5, "Sofia", "555")];
+            todos.truncate(cantidad);
+            todos
+        }
+
+        fn eleccion(id: u64) -> Eleccion {
+            Eleccion::new(id, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1))
+        }
+
+        fn candidato(nombre: &str, dni: &str, votos: u64, votos_ponderados: u64) -> CandidatoVotos {
+            let mut candidato = CandidatoVotos::new(nombre.to_owned(), dni.to_owned());
+            candidato.set_votos_recaudados(votos);
+            candidato.set_votos_ponderados(votos_ponderados);
+            candidato
+        }
+
+        fn lista(nombre: &str, votos: u64, votos_ponderados: u64) -> ListaVotos {
+            let mut lista = ListaVotos::new(nombre.to_owned());
+            lista.set_votos_recaudados(votos);
+            lista.set_votos_ponderados(votos_ponderados);
+            lista
+        }
+
+        // Eleccion con cuatro votantes, dos de ellos con peso, y tres votos
+        fn eleccion_con_pesos() -> Eleccion {
+            let mut elec = eleccion(0);
+            elec.set_votantes_aprobados(votantes(4));
+            elec.set_pesos_votantes(vec![PesoVotante::new(AccountId::from([0x1; 32]), 5), PesoVotante::new(AccountId::from([0x2; 32]), 3)]);
+            elec.set_votos(vec![candidato("Jorge", "999", 2, 8), candidato("Mara", "888", 1, 1)]);
+            elec
+        }
+
+        // Eleccion con votos y sin votantes aprobados
+        fn eleccion_sin_votantes() -> Eleccion {
+            let mut elec = eleccion(0);
+            elec.set_votos(vec![candidato("Jorge", "999", 5, 0), candidato("Mara", "888", 19, 0), candidato("Esteban", "777", 3, 0)]);
+            elec
+        }
+
+        // Elecciones de un mismo cargo en distintos años, y una de otro cargo votada por listas
+        fn elecciones_por_cargo() -> Vec<Eleccion> {
+            let mut presidente_2024 = Eleccion::new(2, "Presidente".to_owned(), 2_000, 2_500, Fecha::new(1,1,2024,0,0,0), Fecha::new(2,1,2024,0,0,0));
+            presidente_2024.set_votantes_aprobados(votantes(4));
+            presidente_2024.set_votos(vec![candidato("Jorge", "999", 3, 3), candidato("Mara", "888", 1, 1)]);
+
+            let mut tesorero = Eleccion::new(1, "Tesorero".to_owned(), 1_500, 1_800, Fecha::new(1,6,2020,0,0,0), Fecha::new(2,6,2020,0,0,0));
+            tesorero.set_votantes_aprobados(votantes(4));
+            tesorero.set_votos_listas(vec![lista("Lista Azul", 1, 1), lista("Lista Roja", 3, 3)]);
+
+            let mut presidente_2020 = Eleccion::new(0, "Presidente".to_owned(), 1_000, 1_200, Fecha::new(1,1,2020,0,0,0), Fecha::new(2,1,2020,0,0,0));
+            presidente_2020.set_votantes_aprobados(votantes(4));
+            presidente_2020.set_votos(vec![candidato("Jorge", "999", 1, 1), candidato("Mara", "888", 2, 2)]);
+
+            // Eleccion que no alcanzo el quorum, queda sin ganador
+            let mut presidente_2028 = Eleccion::new(3, "Presidente".to_owned(), 3_000, 3_500, Fecha::new(1,1,2028,0,0,0), Fecha::new(2,1,2028,0,0,0));
+            presidente_2028.set_votantes_aprobados(votantes(4));
+            presidente_2028.set_votos(vec![candidato("Jorge", "999", 1, 1)]);
+            presidente_2028.set_quorum_alcanzado(false);
+
+            vec![presidente_2024, tesorero, presidente_2020, presidente_2028]
+        }
 
         #[ink::test]
         fn test_reporte_registro_de_votantes(){
             //Resultado informe con vecs vacios
-            let sistema1 = SistemaVotacionFakeA::new();
-            let mut reporte = Reporte::new_fake(SistemaMockeado::A(sistema1));
-            assert_eq!(reporte.sistema.get_elecciones_finiquitadas(), Vec::new());
-            assert_eq!(Ok(ReporteDetalleVotante::new(0, Vec::new(), Vec::new())), reporte.reporte_registrados_aprobados(0));
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![eleccion(0)]));
+            assert_eq!(reporte.buscar_sistema(INSTANCIA).unwrap().get_elecciones_finiquitadas(), vec![eleccion(0)]);
+            assert_eq!(Ok(ReporteDetalleVotante::new(0, Vec::new(), Vec::new())), reporte.reporte_registrados_aprobados(INSTANCIA, 0));
             //Resultado informe con vecs con datos
-            let sistema2 = SistemaVotacionFakeB::new();
-            reporte.set_sistema(SistemaMockeado::B(sistema2));
-            let esperado = ReporteDetalleVotante::new(0,vec![Usuario::new(AccountId::from([0x1; 32]), "Pepe".to_owned(), "111".to_owned()), Usuario::new(AccountId::from([0x2; 32]), "Juan".to_owned(), "222".to_owned())] , vec![Usuario::new(AccountId::from([0x3; 32]), "Lucas".to_owned(), "333".to_owned())]);
-            assert_eq!(reporte.sistema.get_elecciones_finiquitadas(), Vec::new());
-            assert_eq!(Ok(esperado), reporte.reporte_registrados_aprobados(0));
+            let mut elec = eleccion(0);
+            elec.set_votantes_registrados(vec![usuario(0x1, "Pepe", "111"), usuario(0x2, "Juan", "222")]);
+            elec.set_votantes_aprobados(vec![usuario(0x3, "Lucas", "333")]);
+            reporte.set_sistema(INSTANCIA, SistemaVotacionFake::new(vec![elec]));
+            assert_eq!(1, reporte.buscar_sistema(INSTANCIA).unwrap().get_elecciones_finiquitadas().len());
+            let esperado = ReporteDetalleVotante::new(0,vec![Usuario::new(AccountId::from([0x1; 32]), "Pepe".to_owned(), "111".to_owned(), Hash::default()), Usuario::new(AccountId::from([0x2; 32]), "Juan".to_owned(), "222".to_owned(), Hash::default())] , vec![Usuario::new(AccountId::from([0x3; 32]), "Lucas".to_owned(), "333".to_owned(), Hash::default())]);
+            assert_eq!(Ok(esperado), reporte.reporte_registrados_aprobados(INSTANCIA, 0));
             //Resultado informe con error
-            let sistema3 = SistemaVotacionFakeC::new();
-            reporte.set_sistema(SistemaMockeado::C(sistema3));
-            assert_eq!(reporte.sistema.get_elecciones_finiquitadas(), Vec::new());
-            assert_eq!(Err(ErrorSistema::EleccionInvalida), reporte.reporte_registrados_aprobados(0));
+            reporte.set_sistema(INSTANCIA, SistemaVotacionFake::new(Vec::new()));
+            assert_eq!(reporte.buscar_sistema(INSTANCIA).unwrap().get_elecciones_finiquitadas(), Vec::new());
+            assert_eq!(Err(ErrorSistema::EleccionInvalida), reporte.reporte_registrados_aprobados(INSTANCIA, 0));
 
         }
 
         #[ink::test]
         fn test_reporte_participacion(){
             //Resultado informe
-            let sistema1 = SistemaVotacionFakeD::new();
-            let mut reporte = Reporte::new_fake(SistemaMockeado::D(sistema1));
-            let elec = Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1));
-            let esperado = Informe::new(elec.get_id(), elec.get_cargo(), 3, 4, 75);
-            assert_eq!(Ok(Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1))), reporte.sistema.get_elecciones_terminadas_especifica(0));
-            assert_eq!(Ok(esperado), reporte.reporte_participacion(0));
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![eleccion_con_pesos()]));
+            let elec = eleccion(0);
+            let esperado = Informe::new(elec.get_id(), elec.get_cargo(), 3, 4, 75, 9, 10, 90, true);
+            assert_eq!(Ok(eleccion_con_pesos()), reporte.buscar_sistema(INSTANCIA).unwrap().get_elecciones_terminadas_especifica(0));
+            assert_eq!(Ok(esperado), reporte.reporte_participacion(INSTANCIA, 0));
             //Resultado error por eleccion inexistente
-            let sistema2 = SistemaVotacionFakeE::new();
-            reporte.set_sistema(SistemaMockeado::E(sistema2));
-            assert_eq!(Ok(Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1))), reporte.sistema.get_elecciones_terminadas_especifica(0));
-            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_participacion(0));
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(Vec::new()));
+            assert_eq!(Err(ErrorSistema::EleccionInvalida), reporte.buscar_sistema(INSTANCIA).unwrap().get_elecciones_terminadas_especifica(0));
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_participacion(INSTANCIA, 0));
             //Resultado error por division por 0
-            let sistema3 = SistemaVotacionFakeF::new();
-            reporte.set_sistema(SistemaMockeado::F(sistema3));
-            assert_eq!(Ok(Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1))), reporte.sistema.get_elecciones_terminadas_especifica(0));
-            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_participacion(0));
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![eleccion_sin_votantes()]));
+            assert_eq!(Ok(eleccion_sin_votantes()), reporte.buscar_sistema(INSTANCIA).unwrap().get_elecciones_terminadas_especifica(0));
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_participacion(INSTANCIA, 0));
+            //Resultado informe de una eleccion que no alcanzo el quorum
+            let mut sin_quorum = eleccion(0);
+            sin_quorum.set_votantes_aprobados(votantes(2));
+            sin_quorum.set_votos(vec![candidato("Jorge", "999", 1, 1)]);
+            sin_quorum.set_quorum_alcanzado(false);
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![sin_quorum]));
+            let esperado = Informe::new(elec.get_id(), elec.get_cargo(), 1, 2, 50, 1, 2, 50, false);
+            assert_eq!(Ok(esperado), reporte.reporte_participacion(INSTANCIA, 0));
         }
 
         #[ink::test]
         fn test_reporte_resultado(){
             //Resultado vec con datos
-            let sistema1 = SistemaVotacionFakeG::new();
-            let mut reporte = Reporte::new_fake(SistemaMockeado::G(sistema1));
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![eleccion_sin_votantes()]));
             
             let mut esperado = vec![ 
                 CandidatoVotos::new("Mara".to_owned(), "888".to_owned()), 
@@ -606,18 +1362,369 @@ mod reporte {
             esperado[1].set_votos_recaudados(5);
             esperado[0].set_votos_recaudados(19);
             esperado[2].set_votos_recaudados(3);
-            assert_eq!(Ok(Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1))), reporte.sistema.get_elecciones_terminadas_especifica(0));
-            assert_eq!(Ok(esperado), reporte.reporte_resultado(0));
+            assert_eq!(Ok(eleccion_sin_votantes()), reporte.buscar_sistema(INSTANCIA).unwrap().get_elecciones_terminadas_especifica(0));
+            assert_eq!(Ok(esperado), reporte.reporte_resultado(INSTANCIA, 0));
             //Resultado error por inexistencia de eleccion
-            let sistema2 = SistemaVotacionFakeH::new();
-            reporte.set_sistema(SistemaMockeado::H(sistema2));
-            assert_eq!(Ok(Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1))), reporte.sistema.get_elecciones_terminadas_especifica(0));
-            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_resultado(0));
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(Vec::new()));
+            assert_eq!(Err(ErrorSistema::EleccionInvalida), reporte.buscar_sistema(INSTANCIA).unwrap().get_elecciones_terminadas_especifica(0));
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_resultado(INSTANCIA, 0));
             //Resultado error por falta de votos
-            let sistema3 = SistemaVotacionFakeI::new();
-            reporte.set_sistema(SistemaMockeado::I(sistema3));
-            assert_eq!(Ok(Eleccion::new(0, "Un cargo".to_owned(), Timestamp::default(), Timestamp::default(), Fecha::new(1,1,1,1,1,1), Fecha::new(1,1,1,1,1,1))), reporte.sistema.get_elecciones_terminadas_especifica(0));
-            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_resultado(0));
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![eleccion(0)]));
+            assert_eq!(Ok(eleccion(0)), reporte.buscar_sistema(INSTANCIA).unwrap().get_elecciones_terminadas_especifica(0));
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_resultado(INSTANCIA, 0));
+        }
+
+        #[ink::test]
+        fn test_reporte_resultado_listas(){
+            //Resultado vec de listas ordenado
+            let mut elec = eleccion(0);
+            elec.set_votantes_aprobados(votantes(4));
+            elec.set_votos_listas(vec![lista("Lista Azul", 1, 1), lista("Lista Roja", 3, 3)]);
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![elec]));
+
+            let mut esperado = vec![ListaVotos::new("Lista Roja".to_owned()), ListaVotos::new("Lista Azul".to_owned())];
+            esperado[0].set_votos_recaudados(3);
+            esperado[0].set_votos_ponderados(3);
+            esperado[1].set_votos_recaudados(1);
+            esperado[1].set_votos_ponderados(1);
+            assert_eq!(Ok(esperado), reporte.reporte_resultado_listas(INSTANCIA, 0));
+            //La participacion cuenta los votos a listas
+            let elec = eleccion(0);
+            assert_eq!(Ok(Informe::new(elec.get_id(), elec.get_cargo(), 4, 4, 100, 4, 4, 100, true)), reporte.reporte_participacion(INSTANCIA, 0));
+            //Resultado error por eleccion sin listas
+            reporte.set_sistema(INSTANCIA, SistemaVotacionFake::new(vec![eleccion_sin_votantes()]));
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_resultado_listas(INSTANCIA, 0));
+        }
+
+        #[ink::test]
+        fn test_reporte_candidato_descalificado(){
+            let mut elec = eleccion(0);
+            elec.set_votantes_aprobados(votantes(4));
+            let mut votos = vec![candidato("Jorge", "999", 2, 2), CandidatoVotos::new("Mara".to_owned(), "888".to_owned())];
+            votos[1].set_estado(EstadoCandidato::Descalificado("Fraude".to_owned()));
+            elec.set_votos(votos);
+            elec.set_votos_anulados(1, 1);
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![elec]));
+            //El resultado refleja el estado del candidato descalificado
+            let mut esperado = vec![CandidatoVotos::new("Jorge".to_owned(), "999".to_owned()), CandidatoVotos::new("Mara".to_owned(), "888".to_owned())];
+            esperado[0].set_votos_recaudados(2);
+            esperado[0].set_votos_ponderados(2);
+            esperado[1].set_estado(EstadoCandidato::Descalificado("Fraude".to_owned()));
+            assert_eq!(Ok(esperado), reporte.reporte_resultado(INSTANCIA, 0));
+            //Los votos anulados cuentan para la participacion
+            let elec = eleccion(0);
+            assert_eq!(Ok(Informe::new(elec.get_id(), elec.get_cargo(), 3, 4, 75, 3, 4, 75, true)), reporte.reporte_participacion(INSTANCIA, 0));
+        }
+
+        #[ink::test]
+        fn test_reporte_participacion_temporal(){
+            // Eleccion de 3 horas con dos votos en la primera hora, ninguno en la segunda y uno en la tercera
+            let mut elec = Eleccion::new(0, "Un cargo".to_owned(), 0, 10_800_000, Fecha::new(1,1,1970,0,0,0), Fecha::new(1,1,1970,3,0,0));
+            elec.set_votantes_aprobados(votantes(4));
+            elec.set_marcas_tiempo_votos(vec![600_000, 1_200_000, 9_000_000]);
+            let mut sin_votantes = Eleccion::new(1, "Otro cargo".to_owned(), 0, 10_800_000, Fecha::new(1,1,1970,0,0,0), Fecha::new(1,1,1970,3,0,0));
+            sin_votantes.set_marcas_tiempo_votos(Vec::new());
+            // Eleccion de 40 dias
+            let mut extensa = Eleccion::new(2, "Otro cargo".to_owned(), 0, 3_456_000_000, Fecha::new(1,1,1970,0,0,0), Fecha::new(10,2,1970,0,0,0));
+            extensa.set_votantes_aprobados(votantes(4));
+            extensa.set_marcas_tiempo_votos(vec![600_000]);
+            let reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![elec, sin_votantes, extensa]));
+            //Participacion acumulada por hora
+            let esperado = vec![
+                ParticipacionIntervalo::new(0, 2, 50),
+                ParticipacionIntervalo::new(3_600_000, 2, 50),
+                ParticipacionIntervalo::new(7_200_000, 3, 75),
+            ];
+            assert_eq!(Ok(esperado), reporte.reporte_participacion_temporal(INSTANCIA, 0, IntervaloParticipacion::Hora));
+            //Por dia la eleccion entra en un solo intervalo
+            assert_eq!(Ok(vec![ParticipacionIntervalo::new(0, 3, 75)]), reporte.reporte_participacion_temporal(INSTANCIA, 0, IntervaloParticipacion::Dia));
+            //Errores por eleccion sin votantes aprobados o inexistente
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_participacion_temporal(INSTANCIA, 1, IntervaloParticipacion::Hora));
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_participacion_temporal(INSTANCIA, 3, IntervaloParticipacion::Hora));
+            //Una eleccion de 40 dias tiene demasiados intervalos por hora, pero puede pedirse por dia
+            assert_eq!(Err(ErrorSistema::IntervalosExcedidos), reporte.reporte_participacion_temporal(INSTANCIA, 2, IntervaloParticipacion::Hora));
+            assert_eq!(Ok(40), reporte.reporte_participacion_temporal(INSTANCIA, 2, IntervaloParticipacion::Dia).map(|intervalos| intervalos.len()));
+        }
+
+        #[ink::test]
+        fn test_reporte_comparativo(){
+            let reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(elecciones_por_cargo()));
+            //Elecciones del mismo cargo ordenadas por fecha, la que no alcanzo el quorum queda sin ganador
+            let esperado = vec![
+                FilaComparativa::new(INSTANCIA.to_owned(), 0, "Presidente".to_owned(), Fecha::new(1,1,2020,0,0,0), Fecha::new(2,1,2020,0,0,0), 4, 3, 75, Some("Mara".to_owned()), 1),
+                FilaComparativa::new(INSTANCIA.to_owned(), 2, "Presidente".to_owned(), Fecha::new(1,1,2024,0,0,0), Fecha::new(2,1,2024,0,0,0), 4, 4, 100, Some("Jorge".to_owned()), 2),
+                FilaComparativa::new(INSTANCIA.to_owned(), 3, "Presidente".to_owned(), Fecha::new(1,1,2028,0,0,0), Fecha::new(2,1,2028,0,0,0), 4, 1, 25, None, 0),
+            ];
+            assert_eq!(Ok(esperado), reporte.reporte_comparativo(CriterioComparacion::Cargo("Presidente".to_owned())));
+            //Elecciones elegidas por ID, el ganador de una eleccion por listas es una lista
+            let esperado = vec![
+                FilaComparativa::new(INSTANCIA.to_owned(), 0, "Presidente".to_owned(), Fecha::new(1,1,2020,0,0,0), Fecha::new(2,1,2020,0,0,0), 4, 3, 75, Some("Mara".to_owned()), 1),
+                FilaComparativa::new(INSTANCIA.to_owned(), 1, "Tesorero".to_owned(), Fecha::new(1,6,2020,0,0,0), Fecha::new(2,6,2020,0,0,0), 4, 4, 100, Some("Lista Roja".to_owned()), 2),
+            ];
+            assert_eq!(Ok(esperado), reporte.reporte_comparativo(CriterioComparacion::Elecciones(vec![(INSTANCIA.to_owned(), 1), (INSTANCIA.to_owned(), 0)])));
+            //Errores por eleccion no finalizada o cargo sin elecciones
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_comparativo(CriterioComparacion::Elecciones(vec![(INSTANCIA.to_owned(), 0), (INSTANCIA.to_owned(), 5)])));
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_comparativo(CriterioComparacion::Cargo("Vocal".to_owned())));
+        }
+
+        #[ink::test]
+        fn test_reporte_rendimiento_candidatos(){
+            // Eleccion con un candidato retirado que conserva sus votos y un empate en el segundo puesto
+            let mut con_retirado = eleccion(0);
+            con_retirado.set_votantes_aprobados(votantes(5));
+            let mut votos = vec![candidato("Jorge", "999", 1, 1), candidato("Mara", "888", 2, 2), candidato("Esteban", "777", 1, 1), candidato("Ana", "666", 1, 1)];
+            votos[3].set_estado(EstadoCandidato::Retirado);
+            con_retirado.set_votos(votos);
+            // Eleccion empatada en el primer puesto
+            let mut empatada = eleccion(1);
+            empatada.set_votantes_aprobados(votantes(5));
+            empatada.set_votos(vec![candidato("Jorge", "999", 2, 2), candidato("Mara", "888", 2, 2)]);
+            // Eleccion ganada por mayoria absoluta
+            let mut mayoria = eleccion(2);
+            mayoria.set_votantes_aprobados(votantes(5));
+            mayoria.set_votos(vec![candidato("Jorge", "999", 3, 3), candidato("Mara", "888", 1, 1)]);
+            // Eleccion donde el candidato con menos votos gana por el peso de sus votantes
+            let mut ponderada = eleccion(3);
+            ponderada.set_votantes_aprobados(votantes(5));
+            ponderada.set_pesos_votantes(vec![PesoVotante::new(AccountId::from([0x1; 32]), 3), PesoVotante::new(AccountId::from([0x2; 32]), 3)]);
+            ponderada.set_votos(vec![candidato("Jorge", "999", 3, 3), candidato("Mara", "888", 2, 6)]);
+            // Eleccion que no alcanzo el quorum
+            let mut sin_quorum = eleccion(4);
+            sin_quorum.set_votantes_aprobados(votantes(5));
+            sin_quorum.set_votos(vec![candidato("Jorge", "999", 3, 3), candidato("Mara", "888", 1, 1)]);
+            sin_quorum.set_quorum_alcanzado(false);
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![con_retirado, empatada, mayoria, ponderada, sin_quorum]));
+            //Los empatados comparten puesto y el candidato retirado queda sin puesto
+            let mut votos = [
+                CandidatoVotos::new("Mara".to_owned(), "888".to_owned()),
+                CandidatoVotos::new("Jorge".to_owned(), "999".to_owned()),
+                CandidatoVotos::new("Esteban".to_owned(), "777".to_owned()),
+                CandidatoVotos::new("Ana".to_owned(), "666".to_owned())
+            ];
+            votos[0].set_votos_recaudados(2);
+            votos[0].set_votos_ponderados(2);
+            votos[1].set_votos_recaudados(1);
+            votos[1].set_votos_ponderados(1);
+            votos[2].set_votos_recaudados(1);
+            votos[2].set_votos_ponderados(1);
+            votos[3].set_votos_recaudados(1);
+            votos[3].set_votos_ponderados(1);
+            votos[3].set_estado(EstadoCandidato::Retirado);
+            let candidatos = vec![
+                RendimientoCandidato::new(Some(1), votos[0].clone(), 40, 40),
+                RendimientoCandidato::new(Some(2), votos[1].clone(), 20, 20),
+                RendimientoCandidato::new(Some(2), votos[2].clone(), 20, 20),
+                RendimientoCandidato::new(None, votos[3].clone(), 20, 20),
+            ];
+            assert_eq!(Ok(ReporteRendimiento::new(0, candidatos, Some("Mara".to_owned()), 1, false, false)), reporte.reporte_rendimiento_candidatos(INSTANCIA, 0));
+            //Empate en el primer puesto
+            let mut votos = [CandidatoVotos::new("Jorge".to_owned(), "999".to_owned()), CandidatoVotos::new("Mara".to_owned(), "888".to_owned())];
+            votos[0].set_votos_recaudados(2);
+            votos[0].set_votos_ponderados(2);
+            votos[1].set_votos_recaudados(2);
+            votos[1].set_votos_ponderados(2);
+            let candidatos = vec![RendimientoCandidato::new(Some(1), votos[0].clone(), 50, 40), RendimientoCandidato::new(Some(1), votos[1].clone(), 50, 40)];
+            assert_eq!(Ok(ReporteRendimiento::new(1, candidatos, None, 0, true, false)), reporte.reporte_rendimiento_candidatos(INSTANCIA, 1));
+            //Ganador por mayoria absoluta
+            let mut votos = [CandidatoVotos::new("Jorge".to_owned(), "999".to_owned()), CandidatoVotos::new("Mara".to_owned(), "888".to_owned())];
+            votos[0].set_votos_recaudados(3);
+            votos[0].set_votos_ponderados(3);
+            votos[1].set_votos_recaudados(1);
+            votos[1].set_votos_ponderados(1);
+            let candidatos = vec![RendimientoCandidato::new(Some(1), votos[0].clone(), 75, 60), RendimientoCandidato::new(Some(2), votos[1].clone(), 25, 20)];
+            assert_eq!(Ok(ReporteRendimiento::new(2, candidatos.clone(), Some("Jorge".to_owned()), 2, false, true)), reporte.reporte_rendimiento_candidatos(INSTANCIA, 2));
+            //Sin quorum no hay ganador ni puestos
+            let sin_puesto = candidatos.into_iter().map(|rendimiento| RendimientoCandidato { puesto: None, ..rendimiento }).collect();
+            assert_eq!(Ok(ReporteRendimiento::new(4, sin_puesto, None, 0, false, false)), reporte.reporte_rendimiento_candidatos(INSTANCIA, 4));
+            //El orden lo deciden los votos ponderados, no la cantidad de votos
+            let mut votos = [CandidatoVotos::new("Mara".to_owned(), "888".to_owned()), CandidatoVotos::new("Jorge".to_owned(), "999".to_owned())];
+            votos[0].set_votos_recaudados(2);
+            votos[0].set_votos_ponderados(6);
+            votos[1].set_votos_recaudados(3);
+            votos[1].set_votos_ponderados(3);
+            let candidatos = vec![RendimientoCandidato::new(Some(1), votos[0].clone(), 66, 66), RendimientoCandidato::new(Some(2), votos[1].clone(), 33, 33)];
+            assert_eq!(Ok(ReporteRendimiento::new(3, candidatos, Some("Mara".to_owned()), 3, false, true)), reporte.reporte_rendimiento_candidatos(INSTANCIA, 3));
+            //Errores por eleccion inexistente o sin votos
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_rendimiento_candidatos(INSTANCIA, 5));
+            reporte.set_sistema(INSTANCIA, SistemaVotacionFake::new(vec![eleccion(0)]));
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_rendimiento_candidatos(INSTANCIA, 0));
+        }
+
+        #[ink::test]
+        fn test_reporte_abstencion(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            // Cinco votantes aprobados de los que votaron Juan y Franco
+            let mut elec = eleccion(0);
+            elec.set_votantes_votados(vec![usuario(0x2, "Juan", "222"), usuario(0x4, "Franco", "444")]);
+            elec.set_votantes_aprobados(votantes(5));
+            let reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![elec]));
+            let pepe = Usuario::new(AccountId::from([0x1; 32]), "Pepe".to_owned(), "111".to_owned(), Hash::default());
+            let lucia = Usuario::new(AccountId::from([0x3; 32]), "Lucia".to_owned(), "333".to_owned(), Hash::default());
+            let sofia = Usuario::new(AccountId::from([0x5; 32]), "Sofia".to_owned(), "555".to_owned(), Hash::default());
+            //Los que no votaron se devuelven paginados
+            assert_eq!(Ok(ReporteAbstencion::new(0, 5, 2, 3, 0, 2, vec![pepe, lucia])), reporte.reporte_abstencion(INSTANCIA, 0, 0, 2));
+            assert_eq!(Ok(ReporteAbstencion::new(0, 5, 2, 3, 1, 2, vec![sofia])), reporte.reporte_abstencion(INSTANCIA, 0, 1, 2));
+            assert_eq!(Ok(ReporteAbstencion::new(0, 5, 2, 3, 2, 2, Vec::new())), reporte.reporte_abstencion(INSTANCIA, 0, 2, 2));
+            //Errores por paginacion invalida o eleccion no finalizada
+            assert_eq!(Err(ErrorSistema::PaginacionInvalida), reporte.reporte_abstencion(INSTANCIA, 0, 0, 0));
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_abstencion(INSTANCIA, 1, 0, 2));
+            //Solo el admin del sistema puede ver el reporte
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorSistema::NoSePoseenPermisos), reporte.reporte_abstencion(INSTANCIA, 0, 0, 2));
+        }
+
+        #[ink::test]
+        fn test_reporte_embudo_inscripcion(){
+            let votados = vec![usuario(0x2, "Juan", "222"), usuario(0x4, "Franco", "444")];
+            let mut elec = eleccion(0);
+            elec.set_votantes_registrados(vec![usuario(0x5, "Sofia", "555")]);
+            elec.set_votantes_votados(votados.clone());
+            elec.set_votantes_aprobados(votantes(4));
+            elec.set_candidatos_registrados(vec![usuario(0x6, "Ana", "666")]);
+            elec.set_candidatos_aprobados(vec![usuario(0x7, "Jorge", "777"), usuario(0x8, "Mara", "888")]);
+            // Eleccion por listas, los candidatos se inscriben como miembros de una lista
+            let mut por_listas = eleccion(1);
+            por_listas.set_votantes_votados(votados);
+            por_listas.set_votantes_aprobados(votantes(4));
+            let mut pendiente = Lista::new("Lista Verde".to_owned(), AccountId::from([0x9; 32]));
+            pendiente.set_miembros(vec![usuario(0x9, "Luis", "999")]);
+            let mut aprobada = Lista::new("Lista Azul".to_owned(), AccountId::from([0xA; 32]));
+            aprobada.set_miembros(vec![usuario(0xA, "Bruno", "101"), usuario(0xB, "Carla", "102")]);
+            por_listas.set_peticiones_listas(vec![pendiente]);
+            por_listas.set_listas_aprobadas(vec![aprobada]);
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![elec, por_listas]));
+            //Usuarios y conversion en cada etapa
+            assert_eq!(Ok(EmbudoInscripcion::new(0, 1, 4, 2, 1, 2, 80, 50, 66)), reporte.reporte_embudo_inscripcion(INSTANCIA, 0));
+            //En una eleccion por listas los candidatos son los miembros de las listas
+            assert_eq!(Ok(EmbudoInscripcion::new(1, 0, 4, 2, 1, 2, 100, 50, 66)), reporte.reporte_embudo_inscripcion(INSTANCIA, 1));
+            //Una eleccion sin inscriptos tiene conversion 0
+            reporte.set_sistema(INSTANCIA, SistemaVotacionFake::new(vec![eleccion(0)]));
+            assert_eq!(Ok(EmbudoInscripcion::new(0, 0, 0, 0, 0, 0, 0, 0, 0)), reporte.reporte_embudo_inscripcion(INSTANCIA, 0));
+            //Error por eleccion no finalizada
+            reporte.set_sistema(INSTANCIA, SistemaVotacionFake::new(Vec::new()));
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_embudo_inscripcion(INSTANCIA, 0));
+        }
+
+        #[ink::test]
+        fn test_instantanea_reportes(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![eleccion_con_pesos()]));
+            let elec = eleccion(0);
+            let informe = Informe::new(elec.get_id(), elec.get_cargo(), 3, 4, 75, 9, 10, 90, true);
+            let mut votos = vec![CandidatoVotos::new("Jorge".to_owned(), "999".to_owned()), CandidatoVotos::new("Mara".to_owned(), "888".to_owned())];
+            votos[0].set_votos_recaudados(2);
+            votos[0].set_votos_ponderados(8);
+            votos[1].set_votos_recaudados(1);
+            votos[1].set_votos_ponderados(1);
+            //La primera consulta de un reporte guarda la instantanea de la eleccion finalizada
+            assert_eq!(None, reporte.buscar_instantanea(INSTANCIA, 0));
+            assert_eq!(Ok(informe.clone()), reporte.reporte_participacion(INSTANCIA, 0));
+            assert_eq!(Some(InstantaneaEleccion::new(0, Some(informe.clone()), Some(votos.clone()))), reporte.buscar_instantanea(INSTANCIA, 0));
+            //Solo el owner vuelve a calcular la instantanea, de un sistema registrado
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorSistema::NoSePoseenPermisos), reporte.actualizar_instantanea(INSTANCIA, 0));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorSistema::SistemaNoRegistrado), reporte.actualizar_instantanea("Ninguna", 0));
+            assert_eq!(Ok(InstantaneaEleccion::new(0, Some(informe.clone()), Some(votos.clone()))), reporte.actualizar_instantanea(INSTANCIA, 0));
+            //Los reportes se responden desde la instantanea aunque el sistema ya no devuelva la eleccion
+            reporte.set_sistema(INSTANCIA, SistemaVotacionFake::new(Vec::new()));
+            assert_eq!(Ok(informe.clone()), reporte.reporte_participacion(INSTANCIA, 0));
+            assert_eq!(Ok(votos.clone()), reporte.reporte_resultado(INSTANCIA, 0));
+            //Actualizar una eleccion que no esta finalizada falla y conserva la instantanea
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.actualizar_instantanea(INSTANCIA, 0));
+            assert_eq!(Some(InstantaneaEleccion::new(0, Some(informe.clone()), Some(votos.clone()))), reporte.buscar_instantanea(INSTANCIA, 0));
+            //La instantanea se reemplaza al actualizarla, y conserva que no hay resultados disponibles
+            reporte.set_sistema(INSTANCIA, SistemaVotacionFake::new(vec![eleccion(0)]));
+            assert_eq!(Ok(InstantaneaEleccion::new(0, None, None)), reporte.actualizar_instantanea(INSTANCIA, 0));
+            assert_eq!(Some(InstantaneaEleccion::new(0, None, None)), reporte.buscar_instantanea(INSTANCIA, 0));
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_participacion(INSTANCIA, 0));
+            assert_eq!(Err(ErrorSistema::ResultadosNoDisponibles), reporte.reporte_resultado(INSTANCIA, 0));
+            //Al apuntar el nombre a otro contrato no se usan las instantaneas del anterior, aunque repita el ID de la eleccion
+            assert_eq!(Ok(()), reporte.actualizar_sistema(INSTANCIA, SistemaVotacionFake::new(vec![eleccion_sin_votantes()])));
+            assert_eq!(None, reporte.buscar_instantanea(INSTANCIA, 0));
+            let mut esperado = vec![
+                CandidatoVotos::new("Mara".to_owned(), "888".to_owned()),
+                CandidatoVotos::new("Jorge".to_owned(), "999".to_owned()),
+                CandidatoVotos::new("Esteban".to_owned(), "777".to_owned())
+            ];
+            esperado[0].set_votos_recaudados(19);
+            esperado[1].set_votos_recaudados(5);
+            esperado[2].set_votos_recaudados(3);
+            assert_eq!(Ok(esperado), reporte.reporte_resultado(INSTANCIA, 0));
+        }
+
+        #[ink::test]
+        fn test_registro_sistemas(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(elecciones_por_cargo()));
+            //El owner registra un segundo sistema, el nombre no puede repetirse
+            assert_eq!(Ok(()), reporte.registrar_sistema("Otra".to_owned(), SistemaVotacionFake::new(elecciones_por_cargo())));
+            assert_eq!(Err(ErrorSistema::SistemaYaRegistrado), reporte.registrar_sistema("Otra".to_owned(), SistemaVotacionFake::new(Vec::new())));
+            assert_eq!(vec![INSTANCIA.to_owned(), "Otra".to_owned()], reporte.get_sistemas_registrados());
+            //Los reportes de una eleccion se piden al sistema indicado
+            assert_eq!(Ok(4), reporte.reporte_participacion("Otra", 0).map(|informe| informe.votos_totales));
+            assert_eq!(Err(ErrorSistema::SistemaNoRegistrado), reporte.reporte_participacion("Ninguna", 0));
+            //El reporte comparativo por cargo recorre todos los sistemas, ordenado por fecha
+            let filas = reporte.reporte_comparativo(CriterioComparacion::Cargo("Presidente".to_owned())).unwrap();
+            assert_eq!(6, filas.len());
+            assert_eq!((INSTANCIA.to_owned(), 0), (filas[0].instancia.clone(), filas[0].eleccion_id));
+            assert_eq!(("Otra".to_owned(), 0), (filas[1].instancia.clone(), filas[1].eleccion_id));
+            let criterio = CriterioComparacion::Elecciones(vec![("Otra".to_owned(), 1), ("Ninguna".to_owned(), 0)]);
+            assert_eq!(Err(ErrorSistema::SistemaNoRegistrado), reporte.reporte_comparativo(criterio));
+            //Solo el owner administra los sistemas
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorSistema::NoSePoseenPermisos), reporte.registrar_sistema("Tercera".to_owned(), SistemaVotacionFake::new(Vec::new())));
+            assert_eq!(Err(ErrorSistema::NoSePoseenPermisos), reporte.quitar_sistema("Otra"));
+            //Al quitar un sistema dejan de pedirse sus reportes
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(()), reporte.quitar_sistema("Otra"));
+            assert_eq!(Err(ErrorSistema::SistemaNoRegistrado), reporte.reporte_participacion("Otra", 0));
+            assert_eq!(Err(ErrorSistema::SistemaNoRegistrado), reporte.quitar_sistema("Otra"));
+            assert_eq!(vec![INSTANCIA.to_owned()], reporte.get_sistemas_registrados());
+        }
+
+        #[ink::test]
+        fn test_control_acceso_reporte(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut reporte = Reporte::new_fake(INSTANCIA.to_owned(), SistemaVotacionFake::new(vec![eleccion(0)]));
+            let esperado = ReporteDetalleVotante::new(0, Vec::new(), Vec::new());
+            //Sin restriccion cualquiera ve los reportes con datos de votantes
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Ok(esperado.clone()), reporte.reporte_registrados_aprobados(INSTANCIA, 0));
+            //Solo el owner administra las restricciones y los sistemas
+            assert_eq!(Err(ErrorSistema::NoSePoseenPermisos), reporte.set_reportes_restringidos(true));
+            assert_eq!(Err(ErrorSistema::NoSePoseenPermisos), reporte.agregar_lector(accounts.bob));
+            assert_eq!(Err(ErrorSistema::NoSePoseenPermisos), reporte.actualizar_sistema(INSTANCIA, SistemaVotacionFake::new(Vec::new())));
+            //Con la restriccion activa solo el owner y los lectores autorizados ven el reporte
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(()), reporte.set_reportes_restringidos(true));
+            assert_eq!(Ok(esperado.clone()), reporte.reporte_registrados_aprobados(INSTANCIA, 0));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorSistema::NoSePoseenPermisos), reporte.reporte_registrados_aprobados(INSTANCIA, 0));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(()), reporte.agregar_lector(accounts.bob));
+            assert_eq!(Err(ErrorSistema::LectorYaAutorizado), reporte.agregar_lector(accounts.bob));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Ok(esperado.clone()), reporte.reporte_registrados_aprobados(INSTANCIA, 0));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(()), reporte.quitar_lector(accounts.bob));
+            assert_eq!(Err(ErrorSistema::LectorNoAutorizado), reporte.quitar_lector(accounts.bob));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorSistema::NoSePoseenPermisos), reporte.reporte_registrados_aprobados(INSTANCIA, 0));
+            //Al desactivar la restriccion vuelve a ser publico
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(()), reporte.set_reportes_restringidos(false));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Ok(esperado), reporte.reporte_registrados_aprobados(INSTANCIA, 0));
+            //El owner reemplaza el sistema de un nombre registrado
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(()), reporte.actualizar_sistema(INSTANCIA, SistemaVotacionFake::new(Vec::new())));
+            assert_eq!(Err(ErrorSistema::EleccionInvalida), reporte.reporte_registrados_aprobados(INSTANCIA, 0));
+            assert_eq!(Err(ErrorSistema::SistemaNoRegistrado), reporte.actualizar_sistema("Ninguna", SistemaVotacionFake::new(vec![eleccion(0)])));
         }
     }
 }
@@ -19,8 +19,24 @@ mod sistema_votacion {
         elecciones: Vec<Eleccion>,
         elecciones_finiquitadas: Vec<Eleccion>,
         elecciones_conteo_id: u64,
+        plantillas_eleccion: Vec<PlantillaEleccion>, // El id de cada plantilla es su posicion, las plantillas no se eliminan
+        eventos_elecciones: Vec<EventoElecciones>, // El id de cada evento es su posicion, los eventos no se eliminan
 
         peticiones_registro: Vec<Usuario>, // Peticiones en espera de aprobación
+        historial_retiros: Vec<RetiroPeticion>, // Peticiones canceladas por los propios usuarios
+        peticiones_cambio_perfil: Vec<Usuario>, // Datos propuestos por usuarios aprobados, en espera de revision
+
+        indice_dni: Vec<RegistroDni>, // Cada dni pertenece a una unica cuenta, ya sea aprobada o en peticiones
+        migraciones_cuentas: Vec<MigracionCuenta>, // En orden cronologico, permiten seguir a un usuario desde las elecciones finiquitadas
+        usuarios_suspendidos: Vec<AccountId>, // Siguen registrados pero no pueden operar hasta ser reactivados
+
+        atestadores: Vec<[u8; 33]>, // Claves publicas ECDSA comprimidas de los servicios de verificacion de identidad de confianza
+        auditores: Vec<AccountId>, // Pueden consultar el historial de participacion de cualquier usuario
+
+        sal_dni: Hash, // Sal con la que se calcula el hash de los dni, los dni en texto plano no se almacenan. La sal es publica, ver calcular_hash_dni
+
+        votos_emitidos_totales: u64, // Se incrementa con cada voto para no recorrer las elecciones al consultar estadisticas
+        suma_participacion_finiquitadas: u128, // Suma de los porcentajes de participacion de cada eleccion al finalizarla
     }
 
     impl SistemaVotacion {
@@ -31,15 +47,32 @@ mod sistema_votacion {
         #[ink(constructor)]
         pub fn new(nombre_admin: String, dni_admin: String) -> Self {
             let admin_account_id = Self::env().caller();
-            let admin_user = Usuario::new(admin_account_id, nombre_admin, dni_admin);
+
+            let mut sal = <ink::env::hash::Blake2x256 as ink::env::hash::HashOutput>::Type::default();
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&(Self::env().account_id(), Self::env().block_timestamp(), admin_account_id), &mut sal);
+            let sal_dni = Hash::from(sal);
+
+            let admin_user = Usuario::new(admin_account_id, nombre_admin, dni_admin, sal_dni);
 
             Self {
                 admin_id: admin_account_id,
-                usuarios_registados: Vec::from([admin_user]),
+                usuarios_registados: Vec::from([admin_user.clone()]),
                 elecciones: Vec::new(),
                 elecciones_finiquitadas: Vec::new(),
                 elecciones_conteo_id: 0,
-                peticiones_registro: Vec::new()
+                plantillas_eleccion: Vec::new(),
+                eventos_elecciones: Vec::new(),
+                peticiones_registro: Vec::new(),
+                historial_retiros: Vec::new(),
+                peticiones_cambio_perfil: Vec::new(),
+                indice_dni: Vec::from([RegistroDni::new(admin_user.dni_hash, admin_account_id)]),
+                migraciones_cuentas: Vec::new(),
+                usuarios_suspendidos: Vec::new(),
+                atestadores: Vec::new(),
+                auditores: Vec::new(),
+                sal_dni,
+                votos_emitidos_totales: 0,
+                suma_participacion_finiquitadas: 0,
             }
         }
 
@@ -47,8 +80,8 @@ mod sistema_votacion {
         /// Se le pasan por parametros el nombre del usuario y su DNI
         /// Y toma como AccountId al id del usuario que llama a la funcion
         /// La funcion retorna un Result<(), ErrorInterfaz>
-        /// los casos de error pueden ser si el usuario ya esta registrado en la cola de espera, si el usuario es el admin 
-        /// o si el usuario ya fue aprobado en el sistema, si no se cumple ninguna de esas condiciones el usuario se registra en el sistema 
+        /// los casos de error pueden ser si el usuario ya esta registrado en la cola de espera, si el usuario es el admin,
+        /// si el usuario ya fue aprobado en el sistema o si el dni ya pertenece a otra cuenta, si no se cumple ninguna de esas condiciones el usuario se registra en el sistema 
         #[ink(message)]
         pub fn registrarse_en_sistema(&mut self, user_nombre: String, user_dni: String) -> Result<(), ErrorInterfaz>
         {
@@ -62,2067 +95,6196 @@ mod sistema_votacion {
                 return Err(ErrorInterfaz::new(error))
             }
 
-            let user = Usuario::new(caller_id, user_nombre, user_dni);
+            let user = Usuario::new(caller_id, user_nombre, user_dni, self.sal_dni);
+            if let Err(error) = self.validar_dni_disponible(user.dni_hash, caller_id) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
             self.registrar_en_cola_de_sistema(user);
 
             Ok(())
         }
 
-        ///LE PERMITE AL ADMIN VER UNA LISTA DE TODOS LOS USUARIOS EN LA COLA DE ESPERA DEL SISTEMA
-        /// La funcion no recibe parametros, y devuelve un Result<Vec<Usuario>,ErrorInterfaz>
-        /// Retorna un error siempre que el usuario que invoque la funcion no sea el admin
+        /// PERMITE QUE UN USUARIO SE REGISTRE Y QUEDE APROBADO EN EL SISTEMA PRESENTANDO LA FIRMA DE UN ATESTADOR DE CONFIANZA
+        /// Se le pasan por parametros el nombre del usuario, su DNI y la firma ECDSA (65 bytes) de un atestador registrado por el admin
+        /// La firma debe ser sobre el hash Blake2x256 de la tupla (AccountId, dni, nombre) codificada en SCALE, donde el AccountId es el de quien llama a la funcion
+        /// Si la firma corresponde a un atestador registrado el usuario queda aprobado sin pasar por la cola de espera
+        /// La funcion retorna un Result<(), ErrorInterfaz>, los casos de error son los de registrarse_en_sistema, ademas de cuando la firma no es valida
+        /// o no pertenece a ningun atestador registrado
         #[ink(message)]
-        pub fn get_peticiones_de_registro_sistema(&self) -> Result<Vec<Usuario>, ErrorInterfaz> 
+        pub fn registrarse_en_sistema_con_atestacion(&mut self, user_nombre: String, user_dni: String, firma: [u8; 65]) -> Result<(), ErrorInterfaz>
         {
-            self.get_peticiones_de_registro_sistema_priv()
+            self.registrarse_en_sistema_con_atestacion_priv(user_nombre, user_dni, firma)
         }
 
-        fn get_peticiones_de_registro_sistema_priv(&self) -> Result<Vec<Usuario>, ErrorInterfaz> 
+        fn registrarse_en_sistema_con_atestacion_priv(&mut self, user_nombre: String, user_dni: String, firma: [u8; 65]) -> Result<(), ErrorInterfaz>
         {
-            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+            let caller_id = Self::env().caller();
+            if let Err(error) = self.consultar_inexistencia_usuario_en_sistema(caller_id) {
                 return Err(ErrorInterfaz::new(error))
             }
 
-            Ok(self.peticiones_registro.clone())
+            if let Err(error) = self.validar_atestacion(caller_id, &user_dni, &user_nombre, &firma) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            let user = Usuario::new(caller_id, user_nombre, user_dni, self.sal_dni);
+            if let Err(error) = self.validar_dni_disponible(user.dni_hash, caller_id) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            self.registrar_en_cola_de_sistema(user);
+            self.aprobar_usuario(caller_id);
+
+            Ok(())
         }
 
-        /// LE PERMITE AL ADMIN VALIDAR A UN USUARIO EN EL SISTEMA
-        /// La funcion recibe como parametro el AccountId de un usuario
-        /// Si quien invoca a la funcion es el admin, la funcion valida que el accountId por parametro este registrado en el sistema
-        /// de ser el caso el usuario queda validado para acceder a las funcionalidades del mismo
-        /// si la llamada la realiza un usuario no admin la funcion devuelve un ErrorInterfaz
-        /// si el accountId ya fue validado o no existe en el sistema la funcion tambien devuelve un ErrorInterfaz
+        /// LE PERMITE AL ADMIN REGISTRAR UN ATESTADOR DE CONFIANZA
+        /// La funcion recibe la clave publica ECDSA comprimida (33 bytes) del atestador y retorna un Result<(), ErrorInterfaz>
+        /// Los casos de error se dan si quien invoca no es el admin o si el atestador ya estaba registrado
         #[ink(message)]
-        pub fn aprobar_usuario_sistema(&mut self, usuar_account_id: AccountId) -> Result<(), ErrorInterfaz>
+        pub fn agregar_atestador(&mut self, clave_publica: [u8; 33]) -> Result<(), ErrorInterfaz>
         {
-            self.aprobar_usuario_sistema_priv(usuar_account_id)
+            self.agregar_atestador_priv(clave_publica)
         }
 
-        fn aprobar_usuario_sistema_priv(&mut self, usuar_account_id: AccountId) -> Result<(), ErrorInterfaz>
+        fn agregar_atestador_priv(&mut self, clave_publica: [u8; 33]) -> Result<(), ErrorInterfaz>
         {
             if let Err(error) = self.validar_permisos(Self::env().caller()) {
                 return Err(ErrorInterfaz::new(error))
             }
 
-            if let Err(error) = self.consultar_peticion_sistema(usuar_account_id) {
-                return Err(ErrorInterfaz::new(error))
+            if self.atestadores.contains(&clave_publica) {
+                return Err(ErrorInterfaz::new(ErrorSistema::AtestadorYaRegistrado))
             }
 
-            self.aprobar_usuario(usuar_account_id);
-
+            self.atestadores.push(clave_publica);
             Ok(())
         }
 
-        /// LE PERMITE AL ADMIN TRASPASAR SU ROL A OTRO USUARIO
-        /// La funcion recibe por parametros el AccountId, nombre y dni del nuevo admin y retorna un Result<(),ErrorInterfaz>
-        /// Si quien invoca la funcion es el admin la funcion registra al nuevo admin en caso de que no este registrado
-        /// y despues reemplaza el accountId del admin actual por el accountId enviado por parametro
-        /// La funcion retorna un ErrorInterfaz si el usuario que la invoca no es el admin
+        /// LE PERMITE AL ADMIN QUITAR UN ATESTADOR, SUS FIRMAS DEJAN DE SER ACEPTADAS
+        /// La funcion recibe la clave publica ECDSA comprimida del atestador y retorna un Result<(), ErrorInterfaz>
+        /// Los casos de error se dan si quien invoca no es el admin o si el atestador no esta registrado
         #[ink(message)]
-        pub fn delegar_admin(&mut self, nuevo_admin_acc_id: AccountId, nuevo_admin_nombre: String, nuevo_admin_dni: String) -> Result<(), ErrorInterfaz>
+        pub fn quitar_atestador(&mut self, clave_publica: [u8; 33]) -> Result<(), ErrorInterfaz>
         {
-            self.delegar_admin_priv(nuevo_admin_acc_id, nuevo_admin_nombre, nuevo_admin_dni)
+            self.quitar_atestador_priv(clave_publica)
         }
 
-        fn delegar_admin_priv(&mut self, nuevo_admin_acc_id: AccountId, nuevo_admin_nombre: String, nuevo_admin_dni: String) -> Result<(), ErrorInterfaz>
+        fn quitar_atestador_priv(&mut self, clave_publica: [u8; 33]) -> Result<(), ErrorInterfaz>
         {
             if let Err(error) = self.validar_permisos(Self::env().caller()) {
                 return Err(ErrorInterfaz::new(error))
             }
 
-            self.corregir_estado_nuevo_admin(nuevo_admin_acc_id, nuevo_admin_nombre, nuevo_admin_dni);
+            let index = match self.atestadores.iter().position(|a| *a == clave_publica) {
+                Some(index) => index,
+                None => return Err(ErrorInterfaz::new(ErrorSistema::AtestadorNoExiste))
+            };
 
-            self.admin_id = nuevo_admin_acc_id;
+            self.atestadores.remove(index);
             Ok(())
         }
 
-        //////////////////// ELECCIONES ////////////////////
-
-        /// LE PERMITE AL ADMIN CREAR UNA NUEVA ELECCION
-        /// 
-        /// #uso
-        /// La funcion recibe por parametro el cargo, fecha de inicio y fecha de cierre para la eleccion y devuelve un Result<(), ErrorInterfaz>
-        /// 
-        /// #funcionalidad
-        /// Si el usuario que invoca la funcion es el admin, y las fechas de inicio no es anterior al dia de la fecha y la de cierre no es anterior a la de inicio
-        /// se valida el incremento a los id de eleccion para evitar desbordes, se crea la nueva eleccion y se agrega a la lista de elecciones actuales.
-        /// 
-        /// #Errores
-        /// Se devuelve un ErrorInterfaz si quien invoca la funcion no es el admin, o si las fechas no cumplen las condiciones antes mencionadas
-        /// 
-        /// ...
+        /// LE PERMITE AL ADMIN REGISTRAR UN AUDITOR, QUE PUEDE CONSULTAR EL HISTORIAL DE PARTICIPACION DE CUALQUIER USUARIO
+        /// La funcion recibe el AccountId del auditor y retorna un Result<(), ErrorInterfaz>
+        /// Los casos de error se dan si quien invoca no es el admin o si el auditor ya estaba registrado
         #[ink(message)]
-        pub fn crear_nueva_eleccion(&mut self, cargo: String, fecha_inicio: Fecha, fecha_cierre: Fecha) -> Result<(), ErrorInterfaz>
+        pub fn agregar_auditor(&mut self, auditor_id: AccountId) -> Result<(), ErrorInterfaz>
         {
-            self.crear_nueva_eleccion_priv(cargo,fecha_inicio,fecha_cierre)
+            self.agregar_auditor_priv(auditor_id)
         }
 
-        fn crear_nueva_eleccion_priv(&mut self, cargo: String, fecha_inicio: Fecha, fecha_cierre: Fecha) -> Result<(), ErrorInterfaz>
+        fn agregar_auditor_priv(&mut self, auditor_id: AccountId) -> Result<(), ErrorInterfaz>
         {
             if let Err(error) = self.validar_permisos(Self::env().caller()) {
                 return Err(ErrorInterfaz::new(error))
             }
 
-            if let Err(error) = fecha_inicio.validar_fecha() {
-                return Err(ErrorInterfaz::new(ErrorSistema::FechaInicioInvalida(error)));
-            }
-
-            if let Err(error) = fecha_cierre.validar_fecha() {
-                return Err(ErrorInterfaz::new(ErrorSistema::FechaCierreInvalida(error)));
-            }
-
-            if fecha_cierre.fecha_pasada(fecha_inicio.to_timestamp()) {
-                return Err(ErrorInterfaz::new(ErrorSistema::FechaCierreAntesInicio));
+            if self.auditores.contains(&auditor_id) {
+                return Err(ErrorInterfaz::new(ErrorSistema::AuditorYaRegistrado))
             }
 
-            if fecha_inicio.fecha_pasada(Self::env().block_timestamp()) {
-                return Err(ErrorInterfaz::new(ErrorSistema::FechaInicioPasada));
-            }
+            self.auditores.push(auditor_id);
+            Ok(())
+        }
 
-            if fecha_cierre.fecha_pasada(Self::env().block_timestamp()) {
-                return Err(ErrorInterfaz::new(ErrorSistema::FechaCierrePasada));
-            }
-            
-            let eleccion = Eleccion::new(
-                self.elecciones_conteo_id,
-                cargo,
-                fecha_inicio.to_timestamp(),
-                fecha_cierre.to_timestamp(),
-                fecha_inicio,
-                fecha_cierre,
-            );
+        /// LE PERMITE AL ADMIN QUITAR UN AUDITOR
+        /// La funcion recibe el AccountId del auditor y retorna un Result<(), ErrorInterfaz>
+        /// Los casos de error se dan si quien invoca no es el admin o si el auditor no esta registrado
+        #[ink(message)]
+        pub fn quitar_auditor(&mut self, auditor_id: AccountId) -> Result<(), ErrorInterfaz>
+        {
+            self.quitar_auditor_priv(auditor_id)
+        }
 
-            if let Err(error) = self.check_add_elecciones_id() {
+        fn quitar_auditor_priv(&mut self, auditor_id: AccountId) -> Result<(), ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
                 return Err(ErrorInterfaz::new(error))
             }
 
-            self.elecciones.push(eleccion);
+            let index = match self.auditores.iter().position(|a| *a == auditor_id) {
+                Some(index) => index,
+                None => return Err(ErrorInterfaz::new(ErrorSistema::AuditorNoExiste))
+            };
 
+            self.auditores.remove(index);
             Ok(())
         }
 
-
-        ///LE PERMITE AL ADMIN CERRAR UNA ELECCION FINALIZADA Y CONTAR LOS VOTOS
-        ///
-        ///#Uso
-        ///Al un admin llamar a la funcion con un id de una eleccion cerrada, pero que todavia esta dentro de la lista de elecciones activas,
-        ///esta es movida a la lista de elecciones finalizadas y los votos son contados.
-        ///Los candidatos quedan ordenados por cantidad de votos, de mayor a menor, dentro de el campo de votos en la eleccion.
-        ///El ganador tambien es devuelto con sus datos, como nombre, dni, y cantidad de votos.
-        ///Si no hay cantidatos o votos se devuelve un resultado vacio.
-        ///
-        ///#Funcionalidad
-        ///La funcion chequea si el caller es admin, despues encuentra la eleccion, si es que existe. Detecta si hay votos en la eleccion,
-        ///si no hay se devuelve un error, y si hay los ordena por cantidad de votos, de mayor a menor. Por ultimo mueve la eleccion a la
-        ///lista de elecciones finalizadas, y devuelve una copia de los datos del ganador.
-        ///
-        ///#Errores
-        ///Devuelve un error por la falta de privilegios de admin de ErrorSistem::NoPoseenPermisos, y un ErrorEleccion
-        ///para indicar una eleccion invalida.
+        ///LE PERMITE AL ADMIN VER UNA LISTA DE TODOS LOS USUARIOS EN LA COLA DE ESPERA DEL SISTEMA
+        /// La funcion no recibe parametros, y devuelve un Result<Vec<Usuario>,ErrorInterfaz>
+        /// Retorna un error siempre que el usuario que invoque la funcion no sea el admin
         #[ink(message)]
-        pub fn finalizar_y_contar_eleccion(&mut self, eleccion_id: u64) -> Result<CandidatoVotos, ErrorInterfaz>
+        pub fn get_peticiones_de_registro_sistema(&self) -> Result<Vec<Usuario>, ErrorInterfaz> 
         {
-            self.finalizar_y_contar_eleccion_priv(eleccion_id)
+            self.get_peticiones_de_registro_sistema_priv()
         }
 
-        fn finalizar_y_contar_eleccion_priv(&mut self, eleccion_id: u64) -> Result<CandidatoVotos, ErrorInterfaz>
+        fn get_peticiones_de_registro_sistema_priv(&self) -> Result<Vec<Usuario>, ErrorInterfaz> 
         {
             if let Err(error) = self.validar_permisos(Self::env().caller()) {
                 return Err(ErrorInterfaz::new(error))
             }
 
-            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::Cerrada,  Self::env().block_timestamp()) {
-                Ok(index) => index,
-                Err(error) => return Err(ErrorInterfaz::new(error))
-            };
-            let mut eleccion = self.elecciones.swap_remove(eleccion_index);
-            if eleccion.votos.is_empty() {
-                self.elecciones_finiquitadas.push(eleccion.clone());
-                return Ok(CandidatoVotos::new("Vacio".to_owned(), "Vacio".to_owned()))
-            }
-            eleccion.votos.sort_by_key(|candidato| candidato.votos_recaudados);
-            eleccion.votos.reverse();
-            self.elecciones_finiquitadas.push(eleccion.clone());
-            Ok(eleccion.votos[0].clone())
+            Ok(self.peticiones_registro.clone())
         }
 
-
-
-        /// LE PERMITE A CUALQUIER USUARIO APROBADO ACCEDER A UNA LISTA DE LAS ELECCIONES EN CURSO
-        /// 
-        /// #Uso
-        /// La funcion no recibe parametros y retorna un Result<Vec<EleccionInterfaz>,ErrorInterfaz>
-        /// 
-        /// #Funcionalidad
-        /// Si quien invoca la funcion es un usuario valido en el sistema se genera un lista de las elecciones actuales en formato de interfaz y se retorna.
-        /// 
-        /// #Errores
-        /// La funcion devuelve un ErrorInterfaz si quien la invoca no esta registrado o validado en el sistema.
-        /// 
-        /// ...
+        /// LE PERMITE A UN USUARIO CANCELAR SU PROPIA PETICION DE REGISTRO EN EL SISTEMA
+        /// La funcion no recibe parametros y retorna un Result<(), ErrorInterfaz>
+        /// Si quien invoca la funcion esta en la cola de espera del sistema, se lo quita de ella y el retiro queda registrado en el historial,
+        /// pudiendo volver a registrarse con otros datos
+        /// Los casos de error se dan si el usuario ya fue aprobado o si no existe en el sistema
         #[ink(message)]
-        pub fn get_elecciones_actuales(&mut self) -> Result<Vec<EleccionInterfaz>, ErrorInterfaz> 
+        pub fn cancelar_peticion_sistema(&mut self) -> Result<(), ErrorInterfaz>
         {
-            self.get_elecciones_actuales_priv()
+            self.cancelar_peticion_sistema_priv()
         }
 
-        fn get_elecciones_actuales_priv(&mut self) -> Result<Vec<EleccionInterfaz>, ErrorInterfaz>
+        fn cancelar_peticion_sistema_priv(&mut self) -> Result<(), ErrorInterfaz>
         {
-            if let Err(error) = self.validar_caller_como_admin_o_usuario_aprobado(Self::env().caller()) {
+            let caller_id = Self::env().caller();
+            if let Err(error) = self.consultar_peticion_sistema(caller_id) {
                 return Err(ErrorInterfaz::new(error))
             }
-            Ok(self.clonar_elecciones_actuales_a_interfaz(Self::env().block_timestamp()))
+
+            self.retirar_peticion_sistema(caller_id);
+
+            Ok(())
         }
 
-        /// LE PERMITE A CUALQUIER USUARIO APROBADO VER UNA LISTA DE TODAS LAS ELECCIONES FINALIZADAS
-        /// 
-        /// #Uso
-        /// La funcion no recibe parametros y retorna un Result<Vec<EleccionInterfaz>,ErrorInterfaz>
-        /// 
-        /// #Funcionalidad
-        /// Si quien invoca la funcion es un usuario valido en el sistema se genera un lista de las elecciones finalizadas en formato de interfaz y se retorna.
-        /// 
-        /// #Errores
-        /// La funcion devuelve un ErrorInterfaz si quien la invoca no esta registrado o validado en el sistema.
-        /// 
-        /// ...
+        ///LE PERMITE AL ADMIN VER EL HISTORIAL DE PETICIONES CANCELADAS POR LOS USUARIOS
+        /// La funcion no recibe parametros, y devuelve un Result<Vec<RetiroPeticion>,ErrorInterfaz>
+        /// Incluye tanto las peticiones de registro al sistema como las de votante o candidato en elecciones
+        /// Retorna un error siempre que el usuario que invoque la funcion no sea el admin
         #[ink(message)]
-        pub fn get_elecciones_historial(&mut self) -> Result<Vec<EleccionInterfaz>, ErrorInterfaz>
+        pub fn get_historial_retiros(&self) -> Result<Vec<RetiroPeticion>, ErrorInterfaz>
         {
-            self.get_elecciones_historial_priv()
+            self.get_historial_retiros_priv()
         }
 
-        fn get_elecciones_historial_priv(&mut self) -> Result<Vec<EleccionInterfaz>, ErrorInterfaz>
+        fn get_historial_retiros_priv(&self) -> Result<Vec<RetiroPeticion>, ErrorInterfaz>
         {
-            if let Err(error) = self.validar_caller_como_admin_o_usuario_aprobado(Self::env().caller()) {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
                 return Err(ErrorInterfaz::new(error))
             }
 
-            let timestamp = Self::env().block_timestamp();
-
-            let elecciones = self.clonar_elecciones_historicas_a_interfaz();
-            let elecciones = [
-                elecciones,
-                self.clonar_elecciones_actuales_a_interfaz(timestamp),
-            ]
-            .concat();
-
-            Ok(elecciones)
+            Ok(self.historial_retiros.clone())
         }
 
-        /// LE PERMITE A UN USUARIO APROBADO VER UNA LISTA DE LOS VOTANTES APROBADOS DE UNA ELECCION
+        /// LE PERMITE A UN USUARIO APROBADO PROPONER UN CAMBIO EN SU NOMBRE Y DNI
+        /// La funcion recibe el nombre y dni propuestos y retorna un Result<(), ErrorInterfaz>
+        /// El cambio queda en una cola de revision hasta que el admin lo apruebe o rechace, si ya habia un cambio pendiente se reemplaza
+        /// Los casos de error se dan si quien invoca es el admin, no es un usuario aprobado en el sistema o si el dni propuesto pertenece a otra cuenta
         #[ink(message)]
-        pub fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema> {
-            if id as usize >= self.elecciones_finiquitadas.len() {
-                return Err(ErrorSistema::EleccionInvalida);
+        pub fn proponer_cambio_perfil(&mut self, nuevo_nombre: String, nuevo_dni: String) -> Result<(), ErrorInterfaz>
+        {
+            self.proponer_cambio_perfil_priv(nuevo_nombre, nuevo_dni)
+        }
+
+        fn proponer_cambio_perfil_priv(&mut self, nuevo_nombre: String, nuevo_dni: String) -> Result<(), ErrorInterfaz>
+        {
+            let caller_user = match self.validar_caller_como_usuario_aprobado(Self::env().caller()) {
+                Ok(user) => user,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let nuevo_usuario = Usuario::new(caller_user.account_id, nuevo_nombre, nuevo_dni, self.sal_dni);
+            if let Err(error) = self.validar_dni_disponible(nuevo_usuario.dni_hash, caller_user.account_id) {
+                return Err(ErrorInterfaz::new(error))
             }
-            let elecciones_buscada = self.elecciones_finiquitadas[id as usize].clone();
-            Ok(elecciones_buscada)
+
+            self.registrar_cambio_perfil(nuevo_usuario);
+
+            Ok(())
         }
 
-        // fn get_elecciones_terminadas_x_priv(&self, id: u64) -> Result<Vec<Usuario>, ErrorInterfaz> {
-        //     if id as usize >= self.elecciones_finiquitadas.len() {
-        //         return Err(ErrorInterfaz::new(ErrorSistema::EleccionInvalida));
-        //     }
-        //     let elecciones_votantes = self.elecciones_finiquitadas[id as usize].votantes_aprobados.clone();
-        //     Ok(elecciones_votantes)
-        // }
+        ///LE PERMITE AL ADMIN VER LA COLA DE CAMBIOS DE PERFIL PENDIENTES
+        /// La funcion no recibe parametros, y devuelve un Result<Vec<Usuario>,ErrorInterfaz> con los datos propuestos por cada usuario
+        /// Retorna un error siempre que el usuario que invoque la funcion no sea el admin
         #[ink(message)]
-        pub fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion> {
-            self.get_elecciones_finiquitadas_priv()
+        pub fn get_peticiones_cambio_perfil(&self) -> Result<Vec<Usuario>, ErrorInterfaz>
+        {
+            self.get_peticiones_cambio_perfil_priv()
         }
-        
-        fn get_elecciones_finiquitadas_priv(&self) -> Vec<Eleccion> {
-            self.elecciones_finiquitadas.clone()
+
+        fn get_peticiones_cambio_perfil_priv(&self) -> Result<Vec<Usuario>, ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            Ok(self.peticiones_cambio_perfil.clone())
         }
 
-        /// LE PERMITE A UN USUARIO APROBADO REGISTRARSE A UNA ELECCION
-        /// 
-        /// 
-        /// #Uso
-        /// La funcion recibe por parametro el id de la eleccion para registrarse, el Rol en el que quiere presentarse y retorna un Result<(),ErrorInterfaz>
-        /// 
-        /// #Funcionalidad
-        /// Si quien invoca la funcion es un usuario aprobado en el sistema y el id corresponde a una eleccion valida en periodo de inscripcion,
-        /// el usuario que invoca la funcion es registrado a la espera de que el admin lo valide.
-        /// 
-        /// #Errores
-        /// Si el admin invoca la funcion, el usuario que invoca la funcion no esta aprobado o no esta registrado la funcion devuelve un ErrorInterfaz.
-        /// 
-        /// La funcion tambien devuelve un ErrorInterfaz si el id de la eleccion no es valido o es de una eleccion que no esta en periodo de inscripcion.
-        /// 
-        /// ....
+        /// LE PERMITE AL ADMIN APROBAR EL CAMBIO DE PERFIL PENDIENTE DE UN USUARIO
+        /// La funcion recibe el AccountId del usuario y retorna un Result<(), ErrorInterfaz>
+        /// Los nuevos datos reemplazan a los del usuario en el sistema y en todas las copias del mismo guardadas en las elecciones activas
+        /// Los casos de error se dan si quien invoca no es el admin, si el usuario no tiene un cambio de perfil pendiente
+        /// o si el dni propuesto paso a pertenecer a otra cuenta
         #[ink(message)]
-        pub fn registrarse_a_eleccion(
-            &mut self,
-            eleccion_id: u64,
-            rol: Rol,
-        ) -> Result<(), ErrorInterfaz> // Cómo identificar elección
+        pub fn aprobar_cambio_perfil(&mut self, usuario_account_id: AccountId) -> Result<(), ErrorInterfaz>
         {
-            self.registrarse_a_eleccion_priv(eleccion_id, rol)
+            self.aprobar_cambio_perfil_priv(usuario_account_id)
         }
 
-        fn registrarse_a_eleccion_priv(&mut self, eleccion_id: u64, rol: Rol) -> Result<(), ErrorInterfaz>
+        fn aprobar_cambio_perfil_priv(&mut self, usuario_account_id: AccountId) -> Result<(), ErrorInterfaz>
         {
-            let caller_user = match self.validar_caller_como_usuario_aprobado(Self::env().caller()) {
-                Ok(user) => user,
-                Err(error) => return Err(ErrorInterfaz::new(error))
-            };
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
+            }
 
-            let eleccion_index = match self.validar_eleccion(
-                eleccion_id,
-                EstadoEleccion::PeriodoInscripcion,
-                Self::env().block_timestamp(),
-            ) {
+            let index = match self.validar_cambio_perfil_pendiente(usuario_account_id) {
                 Ok(index) => index,
                 Err(error) => return Err(ErrorInterfaz::new(error))
             };
 
-            match self.registrar_peticion_eleccion(caller_user, rol, eleccion_index) {
-                Ok(_) => Ok(()),
-                Err(error) => Err(ErrorInterfaz::new(error))
+            // El dni pudo haber sido tomado por otra cuenta despues de proponerse el cambio
+            if let Err(error) = self.validar_dni_disponible(self.peticiones_cambio_perfil[index].dni_hash, usuario_account_id) {
+                return Err(ErrorInterfaz::new(error))
             }
+
+            let usuario = self.peticiones_cambio_perfil.remove(index);
+            self.aplicar_cambio_perfil(usuario);
+
+            Ok(())
         }
 
-        /// PERMITE AL ADMIN RECUPERAR LA LISTA DE TODOS LOS CANDIDATOS PENDIENTES
-        /// 
-        /// 
-        /// 
-        /// #Uso
-        /// 
-        /// La funcion recibe el id de la eleccion de la que se quieren obtener los candidatos pendientes y retorna un Result<Vec<Usuario>,ErrorInterfaz>
-        /// 
-        /// #Funcionalidad
-        /// 
-        /// Se valida que el usuario que use la funcion sea el admin, si lo es se valida que el id de la eleccion corresponda a una eleccion calida en periodo de inscripcion
-        /// y se devuelve una lista de los candidatos pendientes a aprobacion en esa eleccion
-        /// 
-        /// #Errores
-        /// 
-        /// los casos de error de la funcion son cuando el usuario que la invoca no es el admin, cuando el id de la eleccion no es valido o cuando el id de la eleccion no 
-        /// corresponde a una eleccion en periodo de inscripcion
-        /// 
-        /// ... 
+        /// LE PERMITE AL ADMIN RECHAZAR EL CAMBIO DE PERFIL PENDIENTE DE UN USUARIO
+        /// La funcion recibe el AccountId del usuario y retorna un Result<(), ErrorInterfaz>
+        /// El usuario conserva sus datos actuales
+        /// Los casos de error se dan si quien invoca no es el admin o si el usuario no tiene un cambio de perfil pendiente
         #[ink(message)]
-        pub fn get_candidatos_pendientes(&mut self, eleccion_id: u64) -> Result<Vec<Usuario>, ErrorInterfaz>
+        pub fn rechazar_cambio_perfil(&mut self, usuario_account_id: AccountId) -> Result<(), ErrorInterfaz>
         {
-            self.get_candidatos_pendientes_priv(eleccion_id)
+            self.rechazar_cambio_perfil_priv(usuario_account_id)
         }
 
-        fn get_candidatos_pendientes_priv(&mut self, eleccion_id: u64) -> Result<Vec<Usuario>, ErrorInterfaz>
+        fn rechazar_cambio_perfil_priv(&mut self, usuario_account_id: AccountId) -> Result<(), ErrorInterfaz>
         {
             if let Err(error) = self.validar_permisos(Self::env().caller()) {
                 return Err(ErrorInterfaz::new(error))
             }
 
-            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
+            let index = match self.validar_cambio_perfil_pendiente(usuario_account_id) {
                 Ok(index) => index,
                 Err(error) => return Err(ErrorInterfaz::new(error))
             };
 
-            Ok( self.elecciones[eleccion_index].peticiones_candidatos.clone() )
-        }
+            self.peticiones_cambio_perfil.remove(index);
 
+            Ok(())
+        }
 
+        /// LE PERMITE AL ADMIN REASIGNAR UN DNI A UNA NUEVA CUENTA, POR EJEMPLO SI EL USUARIO PERDIO SU CLAVE
+        /// La funcion recibe el dni y el AccountId de la nueva cuenta y retorna un Result<(), ErrorInterfaz>
+        /// El usuario dueño del dni, aprobado o en la cola de espera, pasa a estar asociado a la nueva cuenta junto con todo lo que tenga en las elecciones activas,
+        /// como en migrar_cuenta_usuario, y la anterior queda libre
+        /// Los casos de error se dan si quien invoca no es el admin, si el dni no pertenece a ninguna cuenta, si pertenece al admin
+        /// o si la nueva cuenta ya esta registrada en el sistema
         #[ink(message)]
-        pub fn get_votantes_pendientes(&mut self, eleccion_id: u64) -> Result<Vec<Usuario>, ErrorInterfaz>
+        pub fn reasignar_dni(&mut self, dni: String, nueva_cuenta: AccountId) -> Result<(), ErrorInterfaz>
         {
-            self.get_votantes_pendientes_priv(eleccion_id)
+            self.reasignar_dni_priv(dni, nueva_cuenta)
         }
 
-        fn get_votantes_pendientes_priv(&mut self, eleccion_id: u64) -> Result<Vec<Usuario>, ErrorInterfaz>
+        fn reasignar_dni_priv(&mut self, dni: String, nueva_cuenta: AccountId) -> Result<(), ErrorInterfaz>
         {
             if let Err(error) = self.validar_permisos(Self::env().caller()) {
                 return Err(ErrorInterfaz::new(error))
             }
 
-            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
-                Ok(index) => index,
+            let cuenta_actual = match self.validar_dni_reasignable(calcular_hash_dni(&dni, &self.sal_dni)) {
+                Ok(cuenta) => cuenta,
                 Err(error) => return Err(ErrorInterfaz::new(error))
             };
 
-            Ok( self.elecciones[eleccion_index].peticiones_votantes.clone() )
-        }   
-        
+            if let Err(error) = self.consultar_inexistencia_usuario_en_sistema(nueva_cuenta) {
+                return Err(ErrorInterfaz::new(error))
+            }
 
-        ///PERMITE AL ADMIN APROBAR UN CANDIDATO A UNA ELECCION
-        /// 
-        /// #Uso
-        /// 
-        /// La funcion recibe el id de la eleccion en la que se quiere aprobar un candidato y el dni del candidato a aprobar, retorna un Result<(),ErrorInterfaz>
-        /// 
-        /// #Funcionalidad
-        /// 
-        /// Se valida que el usuario que invoca a la funcion el admin, en ese caso se valida que el id de la eleccion sea valido y que el dni pertenezca a un candidato
-        /// esperando a ser validado en esta eleccion, si todas las condiciones se cumplen el usuario dueño de ese dni queda registrado como candidato en la eleccion
-        /// 
-        /// #Errores
-        /// 
-        /// Los casos de error de la funcion se dan cuando el usuario que la invoca no es admin, cuando el id de eleccion no es valido o no pertenece a una eleccion en 
-        /// periodo de inscripcion y cuando el dni del candidato no pertenece a un usuario registrado en la eleccion o un usuario ya aprobado
-        /// 
-        /// .
+            self.migrar_cuenta(cuenta_actual, nueva_cuenta);
+
+            Ok(())
+        }
+
+        /// LE PERMITE AL ADMIN MIGRAR LA IDENTIDAD DE UN USUARIO DE UNA CUENTA A OTRA, POR EJEMPLO SI EL USUARIO PERDIO SU CLAVE
+        /// La funcion recibe el AccountId actual del usuario y el de la nueva cuenta, retorna un Result<(), ErrorInterfaz>
+        /// Se mueven a la nueva cuenta el usuario del sistema, sus peticiones pendientes, sus aprobaciones y su pertenencia a listas en todas las elecciones activas,
+        /// y su lugar en el padron de las plantillas. Las elecciones finiquitadas no se modifican, pero al arrastrar sus votantes a una nueva edicion se sigue la migracion.
+        /// Si la cuenta anterior ya voto en una eleccion, la nueva figura como que ya voto en ella, por lo que no puede volver a votar.
+        /// Los casos de error se dan si quien invoca no es el admin, si la cuenta actual es la del admin o no existe en el sistema,
+        /// o si la nueva cuenta ya esta registrada en el sistema
         #[ink(message)]
-        pub fn aprobar_candidato_eleccion(&mut self, eleccion_id: u64, candidato_dni: String) -> Result<(), ErrorInterfaz>
+        pub fn migrar_cuenta_usuario(&mut self, cuenta_actual: AccountId, nueva_cuenta: AccountId) -> Result<(), ErrorInterfaz>
         {
-            self.aprobar_candidato_eleccion_priv(eleccion_id, candidato_dni)
+            self.migrar_cuenta_usuario_priv(cuenta_actual, nueva_cuenta)
         }
 
-        fn aprobar_candidato_eleccion_priv(&mut self, eleccion_id: u64, candidato_dni: String) -> Result<(), ErrorInterfaz>
+        fn migrar_cuenta_usuario_priv(&mut self, cuenta_actual: AccountId, nueva_cuenta: AccountId) -> Result<(), ErrorInterfaz>
         {
             if let Err(error) = self.validar_permisos(Self::env().caller()) {
                 return Err(ErrorInterfaz::new(error))
             }
 
-            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
-                Ok(index) => index,
-                Err(error) => return Err(ErrorInterfaz::new(error))
-            };
-            let candidato_index = match self.validar_candidato_en_pendientes(candidato_dni, eleccion_index) {
-                Ok(index) => index,
-                Err(error) => return Err(ErrorInterfaz::new(error))
-            };
+            if let Err(error) = self.validar_cuenta_de_usuario(cuenta_actual) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            if let Err(error) = self.consultar_inexistencia_usuario_en_sistema(nueva_cuenta) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            self.migrar_cuenta(cuenta_actual, nueva_cuenta);
 
-            self.aprobar_candidato(candidato_index, eleccion_index);
             Ok(())
         }
 
-        ///PERMITE AL ADMIN APROBAR UN VOTANTE A UNA ELECCION
+        /// LE PERMITE AL ADMIN SUSPENDER A UN USUARIO APROBADO
+        /// La funcion recibe el AccountId del usuario y retorna un Result<(), ErrorInterfaz>
+        /// El usuario sigue registrado, pero no puede postularse, votar ni consultar elecciones hasta ser reactivado
+        /// Los casos de error se dan si quien invoca no es el admin, si la cuenta es la del admin, si el usuario no esta aprobado en el sistema
+        /// o si ya esta suspendido
         #[ink(message)]
-        pub fn aprobar_votante_eleccion(&mut self, eleccion_id: u64, votante_dni: String) -> Result<(), ErrorInterfaz>
+        pub fn suspender_usuario(&mut self, usuario_account_id: AccountId) -> Result<(), ErrorInterfaz>
         {
-            self.aprobar_votante_eleccion_priv(eleccion_id, votante_dni)
+            self.suspender_usuario_priv(usuario_account_id)
         }
 
-        fn aprobar_votante_eleccion_priv(&mut self, eleccion_id: u64, votante_dni: String) -> Result<(), ErrorInterfaz>
+        fn suspender_usuario_priv(&mut self, usuario_account_id: AccountId) -> Result<(), ErrorInterfaz>
         {
             if let Err(error) = self.validar_permisos(Self::env().caller()) {
                 return Err(ErrorInterfaz::new(error))
             }
 
-            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
-                Ok(index) => index,
-                Err(error) => return Err(ErrorInterfaz::new(error))
-            };
-            let votante_index = match self.validar_votante_en_pendientes(votante_dni, eleccion_index) {
-                Ok(index) => index,
-                Err(error) => return Err(ErrorInterfaz::new(error))
-            };
+            if let Err(error) = self.validar_caller_como_usuario_aprobado(usuario_account_id) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            self.usuarios_suspendidos.push(usuario_account_id);
 
-            self.aprobar_votante(votante_index, eleccion_index);
             Ok(())
         }
 
-
-        /// PERMITE AL USUARIO VOTAR EN UNA ELECCION EN LA QUE ESTE ACREDITADO, FALTA REVISAR LA EXISTENCIA DEL CANDIDATO
-        /// 
-        /// #Uso
-        /// 
-        /// La funcion recibe el id de una eleccion y el dni del candidato a votar, retorna un Result<(), ErrorInterfaz>
-        /// 
-        /// #Funcionalidad
-        /// 
-        /// Se valida que quien invoca a la funcion sea un usuario aprobado que el id de la eleccion pertenezca a una eleccion en periodo de votacion,
-        /// luego de esto se valida que el usuario este aprobado como votante en esa eleccion y que el candidato este postulado, si se cumplen estas condiciones
-        /// se registra el voto.
-        /// 
-        /// #Errores
-        /// 
-        /// Los casos de error pueden darse si quien invoca la funcion es el admin o si el usuario no esta aprobado como votante en la eleccion, 
-        /// si la eleccion no esta en periodo de votacion o si el candidato no esta postulado y aprobado
-        /// 
-        /// ...
+        /// LE PERMITE AL ADMIN REACTIVAR A UN USUARIO SUSPENDIDO
+        /// La funcion recibe el AccountId del usuario y retorna un Result<(), ErrorInterfaz>
+        /// Los casos de error se dan si quien invoca no es el admin o si el usuario no esta suspendido
         #[ink(message)]
-        pub fn votar_eleccion(&mut self, eleccion_id: u64, candidato_dni: String) -> Result<(), ErrorInterfaz>
+        pub fn reactivar_usuario(&mut self, usuario_account_id: AccountId) -> Result<(), ErrorInterfaz>
         {
-            self.votar_eleccion_priv(eleccion_id, candidato_dni)
+            self.reactivar_usuario_priv(usuario_account_id)
         }
 
-        fn votar_eleccion_priv(&mut self, eleccion_id: u64, candidato_dni: String) -> Result<(), ErrorInterfaz>
+        fn reactivar_usuario_priv(&mut self, usuario_account_id: AccountId) -> Result<(), ErrorInterfaz>
         {
-            let caller_user = match self.validar_caller_como_usuario_aprobado(Self::env().caller()) {
-                Ok(user) => user,
-                Err(error) => return Err(ErrorInterfaz::new(error))
-            };
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
+            }
 
-            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoVotacion,  Self::env().block_timestamp()) {
-                Ok(index) => index,
-                Err(error) => return Err(ErrorInterfaz::new(error))
+            let index = match self.usuarios_suspendidos.iter().position(|id| *id == usuario_account_id) {
+                Some(index) => index,
+                None => return Err(ErrorInterfaz::new(ErrorSistema::UsuarioNoSuspendido))
             };
 
-            if self.elecciones[eleccion_index].votantes_votados.iter().any(|v| v.account_id == caller_user.account_id) {
-                return Err(ErrorInterfaz::new(ErrorSistema::VotanteYaVoto))
+            self.usuarios_suspendidos.remove(index);
+
+            Ok(())
+        }
+
+        /// LE PERMITE AL ADMIN ELIMINAR DEFINITIVAMENTE A UN USUARIO DEL SISTEMA
+        /// La funcion recibe el AccountId del usuario y retorna un Result<(), ErrorInterfaz>
+        /// El usuario, aprobado o en la cola de espera, se quita del sistema junto con sus peticiones pendientes en las elecciones activas y su dni queda libre.
+        /// Las aprobaciones ya otorgadas y los registros de votantes que ya votaron se conservan para auditoria
+        /// Los casos de error se dan si quien invoca no es el admin, si la cuenta es la del admin o si no existe en el sistema
+        #[ink(message)]
+        pub fn eliminar_usuario(&mut self, usuario_account_id: AccountId) -> Result<(), ErrorInterfaz>
+        {
+            self.eliminar_usuario_priv(usuario_account_id)
+        }
+
+        fn eliminar_usuario_priv(&mut self, usuario_account_id: AccountId) -> Result<(), ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
             }
 
-            if let Err(error) = self.validar_votante_aprobado_en_eleccion(caller_user.account_id, eleccion_index) {
+            if let Err(error) = self.validar_cuenta_de_usuario(usuario_account_id) {
                 return Err(ErrorInterfaz::new(error))
             }
 
-            let candidato_index = match self.validar_candidato_aprobado(candidato_dni, eleccion_index) {
-                Ok(index) => index,
-                Err(error) => return Err(ErrorInterfaz::new(error))
-            };
+            self.eliminar_usuario_del_sistema(usuario_account_id);
 
-            match self.registrar_voto_a_candidato(candidato_index, eleccion_index) {
-                Ok(_) => Ok(()),
-                Err(error) => Err(ErrorInterfaz::new(error))
+            Ok(())
+        }
+
+        /// LE PERMITE AL ADMIN VALIDAR A UN USUARIO EN EL SISTEMA
+        /// La funcion recibe como parametro el AccountId de un usuario
+        /// Si quien invoca a la funcion es el admin, la funcion valida que el accountId por parametro este registrado en el sistema
+        /// de ser el caso el usuario queda validado para acceder a las funcionalidades del mismo
+        /// si la llamada la realiza un usuario no admin la funcion devuelve un ErrorInterfaz
+        /// si el accountId ya fue validado o no existe en el sistema la funcion tambien devuelve un ErrorInterfaz
+        #[ink(message)]
+        pub fn aprobar_usuario_sistema(&mut self, usuar_account_id: AccountId) -> Result<(), ErrorInterfaz>
+        {
+            self.aprobar_usuario_sistema_priv(usuar_account_id)
+        }
+
+        fn aprobar_usuario_sistema_priv(&mut self, usuar_account_id: AccountId) -> Result<(), ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            if let Err(error) = self.consultar_peticion_sistema(usuar_account_id) {
+                return Err(ErrorInterfaz::new(error))
             }
+
+            self.aprobar_usuario(usuar_account_id);
+
+            Ok(())
         }
 
-        //////////////////////////////////////// PRIVATES ////////////////////////////////////////
+        /// LE PERMITE AL ADMIN TRASPASAR SU ROL A OTRO USUARIO
+        /// La funcion recibe por parametros el AccountId, nombre y dni del nuevo admin y retorna un Result<(),ErrorInterfaz>
+        /// Si quien invoca la funcion es el admin la funcion registra al nuevo admin en caso de que no este registrado
+        /// y despues reemplaza el accountId del admin actual por el accountId enviado por parametro
+        /// La funcion retorna un ErrorInterfaz si el usuario que la invoca no es el admin o si el nuevo admin no esta registrado y su dni ya pertenece a otra cuenta
+        #[ink(message)]
+        pub fn delegar_admin(&mut self, nuevo_admin_acc_id: AccountId, nuevo_admin_nombre: String, nuevo_admin_dni: String) -> Result<(), ErrorInterfaz>
+        {
+            self.delegar_admin_priv(nuevo_admin_acc_id, nuevo_admin_nombre, nuevo_admin_dni)
+        }
 
-        //////////////////// SISTEMA ////////////////////
+        fn delegar_admin_priv(&mut self, nuevo_admin_acc_id: AccountId, nuevo_admin_nombre: String, nuevo_admin_dni: String) -> Result<(), ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
+            }
 
-        ///SE APRUEBA UN USUARIO EN EL SISTEMA
+            if self.consultar_inexistencia_usuario_en_sistema(nuevo_admin_acc_id).is_ok() {
+                if let Err(error) = self.validar_dni_disponible(calcular_hash_dni(&nuevo_admin_dni, &self.sal_dni), nuevo_admin_acc_id) {
+                    return Err(ErrorInterfaz::new(error))
+                }
+            }
+
+            self.corregir_estado_nuevo_admin(nuevo_admin_acc_id, nuevo_admin_nombre, nuevo_admin_dni);
+
+            self.admin_id = nuevo_admin_acc_id;
+            Ok(())
+        }
+
+        /// LE PERMITE AL ADMIN O A UN USUARIO APROBADO CONSULTAR LAS ESTADISTICAS GENERALES DEL SISTEMA
         /// 
         /// #Uso
-        /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId
+        /// La funcion no recibe parametros y retorna un Result<EstadisticasSistema, ErrorInterfaz>
         /// 
         /// #Funcionalidad
-        /// 
-        /// La funcion busca el AccountId proporcionado en la lista de peticiones de registro del sistema y se guarda la posicion del mismo
-        /// despues elemina al user de la lista de peticiones y lo agrega a la lista de usuarios validos
+        /// Informa la cantidad de usuarios registrados, de peticiones de registro pendientes, de elecciones actuales en cada estado,
+        /// de elecciones finalizadas, el total de votos emitidos y el promedio de participacion de las elecciones finalizadas.
+        /// Los votos y la participacion se acumulan en contadores al votar y al finalizar cada eleccion, por lo que la consulta
+        /// solo recorre las elecciones que aun no fueron finalizadas para conocer su estado.
         /// 
         /// #Errores
-        /// 
-        /// La funcion no maneja errores ya que se filtran anteriormente
+        /// La funcion devuelve un ErrorInterfaz si quien la invoca no esta registrado o validado en el sistema.
         /// 
         /// ...
-        fn aprobar_usuario(&mut self, usuario_account_id: AccountId)
+        #[ink(message)]
+        pub fn get_estadisticas_sistema(&self) -> Result<EstadisticasSistema, ErrorInterfaz>
         {
-            let index = self.get_usuario_en_peticiones_del_sistema(usuario_account_id);
-            let user = self.peticiones_registro.remove(index.unwrap()); // Unwrap porque ya sé que existe en el vec
-            self.usuarios_registados.push(user);
+            self.get_estadisticas_sistema_priv()
         }
 
-        /// SE AGREGA UN USUARIO A LA COLA DE ESPERA DEL SISTEMA
-        /// 
-        /// #Uso
+        fn get_estadisticas_sistema_priv(&self) -> Result<EstadisticasSistema, ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_caller_como_admin_o_usuario_aprobado(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            let timestamp = Self::env().block_timestamp();
+            let contar_en_estado = |estado: EstadoEleccion| self.elecciones.iter().filter(|e| e.get_estado_eleccion(timestamp) == estado).count() as u64;
+
+            let cant_finiquitadas = self.elecciones_finiquitadas.len() as u64;
+            let participacion_promedio = self.suma_participacion_finiquitadas.checked_div(cant_finiquitadas as u128).unwrap_or(0);
+
+            Ok(EstadisticasSistema::new(
+                self.usuarios_registados.len() as u64,
+                self.peticiones_registro.len() as u64,
+                contar_en_estado(EstadoEleccion::PeriodoInscripcion),
+                contar_en_estado(EstadoEleccion::PeriodoVotacion),
+                contar_en_estado(EstadoEleccion::Cerrada),
+                cant_finiquitadas,
+                self.votos_emitidos_totales,
+                participacion_promedio,
+            ))
+        }
+
+        //////////////////// ELECCIONES ////////////////////
+
+        /// LE PERMITE AL ADMIN CREAR UNA NUEVA ELECCION
         /// 
-        /// La funcion es de uso interno del sistema, recibe un Usuario
+        /// #uso
+        /// La funcion recibe por parametro el cargo, fecha de inicio, fecha de cierre y un quorum opcional para la eleccion y devuelve un Result<(), ErrorInterfaz>
         /// 
-        /// #Funcionalidad
+        /// #funcionalidad
+        /// Si el usuario que invoca la funcion es el admin, y las fechas de inicio no es anterior al dia de la fecha y la de cierre no es anterior a la de inicio
+        /// se valida el incremento a los id de eleccion para evitar desbordes, se crea la nueva eleccion y se agrega a la lista de elecciones actuales.
+        /// El quorum puede ser un porcentaje de los votantes aprobados o una cantidad absoluta de votantes, y se controla al finalizar la eleccion.
         /// 
-        /// La funcion agrega al usuario recibido a la lista de peticiones de registro del sistema
+        /// #Errores
+        /// Se devuelve un ErrorInterfaz si quien invoca la funcion no es el admin, si las fechas no cumplen las condiciones antes mencionadas
+        /// o si el quorum es un porcentaje mayor a 100
         /// 
         /// ...
-        fn registrar_en_cola_de_sistema(&mut self, user: Usuario) {
-            self.peticiones_registro.push(user);
+        #[ink(message)]
+        pub fn crear_nueva_eleccion(&mut self, cargo: String, fecha_inicio: Fecha, fecha_cierre: Fecha, quorum: Option<Quorum>) -> Result<(), ErrorInterfaz>
+        {
+            self.crear_nueva_eleccion_priv(cargo,fecha_inicio,fecha_cierre,quorum)
         }
 
-        /// EN CASO DE QUE EL ADMIN ID NO ESTA REGISTRADO LO REGISTRA
-        /// 
-        /// #Uso
+        fn crear_nueva_eleccion_priv(&mut self, cargo: String, fecha_inicio: Fecha, fecha_cierre: Fecha, quorum: Option<Quorum>) -> Result<(), ErrorInterfaz>
+        {
+            self.crear_eleccion(cargo, fecha_inicio, fecha_cierre, quorum, MetodoVotacion::Candidatos)
+        }
+
+        /// LE PERMITE AL ADMIN CREAR UNA NUEVA ELECCION EN LA QUE SE VOTA POR LISTAS
         /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId, un nombre y un dni
+        /// #uso
+        /// La funcion recibe los mismos parametros que crear_nueva_eleccion y devuelve un Result<(), ErrorInterfaz>
         /// 
-        /// #Funcionalidad
+        /// #funcionalidad
+        /// Crea la eleccion con las mismas validaciones que crear_nueva_eleccion, pero en ella no se postulan candidatos individuales
+        /// sino listas, que registra un lider y aprueba el admin como una unidad. Los votos de la eleccion van a las listas.
         /// 
-        /// La funcion revisa que el accountId recibido este registrado o en peticiones del sistema, si no se encuntra en ninguna lista
-        /// lo registra y valida si esta en la lista de peticiones solo lo valida.
+        /// #Errores
+        /// Los mismos que crear_nueva_eleccion
         /// 
         /// ...
-        fn corregir_estado_nuevo_admin(&mut self, new_admin_id: AccountId, new_admin_nombre: String, new_admin_dni: String)
+        #[ink(message)]
+        pub fn crear_nueva_eleccion_por_listas(&mut self, cargo: String, fecha_inicio: Fecha, fecha_cierre: Fecha, quorum: Option<Quorum>) -> Result<(), ErrorInterfaz>
         {
-            if self.get_usuario_registrado_en_sistema(new_admin_id).is_some() { return; }
-
-            if self.get_usuario_en_peticiones_del_sistema(new_admin_id).is_none()
-            {
-                let new_user = Usuario::new(new_admin_id, new_admin_nombre, new_admin_dni);
-                self.registrar_en_cola_de_sistema(new_user);
-            }
-
-            self.aprobar_usuario(new_admin_id);
+            self.crear_nueva_eleccion_por_listas_priv(cargo,fecha_inicio,fecha_cierre,quorum)
         }
 
-        //////////////////// ELECCIONES ////////////////////
+        fn crear_nueva_eleccion_por_listas_priv(&mut self, cargo: String, fecha_inicio: Fecha, fecha_cierre: Fecha, quorum: Option<Quorum>) -> Result<(), ErrorInterfaz>
+        {
+            self.crear_eleccion(cargo, fecha_inicio, fecha_cierre, quorum, MetodoVotacion::Listas)
+        }
 
-        /// AGREGA A UN USUARIO A LA COLA DE ESPERA PARA SER VOTANTE O CANDIDATO EN UNA ELECCION
-        ///
-        /// #Uso
+        /// LE PERMITE AL ADMIN GUARDAR UNA PLANTILLA PARA CREAR ELECCIONES QUE SE REPITEN
         /// 
-        /// La funcion es de uso interno del sistema, recibe un Usuario, un Rol y usize, retorna un Result<(),ErrorSistema>
+        /// #uso
+        /// La funcion recibe el cargo, la duracion de la eleccion en segundos, el metodo de votacion, un quorum opcional y el padron de votantes,
+        /// y devuelve un Result<u64, ErrorInterfaz> con el id de la plantilla creada
         /// 
-        ///#Funcionalidad 
-        /// 
-        /// La funcion valida que el usuario no exista en la eleccion recibida y lo agrega a la lista de espera adecuada dependiendo de rol recibido
+        /// #funcionalidad
+        /// La plantilla guarda los datos que se repiten en cada edicion de la eleccion. El padron son los AccountId de los usuarios
+        /// que quedan aprobados como votantes en cada eleccion creada a partir de la plantilla.
         /// 
         /// #Errores
-        /// 
-        /// Los casos de error se dan si el usuario ya esta registrado y aprobado en esta eleccion ya sea como votante o como candidato
+        /// Se devuelve un ErrorInterfaz si quien invoca la funcion no es el admin, si la duracion es 0 o si el quorum es un porcentaje mayor a 100
         /// 
         /// ...
-        fn registrar_peticion_eleccion(&mut self, user: Usuario, rol: Rol, eleccion_index: usize) -> Result<(), ErrorSistema>
+        #[ink(message)]
+        pub fn crear_plantilla_eleccion(&mut self, cargo: String, duracion_segundos: u64, metodo_votacion: MetodoVotacion, quorum: Option<Quorum>, padron: Vec<AccountId>) -> Result<u64, ErrorInterfaz>
         {
-            self.validar_inexistencia_de_usuario_en_eleccion(user.account_id, eleccion_index)?;
+            self.crear_plantilla_eleccion_priv(cargo, duracion_segundos, metodo_votacion, quorum, padron)
+        }
 
-            match rol {
-                Rol::Votante => self.elecciones[eleccion_index]
-                    .peticiones_votantes
-                    .push(user),
-                Rol::Candidato => self.elecciones[eleccion_index]
-                    .peticiones_candidatos
-                    .push(user),
+        fn crear_plantilla_eleccion_priv(&mut self, cargo: String, duracion_segundos: u64, metodo_votacion: MetodoVotacion, quorum: Option<Quorum>, padron: Vec<AccountId>) -> Result<u64, ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
             }
 
-            Ok(())
+            if duracion_segundos == 0 {
+                return Err(ErrorInterfaz::new(ErrorSistema::DuracionInvalida));
+            }
+
+            if let Some(Quorum::Porcentaje(porcentaje)) = quorum {
+                if porcentaje > 100 {
+                    return Err(ErrorInterfaz::new(ErrorSistema::QuorumInvalido));
+                }
+            }
+
+            let plantilla_id = self.plantillas_eleccion.len() as u64;
+            self.plantillas_eleccion.push(PlantillaEleccion::new(plantilla_id, cargo, duracion_segundos, metodo_votacion, quorum, padron));
+
+            Ok(plantilla_id)
         }
 
-        ///CREA UNA LISTA DE LAS ELECCIONES EN CURSO DE LA FORMA QUE SE LEE EN LA INTERFAZ
-        /// 
-        /// #Uso
-        /// 
-        /// La funcion es de uso interno del sistema, recibe un time stamp y retorna un Vec<EleccionInterfaz>
+        ///LE PERMITE AL ADMIN VER LAS PLANTILLAS DE ELECCION GUARDADAS
+        #[ink(message)]
+        pub fn get_plantillas_eleccion(&self) -> Result<Vec<PlantillaEleccion>, ErrorInterfaz>
+        {
+            self.get_plantillas_eleccion_priv()
+        }
+
+        fn get_plantillas_eleccion_priv(&self) -> Result<Vec<PlantillaEleccion>, ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            Ok(self.plantillas_eleccion.clone())
+        }
+
+        /// LE PERMITE AL ADMIN CREAR UNA NUEVA ELECCION A PARTIR DE UNA PLANTILLA
         /// 
-        /// #Funcionalidad
+        /// #uso
+        /// La funcion recibe el id de la plantilla, la fecha de inicio de la eleccion y si se arrastran los votantes de la edicion anterior,
+        /// y devuelve un Result<(), ErrorInterfaz>
         /// 
-        /// La funcion crea un Vec<EleccionInterfaz> usando el la lista de elecciones actuales del sistema
+        /// #funcionalidad
+        /// Se crea la eleccion con el cargo, metodo de votacion y quorum de la plantilla, y la fecha de cierre se calcula sumando la duracion de la plantilla
+        /// a la fecha de inicio. Los usuarios del padron de la plantilla quedan aprobados como votantes. Si se pide arrastrar votantes, tambien quedan aprobados
+        /// los votantes aprobados en la ultima eleccion finalizada creada a partir de la misma plantilla. Solo se agregan usuarios que sigan aprobados en el sistema.
         /// 
         /// #Errores
-        /// 
-        /// La funcion no maneja errores
+        /// Se devuelve un ErrorInterfaz si la plantilla no existe, ademas de los casos de error de crear_nueva_eleccion
         /// 
         /// ...
-        fn clonar_elecciones_actuales_a_interfaz(&self, timestamp: u64) -> Vec<EleccionInterfaz>
+        #[ink(message)]
+        pub fn crear_eleccion_desde_plantilla(&mut self, plantilla_id: u64, fecha_inicio: Fecha, arrastrar_votantes: bool) -> Result<(), ErrorInterfaz>
         {
-            let mut vec: Vec<EleccionInterfaz> = Vec::new();
+            self.crear_eleccion_desde_plantilla_priv(plantilla_id, fecha_inicio, arrastrar_votantes)
+        }
 
-            for i in 0..self.elecciones.len() {
-                vec.push(EleccionInterfaz::from_eleccion(
-                    self.elecciones[i].get_estado_eleccion(timestamp),
-                    self.elecciones[i].clone(),
-                    None
-                ));
+        fn crear_eleccion_desde_plantilla_priv(&mut self, plantilla_id: u64, fecha_inicio: Fecha, arrastrar_votantes: bool) -> Result<(), ErrorInterfaz>
+        {
+            let plantilla = match self.plantillas_eleccion.get(plantilla_id as usize) {
+                Some(plantilla) => plantilla.clone(),
+                None => return Err(ErrorInterfaz::new(ErrorSistema::PlantillaNoExiste))
+            };
+
+            let fecha_cierre = Fecha::from_timestamp(fecha_inicio.to_timestamp().saturating_add(plantilla.duracion_segundos.saturating_mul(1000)));
+            self.crear_eleccion(plantilla.cargo.clone(), fecha_inicio, fecha_cierre, plantilla.quorum.clone(), plantilla.metodo_votacion.clone())?;
+
+            let mut padron = plantilla.padron.clone();
+            if arrastrar_votantes {
+                padron.extend(self.get_votantes_ultima_eleccion_de_plantilla(plantilla_id));
             }
+            self.aprobar_padron_en_ultima_eleccion(plantilla_id, padron);
 
-            vec
+            Ok(())
         }
 
-        ///CREA UNA LISTA DE LAS ELECCIONES TERMINADAS DE LA FORMA QUE SE LEE ENE LA INTERFAZ
-        /// #Uso
-        /// 
-        /// La funcion es de uso interno del sistema, recibe un time stamp y retorna un Vec<EleccionInterfaz>
+        /// LE PERMITE AL ADMIN CREAR UN EVENTO CON VARIAS ELECCIONES SIMULTANEAS QUE COMPARTEN EL PADRON DE VOTANTES
         /// 
-        /// #Funcionalidad
+        /// #uso
+        /// La funcion recibe el nombre del evento, los cargos a elegir, las fechas de inicio y cierre y un quorum opcional,
+        /// y devuelve un Result<u64, ErrorInterfaz> con el id del evento creado
         /// 
-        /// La funcion crea un Vec<EleccionInterfaz> usando el la lista de elecciones pasadas del sistema
+        /// #funcionalidad
+        /// Se crea una eleccion por candidatos para cada cargo, todas con las mismas fechas y quorum, y se agrupan en el evento.
+        /// Los votantes se registran y son aprobados una sola vez en el padron del evento, que comparten todas sus elecciones, y pueden emitir todos sus votos juntos.
+        /// Los votantes de las elecciones del evento no pueden registrarse ni aprobarse en cada eleccion por separado.
         /// 
         /// #Errores
-        /// 
-        /// La funcion no maneja errores
+        /// Se devuelve un ErrorInterfaz si quien invoca la funcion no es el admin, si no se recibe ningun cargo, o en los casos de error de crear_nueva_eleccion
         /// 
         /// ...
-        fn clonar_elecciones_historicas_a_interfaz(&self) -> Vec<EleccionInterfaz>
+        #[ink(message)]
+        pub fn crear_evento_elecciones(&mut self, nombre: String, cargos: Vec<String>, fecha_inicio: Fecha, fecha_cierre: Fecha, quorum: Option<Quorum>) -> Result<u64, ErrorInterfaz>
         {
-            let mut vec: Vec<EleccionInterfaz> = Vec::new();
+            self.crear_evento_elecciones_priv(nombre, cargos, fecha_inicio, fecha_cierre, quorum)
+        }
 
-            for i in 0..self.elecciones_finiquitadas.len() {
-                vec.push(EleccionInterfaz::from_eleccion(
-                    EstadoEleccion::Finalizada,
-                    self.elecciones_finiquitadas[i].clone(),
-                    Some(self.elecciones_finiquitadas[i].votos.clone())
-                ));
+        fn crear_evento_elecciones_priv(&mut self, nombre: String, cargos: Vec<String>, fecha_inicio: Fecha, fecha_cierre: Fecha, quorum: Option<Quorum>) -> Result<u64, ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
             }
 
-            vec
+            if cargos.is_empty() {
+                return Err(ErrorInterfaz::new(ErrorSistema::EventoSinElecciones))
+            }
+
+            let mut elecciones = Vec::new();
+            for cargo in cargos {
+                elecciones.push(self.elecciones_conteo_id);
+                self.crear_eleccion(cargo, fecha_inicio.clone(), fecha_cierre.clone(), quorum.clone(), MetodoVotacion::Candidatos)?;
+            }
+
+            let evento_id = self.eventos_elecciones.len() as u64;
+            self.eventos_elecciones.push(EventoElecciones::new(evento_id, nombre, elecciones));
+
+            Ok(evento_id)
         }
 
-        ///APRUEBA UN CANDIDATO PARA PARTICIPAR EN UNA ELECCION
+        ///DEVUELVE LOS EVENTOS DE ELECCIONES CREADOS, CON LOS IDS DE LAS ELECCIONES QUE AGRUPA CADA UNO
+        #[ink(message)]
+        pub fn get_eventos_elecciones(&self) -> Vec<EventoElecciones>
+        {
+            self.eventos_elecciones.clone()
+        }
+
+        /// PERMITE QUE UN USUARIO SE REGISTRE COMO VOTANTE EN TODAS LAS ELECCIONES DE UN EVENTO
         /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe dos usize 
+        /// La funcion recibe el id del evento y retorna un Result<(),ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion usa los usize proporcionados para buscar un candidato en la lista de candidatos pendientes y una eleccion en la lista de elecciones actuales,
-        /// lo elimina de esa lista y lo agrega a la lista de candidatos aprobados en la eleccion, tambien lo crea en la lista de votosxcandidato para que se lo pueda 
-        /// votar 
+        /// Si todas las elecciones del evento estan en periodo de inscripcion, el usuario queda en la cola de votantes pendientes del padron del evento.
+        /// Un candidato de alguna de las elecciones tambien puede registrarse, y una vez aprobado vota en todas las elecciones del evento menos en la que compite.
         /// 
         /// #Errores
         /// 
-        /// La funcion no maneja errores
+        /// Los casos de error se dan si quien invoca no es un usuario aprobado, si el evento no existe o no esta en periodo de inscripcion,
+        /// o si el usuario ya esta pendiente o aprobado en el padron del evento
         /// 
         /// ...
-        fn aprobar_candidato(&mut self, candidato_index: usize, eleccion_index: usize) 
+        #[ink(message)]
+        pub fn registrarse_a_evento(&mut self, evento_id: u64) -> Result<(), ErrorInterfaz>
         {
-            let e = &mut self.elecciones[eleccion_index];
+            self.registrarse_a_evento_priv(evento_id)
+        }
 
-            let candidato = e.peticiones_candidatos.remove(candidato_index);
-            e.candidatos_aprobados.push(candidato.clone());
+        fn registrarse_a_evento_priv(&mut self, evento_id: u64) -> Result<(), ErrorInterfaz>
+        {
+            let caller_user = match self.validar_caller_como_usuario_aprobado(Self::env().caller()) {
+                Ok(user) => user,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
 
-            let candidato_votos = CandidatoVotos::new(candidato.nombre, candidato.dni);
-            e.votos.push(candidato_votos);
+            if let Err(error) = self.validar_evento_en_estado(evento_id, EstadoEleccion::PeriodoInscripcion, Self::env().block_timestamp()) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            let evento = &mut self.eventos_elecciones[evento_id as usize];
+            if evento.peticiones_votantes.iter().any(|v| v.account_id == caller_user.account_id) {
+                return Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteEnPendiente)))
+            }
+            if evento.votantes_aprobados.iter().any(|v| v.account_id == caller_user.account_id) {
+                return Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteActualmenteAprobado)))
+            }
+
+            evento.peticiones_votantes.push(caller_user);
+            Ok(())
         }
 
-        ///APRUEBA UN VOTANTE PARA PARTICIPAR EN UNA ELECCION
+        /// PERMITE AL ADMIN APROBAR UN VOTANTE EN TODAS LAS ELECCIONES DE UN EVENTO
         /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe dos usize 
+        /// La funcion recibe el id del evento y el AccountId del votante, retorna un Result<(),ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion usa los usize proporcionados para buscar un votante en la lista de votantes pendientes y una eleccion en la lista de elecciones actuales,
-        /// lo elimina de esa lista y lo agrega a la lista de votantes aprobados en la eleccion. 
+        /// El votante pasa de la cola de pendientes al padron del evento, con el que se valida su voto en cada una de las elecciones del evento
         /// 
         /// #Errores
         /// 
-        /// La funcion no maneja errores
+        /// Los casos de error se dan si quien invoca no es el admin, si el evento no existe o no esta en periodo de inscripcion,
+        /// o si el votante no esta pendiente en el padron del evento
         /// 
         /// ...
-        fn aprobar_votante(&mut self, votante_index: usize, eleccion_index: usize) 
+        #[ink(message)]
+        pub fn aprobar_votante_evento(&mut self, evento_id: u64, votante_id: AccountId) -> Result<(), ErrorInterfaz>
         {
-            let e = &mut self.elecciones[eleccion_index];
+            self.aprobar_votante_evento_priv(evento_id, votante_id)
+        }
 
-            let votante = e.peticiones_votantes.remove(votante_index);
-            e.votantes_aprobados.push(votante.clone());
+        fn aprobar_votante_evento_priv(&mut self, evento_id: u64, votante_id: AccountId) -> Result<(), ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            if let Err(error) = self.validar_evento_en_estado(evento_id, EstadoEleccion::PeriodoInscripcion, Self::env().block_timestamp()) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            let evento = &mut self.eventos_elecciones[evento_id as usize];
+            let votante_index = match evento.peticiones_votantes.iter().position(|v| v.account_id == votante_id) {
+                Some(index) => index,
+                None if evento.votantes_aprobados.iter().any(|v| v.account_id == votante_id) => return Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteActualmenteAprobado))),
+                None => return Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoExiste))),
+            };
+
+            let votante = evento.peticiones_votantes.remove(votante_index);
+            evento.votantes_aprobados.push(votante);
+            Ok(())
         }
 
-        
-        /// SE REGISTRA UN VOTO DE UN VOTANTE VALIDO A UN CANDIDATO VALIDO
+        /// PERMITE AL USUARIO EMITIR EN UNA SOLA OPERACION SUS VOTOS EN VARIAS ELECCIONES DE UN EVENTO
         /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe dos usize, retorna un Result<(),ErrorSistema>  
+        /// La funcion recibe el id del evento y un voto por cada eleccion en la que se quiere votar, retorna un Result<(), ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion le permite a un votante valido votar por un candidato valido, luego de emitir el voto el votante es agregado a la lista de votantes que ya votaron
-        /// de la eleccion
+        /// Cada voto indica la eleccion y el numero del candidato elegido en ella. Se validan todos los votos antes de registrar alguno,
+        /// de forma que se registran todos o ninguno. No es necesario votar en todas las elecciones del evento.
         /// 
         /// #Errores
         /// 
-        /// Los casos de error se dan cuando el votante no es parte de los votantes habilitados a la eleccion o si ya se alcanzo el numero de votos maximos para un candidato
+        /// Los casos de error se dan si el evento no existe, si alguna eleccion no pertenece al evento o se repite,
+        /// ademas de los casos de error de votar_eleccion_por_numero en cualquiera de los votos
         /// 
         /// ...
-        fn registrar_voto_a_candidato(&mut self, candidato_index: usize, eleccion_index: usize) -> Result<(), ErrorSistema>
+        #[ink(message)]
+        pub fn votar_evento(&mut self, evento_id: u64, votos: Vec<VotoEvento>) -> Result<(), ErrorInterfaz>
         {
-            if let Some(num) = self.elecciones[eleccion_index].votos[candidato_index].votos_recaudados.checked_add(1) { 
-                self.elecciones[eleccion_index].votos[candidato_index].votos_recaudados = num;
-            } else {
-                return Err(ErrorSistema::RepresentacionLimiteAlcanzada) 
+            self.votar_evento_priv(evento_id, votos)
+        }
+
+        fn votar_evento_priv(&mut self, evento_id: u64, votos: Vec<VotoEvento>) -> Result<(), ErrorInterfaz>
+        {
+            let evento = match self.validar_evento(evento_id) {
+                Ok(evento) => evento,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let mut boletas: Vec<(usize, usize)> = Vec::new();
+            for (i, voto) in votos.iter().enumerate() {
+                if !evento.elecciones.contains(&voto.eleccion_id) {
+                    return Err(ErrorInterfaz::new(ErrorSistema::EleccionFueraDeEvento))
+                }
+
+                if votos[..i].iter().any(|v| v.eleccion_id == voto.eleccion_id) {
+                    return Err(ErrorInterfaz::new(ErrorSistema::EleccionRepetida))
+                }
+
+                let eleccion_index = match self.validar_votante_habilitado(voto.eleccion_id) {
+                    Ok(index) => index,
+                    Err(error) => return Err(ErrorInterfaz::new(error))
+                };
+
+                match self.validar_numero_candidato(voto.numero_candidato, eleccion_index) {
+                    Ok(candidato_index) => boletas.push((eleccion_index, candidato_index)),
+                    Err(error) => return Err(ErrorInterfaz::new(error))
+                }
             }
 
-            if let Some(votante_index) = self.elecciones[eleccion_index].votantes_aprobados.iter().position(|v| v.account_id == Self::env().caller()) {
-                let votante = self.elecciones[eleccion_index].votantes_aprobados[votante_index].clone();
-                self.elecciones[eleccion_index].votantes_votados.push(votante);
-            } else {
-                return Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoExiste))
+            for (eleccion_index, candidato_index) in boletas {
+                if let Err(error) = self.registrar_voto_a_candidato(candidato_index, eleccion_index) {
+                    return Err(ErrorInterfaz::new(error))
+                }
             }
 
             Ok(())
         }
-    
 
 
+        ///LE PERMITE AL ADMIN CERRAR UNA ELECCION FINALIZADA Y CONTAR LOS VOTOS
+        ///
+        ///#Uso
+        ///Al un admin llamar a la funcion con un id de una eleccion cerrada, pero que todavia esta dentro de la lista de elecciones activas,
+        ///esta es movida a la lista de elecciones finalizadas y los votos son contados.
+        ///Los candidatos quedan ordenados por cantidad de votos ponderados, de mayor a menor, dentro de el campo de votos en la eleccion.
+        ///El ganador tambien es devuelto con sus datos, como nombre, dni, y cantidad de votos. En las elecciones por listas se ordenan y devuelven las listas.
+        ///Si no hay cantidatos o votos se devuelve ResultadoEleccion::SinVotos. Los candidatos retirados o descalificados no pueden resultar ganadores,
+        ///ni tampoco un candidato activo que no recibio votos. Los votos de los dados de baja se anulan o se conservan segun lo configurado en la eleccion.
+        ///Si la eleccion tiene quorum y no se alcanzo, la eleccion queda marcada como invalidada, sin ganador, y se devuelve ResultadoEleccion::QuorumNoAlcanzado.
+        ///
+        ///#Funcionalidad
+        ///La funcion chequea si el caller es admin, despues encuentra la eleccion, si es que existe. Controla el quorum de la eleccion y detecta si hay votos,
+        ///si no se alcanzo el quorum o no hay votos la eleccion se mueve a finalizadas sin ganador, y si hay los ordena por cantidad de votos, de mayor a menor.
+        ///Por ultimo mueve la eleccion a la lista de elecciones finalizadas, y devuelve una copia de los datos del ganador.
+        ///
+        ///#Errores
+        ///Devuelve un error por la falta de privilegios de admin de ErrorSistem::NoPoseenPermisos, y un ErrorEleccion
+        ///para indicar una eleccion invalida.
+        #[ink(message)]
+        pub fn finalizar_y_contar_eleccion(&mut self, eleccion_id: u64) -> Result<ResultadoEleccion, ErrorInterfaz>
+        {
+            self.finalizar_y_contar_eleccion_priv(eleccion_id)
+        }
 
-        //////////////////////////////////////// VALIDACIONES ////////////////////////////////////////
+        fn finalizar_y_contar_eleccion_priv(&mut self, eleccion_id: u64) -> Result<ResultadoEleccion, ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
+            }
 
-        //////////////////// SISTEMA ////////////////////
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::Cerrada,  Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+            let mut eleccion = self.elecciones.swap_remove(eleccion_index);
+            if let Some(evento) = self.evento_de_eleccion(eleccion_id) {
+                eleccion.registrar_padron_de_evento(evento);
+            }
+            self.acumular_participacion(&eleccion);
+            if !eleccion.alcanza_quorum() {
+                eleccion.quorum_alcanzado = false;
+                self.elecciones_finiquitadas.push(eleccion);
+                return Ok(ResultadoEleccion::QuorumNoAlcanzado)
+            }
+            if let MetodoVotacion::Listas = eleccion.metodo_votacion {
+                if eleccion.votos_listas.is_empty() {
+                    self.elecciones_finiquitadas.push(eleccion);
+                    return Ok(ResultadoEleccion::SinVotos)
+                }
+                eleccion.votos_listas.sort_by_key(|lista| lista.votos_ponderados);
+                eleccion.votos_listas.reverse();
+                self.elecciones_finiquitadas.push(eleccion.clone());
+                return Ok(ResultadoEleccion::ListaGanadora(eleccion.votos_listas[0].clone()))
+            }
+            eleccion.aplicar_tratamiento_votos_bajas();
+            let ganador = eleccion.contar_votos_candidatos();
+            self.elecciones_finiquitadas.push(eleccion);
+            match ganador {
+                Some(candidato) => Ok(ResultadoEleccion::Ganador(candidato)),
+                None => Ok(ResultadoEleccion::SinVotos),
+            }
+        }
 
-        ///CONFIRMA LOS PERMISOS DEL ADMIN
+
+
+        /// LE PERMITE A CUALQUIER USUARIO APROBADO ACCEDER A UNA LISTA DE LAS ELECCIONES EN CURSO
         /// 
         /// #Uso
-        /// La funcion es de uso interno del sistema, recibe un AccountId y un String y retorna un Result<(),ErrorSistema>
+        /// La funcion no recibe parametros y retorna un Result<Vec<EleccionInterfaz>,ErrorInterfaz>
         /// 
         /// #Funcionalidad
-        /// 
-        /// La funcion valida que el AccountId recibido sea el admin del sistema
+        /// Si quien invoca la funcion es un usuario valido en el sistema se genera un lista de las elecciones actuales en formato de interfaz y se retorna.
         /// 
         /// #Errores
-        /// 
-        /// Los casos de error se dan si el AccountId recibido no es el admin, en  ese caso se devuelve el string recibido dentro del ErrorSistema
+        /// La funcion devuelve un ErrorInterfaz si quien la invoca no esta registrado o validado en el sistema.
         /// 
         /// ...
-        fn validar_permisos(&self, caller_id: AccountId) -> Result<(), ErrorSistema> {
-            if !self.es_admin(caller_id) { return Err( ErrorSistema::NoSePoseenPermisos); }
-            Ok(())
+        #[ink(message)]
+        pub fn get_elecciones_actuales(&mut self) -> Result<Vec<EleccionInterfaz>, ErrorInterfaz> 
+        {
+            self.get_elecciones_actuales_priv()
         }
 
-        ///INFORMA SI EL ID PROPORCIONADO ES EL ADMIN
-        fn es_admin(&self, caller_id: AccountId) -> bool { caller_id == self.admin_id }
+        fn get_elecciones_actuales_priv(&mut self) -> Result<Vec<EleccionInterfaz>, ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_caller_como_admin_o_usuario_aprobado(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
+            }
+            Ok(self.clonar_elecciones_actuales_a_interfaz(Self::env().block_timestamp()))
+        }
 
-        /// VALIDA SI EL USUARIO ES EL ADMIN O ESTA APROBADO
+        /// LE PERMITE A CUALQUIER USUARIO APROBADO VER UNA LISTA DE TODAS LAS ELECCIONES FINALIZADAS
         /// 
         /// #Uso
-        /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Result<(),ErrorSistema>
+        /// La funcion no recibe parametros y retorna un Result<Vec<EleccionInterfaz>,ErrorInterfaz>
         /// 
         /// #Funcionalidad
-        /// 
-        /// La funcion valida que el AccountId recibido sea de el admin o de un usuario aprobado en el sistema
+        /// Si quien invoca la funcion es un usuario valido en el sistema se genera un lista de las elecciones finalizadas en formato de interfaz y se retorna.
         /// 
         /// #Errores
-        /// 
-        /// Los casos de error se dan si el AccountId recibido es de un usuario que auno no fue aprobado en el sistema o
-        /// de un usuario que aun no se registro en el sistema
+        /// La funcion devuelve un ErrorInterfaz si quien la invoca no esta registrado o validado en el sistema.
         /// 
         /// ...
-        fn validar_caller_como_admin_o_usuario_aprobado(&self, caller_id: AccountId) -> Result<(), ErrorSistema>
+        #[ink(message)]
+        pub fn get_elecciones_historial(&mut self) -> Result<Vec<EleccionInterfaz>, ErrorInterfaz>
         {
-            if self.es_admin(caller_id) { return Ok(()) }
+            self.get_elecciones_historial_priv()
+        }
 
-            match self.validar_usuario(caller_id) {
-                Ok(_) => Ok(()),
-                Err(e) => Err(e),
+        fn get_elecciones_historial_priv(&mut self) -> Result<Vec<EleccionInterfaz>, ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_caller_como_admin_o_usuario_aprobado(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
             }
-        }
 
-        /// VALIDA QUE EL USUARIO ESTE APROBADO EN EL SISTEMA, DE SER EL CASO LO DEVUELVE
-        /// 
-        /// #Uso 
+            let timestamp = Self::env().block_timestamp();
+
+            let elecciones = self.clonar_elecciones_historicas_a_interfaz();
+            let elecciones = [
+                elecciones,
+                self.clonar_elecciones_actuales_a_interfaz(timestamp),
+            ]
+            .concat();
+
+            Ok(elecciones)
+        }
+
+        /// LE PERMITE VER UNA ELECCION FINALIZADA A PARTIR DE SU ID
+        #[ink(message)]
+        pub fn get_elecciones_terminadas_especifica(&self, id: u64) -> Result<Eleccion, ErrorSistema> {
+            // Las elecciones se finiquitan en cualquier orden, su posicion no coincide con su ID
+            let elecciones_buscada = self.elecciones_finiquitadas.iter().find(|eleccion| eleccion.eleccion_id == id).ok_or(ErrorSistema::EleccionInvalida)?;
+            Ok(elecciones_buscada.clone())
+        }
+
+        // fn get_elecciones_terminadas_x_priv(&self, id: u64) -> Result<Vec<Usuario>, ErrorInterfaz> {
+        //     if id as usize >= self.elecciones_finiquitadas.len() {
+        //         return Err(ErrorInterfaz::new(ErrorSistema::EleccionInvalida));
+        //     }
+        //     let elecciones_votantes = self.elecciones_finiquitadas[id as usize].votantes_aprobados.clone();
+        //     Ok(elecciones_votantes)
+        // }
+        #[ink(message)]
+        pub fn get_elecciones_finiquitadas(&self) -> Vec<Eleccion> {
+            self.get_elecciones_finiquitadas_priv()
+        }
+        
+        fn get_elecciones_finiquitadas_priv(&self) -> Vec<Eleccion> {
+            self.elecciones_finiquitadas.clone()
+        }
+
+        /// PERMITE CONOCER EL ADMIN ACTUAL DEL SISTEMA, USADO POR LOS CONTRATOS QUE RESTRINGEN SUS REPORTES AL ADMIN
+        #[ink(message)]
+        pub fn get_admin_id(&self) -> AccountId {
+            self.admin_id
+        }
+
+        /// PERMITE CONSULTAR EN QUE ELECCIONES PARTICIPO UN USUARIO Y CON QUE RESULTADO
         /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId y un string y retorna un Result<(),ErrorSistema>
+        /// #Uso
+        /// La funcion recibe el AccountId del usuario a consultar y retorna un Result<Vec<ParticipacionUsuario>, ErrorInterfaz>
         /// 
         /// #Funcionalidad
-        /// 
-        /// La funcion valida que el AccountId recibido sea el de un usuario aprobado en el sistema
+        /// Se recorren las elecciones finalizadas y las actuales, y se informa cada eleccion en la que el usuario se postulo como votante o como candidato,
+        /// ya sea individualmente o como miembro de una lista. Para cada una se indica si quedo pendiente, si fue aprobado, si voto o si fue dado de baja,
+        /// y si la eleccion sigue en curso, finalizo, o en el caso de los candidatos si resulto electo.
+        /// Cualquier usuario puede consultar su propio historial, pero solo el admin y los auditores pueden consultar el de otros usuarios.
         /// 
         /// #Errores
-        /// 
-        /// Los casos de error se dan si el AccountId proporcionado pertence a el adminm a un usuario no aprobado en el sistema o 
-        /// a un usuario no registrado en el sistema
+        /// La funcion devuelve un ErrorInterfaz si quien la invoca consulta el historial de otro usuario sin ser el admin o un auditor.
         /// 
         /// ...
-        fn validar_caller_como_usuario_aprobado(&self, caller_id: AccountId) -> Result<Usuario, ErrorSistema>
+        #[ink(message)]
+        pub fn get_historial_participacion(&self, usuario_id: AccountId) -> Result<Vec<ParticipacionUsuario>, ErrorInterfaz>
         {
-            if self.es_admin(caller_id) { return Err( ErrorSistema::AccionUnicaDeUsuarios); }
+            self.get_historial_participacion_priv(usuario_id)
+        }
 
-            self.validar_usuario(caller_id)
+        fn get_historial_participacion_priv(&self, usuario_id: AccountId) -> Result<Vec<ParticipacionUsuario>, ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos_historial(Self::env().caller(), usuario_id) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            let historial = self.elecciones_finiquitadas.iter()
+                .flat_map(|e| e.participacion_de_usuario(usuario_id, true))
+                .chain(self.elecciones.iter().flat_map(|e| match self.evento_de_eleccion(e.eleccion_id) {
+                    Some(evento) => {
+                        let mut eleccion = e.clone();
+                        eleccion.registrar_padron_de_evento(evento);
+                        eleccion.participacion_de_usuario(usuario_id, false)
+                    }
+                    None => e.participacion_de_usuario(usuario_id, false),
+                }))
+                .collect();
+
+            Ok(historial)
         }
 
-        /// VALIDA QUE UN USUARIO ESTE REGISTRADO EN EL SISTEMA
+        /// LE PERMITE A UN USUARIO APROBADO REGISTRARSE A UNA ELECCION
         /// 
-        /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Result<Usuario,ErrorSistema>
+        /// #Uso
+        /// La funcion recibe por parametro el id de la eleccion para registrarse, el Rol en el que quiere presentarse y retorna un Result<(),ErrorInterfaz>
         /// 
         /// #Funcionalidad
-        /// 
-        /// La funcion usa el AccountId recibido para buscar la posicion de un Usuario en el sistema y devuelve una copia de ese usuario
+        /// Si quien invoca la funcion es un usuario aprobado en el sistema y el id corresponde a una eleccion valida en periodo de inscripcion,
+        /// el usuario que invoca la funcion es registrado a la espera de que el admin lo valide.
         /// 
         /// #Errores
+        /// Si el admin invoca la funcion, el usuario que invoca la funcion no esta aprobado o no esta registrado la funcion devuelve un ErrorInterfaz.
         /// 
-        /// Los casos de error de esta funcion son los mismo que los de la fn validar_usuario_en_sistema
-        fn validar_usuario(&self, caller_id: AccountId) -> Result<Usuario, ErrorSistema>
+        /// La funcion tambien devuelve un ErrorInterfaz si el id de la eleccion no es valido o es de una eleccion que no esta en periodo de inscripcion.
+        /// 
+        /// ....
+        #[ink(message)]
+        pub fn registrarse_a_eleccion(
+            &mut self,
+            eleccion_id: u64,
+            rol: Rol,
+        ) -> Result<(), ErrorInterfaz> // Cómo identificar elección
         {
-            let index = self.validar_usuario_en_sistema(caller_id)?;
-            Ok(self.usuarios_registados[index].clone())
+            self.registrarse_a_eleccion_priv(eleccion_id, rol)
         }
 
-        ///CONFIRMA LA NO EXISTENCIA DE UN USUARIO EN EL SISTEMA
+        fn registrarse_a_eleccion_priv(&mut self, eleccion_id: u64, rol: Rol) -> Result<(), ErrorInterfaz>
+        {
+            let caller_user = match self.validar_caller_como_usuario_aprobado(Self::env().caller()) {
+                Ok(user) => user,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let eleccion_index = match self.validar_eleccion(
+                eleccion_id,
+                EstadoEleccion::PeriodoInscripcion,
+                Self::env().block_timestamp(),
+            ) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            match self.registrar_peticion_eleccion(caller_user, rol, eleccion_index) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(ErrorInterfaz::new(error))
+            }
+        }
+
+        /// LE PERMITE A UN USUARIO CANCELAR SU PETICION PENDIENTE DE VOTANTE O CANDIDATO EN UNA ELECCION
         /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Result<(),ErrorSistema>
+        /// La funcion recibe el id de la eleccion y retorna un Result<(),ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion revisa en todas las lista del sistema para asegurarse de que el AccountId proporcionado no exista en ninguna
+        /// Si quien invoca la funcion esta en la cola de votantes o de candidatos pendientes de una eleccion en periodo de inscripcion, se lo quita de ella
+        /// y el retiro queda registrado en el historial, pudiendo volver a registrarse con otro rol. Si era candidato tambien se descarta su perfil.
         /// 
-        /// #Erroes
+        /// #Errores
         /// 
-        /// Los casos de error se dan cuando el AccountId se encuentra en alguna de las lista
+        /// Los casos de error se dan si quien invoca no es un usuario aprobado, si la eleccion no existe o no esta en periodo de inscripcion,
+        /// si el usuario ya fue aprobado en la eleccion o si no tiene una peticion pendiente en ella
         /// 
         /// ...
-        fn consultar_inexistencia_usuario_en_sistema(&self, caller_id: AccountId) -> Result<(), ErrorSistema>
+        #[ink(message)]
+        pub fn cancelar_peticion_eleccion(&mut self, eleccion_id: u64) -> Result<(), ErrorInterfaz>
         {
-            if self.es_admin(caller_id) { return Err( ErrorSistema::AdminYaRegistrado); }
+            self.cancelar_peticion_eleccion_priv(eleccion_id)
+        }
 
-            if self.existe_usuario_en_peticiones_del_sistema(caller_id) { return Err( ErrorSistema::UsuarioYaRegistradoEnPeticiones); }
-            if self.existe_usuario_registrado_en_sistema(caller_id) { return Err( ErrorSistema::UsuarioYaRegistrado); }
+        fn cancelar_peticion_eleccion_priv(&mut self, eleccion_id: u64) -> Result<(), ErrorInterfaz>
+        {
+            let caller_user = match self.validar_caller_como_usuario_aprobado(Self::env().caller()) {
+                Ok(user) => user,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let rol = match self.validar_peticion_pendiente_en_eleccion(caller_user.account_id, eleccion_index) {
+                Ok(rol) => rol,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
 
+            self.retirar_peticion_eleccion(caller_user.account_id, eleccion_index, rol);
             Ok(())
         }
 
-        ///BUSCA UN USUARIO EN LA COLA DE PETICIONES DE REGISTRO
+        /// LE PERMITE A UN CANDIDATO PENDIENTE CARGAR O EDITAR SU PERFIL EN UNA ELECCION
         /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Result<(),ErrorSistema>
+        /// La funcion recibe el id de la eleccion, el partido o lista, una biografia corta, la url de la plataforma y el hash del manifiesto del candidato,
+        /// retorna un Result<(),ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion checkea que un AccountId este dentro de la lista de peticiones de registro del sistema
+        /// Si quien invoca la funcion es un usuario aprobado que se encuentra en la cola de candidatos pendientes de una eleccion en periodo de inscripcion,
+        /// se guarda su perfil reemplazando el que tuviera anteriormente. Una vez que el admin aprueba al candidato su perfil queda congelado.
         /// 
         /// #Errores
         /// 
-        /// Los casos de error se dan cuando el AccountId recibido ya forma parte de la lista de usuarios aprobados en el sistema o 
-        /// cuando el usuario no existe en el sistema
+        /// Los casos de error se dan si quien invoca la funcion no es un usuario aprobado, si la eleccion no existe o no esta en periodo de inscripcion,
+        /// si el usuario no es candidato en la eleccion o si ya fue aprobado como candidato
         /// 
         /// ...
-        fn consultar_peticion_sistema(&self, user_id: AccountId) -> Result<(), ErrorSistema>
+        #[ink(message)]
+        pub fn editar_perfil_candidato(&mut self, eleccion_id: u64, partido: String, biografia: String, url_plataforma: String, hash_manifiesto: Hash) -> Result<(), ErrorInterfaz>
         {
-            if self.existe_usuario_en_peticiones_del_sistema(user_id) { return Ok(()) }
+            self.editar_perfil_candidato_priv(eleccion_id, partido, biografia, url_plataforma, hash_manifiesto)
+        }
 
-            match self.existe_usuario_registrado_en_sistema(user_id) { 
-                true  => Err( ErrorSistema::UsuarioYaRegistrado),
-                false => Err( ErrorSistema::NoExisteUsuario)
+        fn editar_perfil_candidato_priv(&mut self, eleccion_id: u64, partido: String, biografia: String, url_plataforma: String, hash_manifiesto: Hash) -> Result<(), ErrorInterfaz>
+        {
+            let caller_user = match self.validar_caller_como_usuario_aprobado(Self::env().caller()) {
+                Ok(user) => user,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            if let Err(error) = self.validar_perfil_editable(caller_user.account_id, eleccion_index) {
+                return Err(ErrorInterfaz::new(error))
             }
+
+            let perfil = PerfilCandidato::new(caller_user.account_id, partido, biografia, url_plataforma, hash_manifiesto);
+            self.elecciones[eleccion_index].guardar_perfil(perfil);
+            Ok(())
         }
 
-        ///INFORMA SI UN DETERMINADO USUARIO EXISTE EN LA COLA DE PETICIONES DE REGISTRO
-        /// 
-        /// #Uso
-        /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un bool
-        /// 
-        /// #Funcionalidad
+        /// PERMITE AL ADMIN RECUPERAR LA LISTA DE TODOS LOS CANDIDATOS PENDIENTES
         /// 
-        /// La funcion retorna true cuando un usuario esta en la lista de peticiones de registro del sistema y false cuando no esta
         /// 
-        /// ...
-        fn existe_usuario_en_peticiones_del_sistema(&self, caller_id: AccountId) -> bool {
-            self.peticiones_registro
-                .iter()
-                .any(|u| u.account_id == caller_id)
-        }
-
-        ///INFORMA SI UN DETERMINADO USUARIO EXISTE EN LA COLA DE USUARIOS REGISTRADOS EN EL SISTEMA
         /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un bool
+        /// La funcion recibe el id de la eleccion de la que se quieren obtener los candidatos pendientes y retorna un Result<Vec<Usuario>,ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion retorna true cuando un usuario esta en la lista de usuarios registrados del sistema y false cuando no esta
-        fn existe_usuario_registrado_en_sistema(&self, caller_id: AccountId) -> bool {
-            self.usuarios_registados
-                .iter()
-                .any(|u| u.account_id == caller_id)
-        }
-
-        /// SE BUSCA Y RETORNA LA POSICION EN LA COLA DE UN USUARIO REGISTRADO ESPECIFICO EN CASO DE EXISTIR
-        /// 
-        /// #Use
-        /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Option<usize>
+        /// Se valida que el usuario que use la funcion sea el admin, si lo es se valida que el id de la eleccion corresponda a una eleccion calida en periodo de inscripcion
+        /// y se devuelve una lista de los candidatos pendientes a aprobacion en esa eleccion
         /// 
-        /// #Funcionalidad
+        /// #Errores
         /// 
-        /// La funcion busca la posicion del AccountId recibido en la lista de usuarios registrados en el sistema y si el accountId existe retorna su posicion
-        /// en caso contrario retorna None
+        /// los casos de error de la funcion son cuando el usuario que la invoca no es el admin, cuando el id de la eleccion no es valido o cuando el id de la eleccion no 
+        /// corresponde a una eleccion en periodo de inscripcion
         /// 
-        /// ...
-        fn get_usuario_registrado_en_sistema(&self, user_id: AccountId) -> Option<usize>
+        /// ... 
+        #[ink(message)]
+        pub fn get_candidatos_pendientes(&mut self, eleccion_id: u64) -> Result<Vec<Usuario>, ErrorInterfaz>
         {
-            for i in 0 .. self.usuarios_registados.len() {
-                if self.usuarios_registados[i].account_id == user_id { return Some(i); }
+            self.get_candidatos_pendientes_priv(eleccion_id)
+        }
+
+        fn get_candidatos_pendientes_priv(&mut self, eleccion_id: u64) -> Result<Vec<Usuario>, ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
             }
 
-            None
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            Ok( self.elecciones[eleccion_index].peticiones_candidatos.clone() )
         }
 
-        /// SE BUSCA Y RETORNA LA POSICION EN LA COLA DE UN USUARIO NO REGISTRADO ESPECIFICO EN CASO DE EXISTIR
-        /// 
-        /// #Use
-        /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Option<usize>
-        /// 
-        /// #Funcionalidad
-        /// 
-        /// La funcion busca la posicion del AccountId recibido en la lista de usuarios pendientes en el sistema y si el accountId existe retorna su posicion
-        /// en caso contrario retorna None
-        /// 
-        /// ...
-        fn get_usuario_en_peticiones_del_sistema(&self, user_id: AccountId) -> Option<usize>
+
+        #[ink(message)]
+        pub fn get_votantes_pendientes(&mut self, eleccion_id: u64) -> Result<Vec<Usuario>, ErrorInterfaz>
         {
-            for i in 0 .. self.peticiones_registro.len() {
-                if self.peticiones_registro[i].account_id == user_id { return Some(i); }
+            self.get_votantes_pendientes_priv(eleccion_id)
+        }
+
+        fn get_votantes_pendientes_priv(&mut self, eleccion_id: u64) -> Result<Vec<Usuario>, ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
             }
 
-            None
-        }
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
 
-        ///VALIDA LA EXISTENCIA DE UN USUARIO EN EL SISTEMA
+            Ok( self.elecciones[eleccion_index].peticiones_votantes.clone() )
+        }   
+        
+
+        ///PERMITE AL ADMIN APROBAR UN CANDIDATO A UNA ELECCION
         /// 
-        /// #Use
+        /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Result<usize,ErrorSistema>
+        /// La funcion recibe el id de la eleccion en la que se quiere aprobar un candidato y el dni del candidato a aprobar, retorna un Result<(),ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion busca la posicion en la lista de usuarios registrados en el sistema del AccountId recibido y la retorna
+        /// Se valida que el usuario que invoca a la funcion el admin, en ese caso se valida que el id de la eleccion sea valido y que el dni pertenezca a un candidato
+        /// esperando a ser validado en esta eleccion, si todas las condiciones se cumplen el usuario dueño de ese dni queda registrado como candidato en la eleccion
         /// 
         /// #Errores
         /// 
-        /// Los casos de error se dan cuando el AccountId aun no fue aprobado en el sistema o cuando el AccountId no existe en el sistema
-        /// 
-        /// ...
-        fn validar_usuario_en_sistema(&self, caller_id: AccountId) -> Result<usize, ErrorSistema>
+        /// Los casos de error de la funcion se dan cuando el usuario que la invoca no es admin, cuando el id de eleccion no es valido o no pertenece a una eleccion en 
+        /// periodo de inscripcion y cuando el dni del candidato no pertenece a un usuario registrado en la eleccion o un usuario ya aprobado
+        /// 
+        /// .
+        #[ink(message)]
+        pub fn aprobar_candidato_eleccion(&mut self, eleccion_id: u64, candidato_dni: String) -> Result<(), ErrorInterfaz>
         {
-            if let Some(index) = self.get_usuario_registrado_en_sistema(caller_id) { return Ok(index); }
+            self.aprobar_candidato_eleccion_priv(eleccion_id, candidato_dni)
+        }
 
-            match self.existe_usuario_en_peticiones_del_sistema(caller_id) {
-                true =>  Err( ErrorSistema::UsuarioNoAprobado),
-                false => Err( ErrorSistema::NoExisteUsuario)
+        fn aprobar_candidato_eleccion_priv(&mut self, eleccion_id: u64, candidato_dni: String) -> Result<(), ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
             }
+
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+            let candidato_index = match self.validar_candidato_en_pendientes(IdentificadorUsuario::Dni(calcular_hash_dni(&candidato_dni, &self.sal_dni)), eleccion_index) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            self.aprobar_candidato(candidato_index, eleccion_index);
+            Ok(())
         }
 
-        //////////////////// ELECCIONES ////////////////////
+        ///PERMITE AL ADMIN APROBAR UN VOTANTE A UNA ELECCION
+        #[ink(message)]
+        pub fn aprobar_votante_eleccion(&mut self, eleccion_id: u64, votante_dni: String) -> Result<(), ErrorInterfaz>
+        {
+            self.aprobar_votante_eleccion_priv(eleccion_id, votante_dni)
+        }
 
-        ///INCREMENTA EN 1 EL NUMERO DE IDS DE ELECCIONES, EN CASO DE DESBORDE LO INFORMA
-        /// 
-        /// #Errores
-        /// 
-        /// El caso de error de la funcion se da cuando se alcanzo el numero maximo de representacion con un u64
-        /// 
-        /// ...
-        fn check_add_elecciones_id(&mut self) -> Result<(), ErrorSistema> 
+        fn aprobar_votante_eleccion_priv(&mut self, eleccion_id: u64, votante_dni: String) -> Result<(), ErrorInterfaz>
         {
-            if let Some(resultado) = self.elecciones_conteo_id.checked_add(1) {
-                self.elecciones_conteo_id = resultado;
-                Ok(())
-            } else {
-                Err(ErrorSistema::RepresentacionLimiteAlcanzada)
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
+            }
+
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+            if let Err(error) = self.validar_eleccion_fuera_de_evento(eleccion_id) {
+                return Err(ErrorInterfaz::new(error))
             }
+            let votante_index = match self.validar_votante_en_pendientes(IdentificadorUsuario::Dni(calcular_hash_dni(&votante_dni, &self.sal_dni)), eleccion_index) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            self.aprobar_votante(votante_index, eleccion_index);
+            Ok(())
         }
 
-        ///DEVUELVE LA POSICION EN LA LISTA DE ELECCIONES DE UNA ELECCION ESPECIFICA
+        ///PERMITE AL ADMIN APROBAR UN CANDIDATO A UNA ELECCION IDENTIFICANDOLO POR SU ACCOUNTID
         /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe dos u64 y un EstadoEleccion y retorna un Result<usize,ErrorSistema>
+        /// La funcion recibe el id de la eleccion y el AccountId del candidato a aprobar, retorna un Result<(),ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion busca la posicion en la lista de elecciones actuales de el id de eleccion recibido (U64) y si esta en el EstadoEleccion deseado los retorna
+        /// Es equivalente a aprobar_candidato_eleccion, pero evita tener que enviar el dni del candidato
         /// 
         /// #Errores
         /// 
-        /// Los casos de error de esta funcion son los de las funciones fn existe_eleccion y fn consultar_estado_eleccion
+        /// Los casos de error son los mismos que los de aprobar_candidato_eleccion
         /// 
         /// ...
-        fn validar_eleccion(&mut self,eleccion_id: u64,estado_buscado: EstadoEleccion,timestamp: u64,) -> Result<usize, ErrorSistema> 
+        #[ink(message)]
+        pub fn aprobar_candidato_eleccion_por_cuenta(&mut self, eleccion_id: u64, candidato_id: AccountId) -> Result<(), ErrorInterfaz>
         {
-            let eleccion_index = self.existe_eleccion(eleccion_id)?;
-            self.consultar_estado_eleccion(estado_buscado, eleccion_index, timestamp)?;
-
-            Ok(eleccion_index)
+            self.aprobar_candidato_eleccion_por_cuenta_priv(eleccion_id, candidato_id)
         }
 
-        ///VALIDA LA EXISTENCIA DE UNA ELECCION Y DEVUELVE SU POSICION EN EL VEC
-        /// 
-        /// #Uso
-        /// 
-        /// La funcion es de uso interno del sistema, recibe un u64 y retorna un Result<usize,ErrorSistema> 
-        /// 
-        /// #Funcionalidad
-        /// 
-        /// La funcion checkea que el u64 pertenezca a los id de eleccion posibles en el sistema y retorna la posicion de la eleccion en la lista de elecciones
-        /// del sistema
-        /// 
-        /// #Errores
-        /// 
-        /// Los casos de error se dan si el u64 es mayor al el valor de eleccion_conteo_id del sistema, si este valor es 0 o si el id de la eleccion pertenece a una 
-        /// eleccion ya finalizada
-        /// 
-        /// ...
-        fn existe_eleccion(&self, eleccion_id: u64) -> Result<usize, ErrorSistema>
+        fn aprobar_candidato_eleccion_por_cuenta_priv(&mut self, eleccion_id: u64, candidato_id: AccountId) -> Result<(), ErrorInterfaz>
         {
-            if eleccion_id > self.elecciones_conteo_id.saturating_sub(1) || self.elecciones_conteo_id == 0 { 
-                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::NoExisteEleccion))
-            } else {
-                self.get_index_eleccion(eleccion_id)
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
             }
+
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+            let candidato_index = match self.validar_candidato_en_pendientes(IdentificadorUsuario::Cuenta(candidato_id), eleccion_index) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            self.aprobar_candidato(candidato_index, eleccion_index);
+            Ok(())
         }
 
-        ///SE BUSCA LA POSICION DE UNA ELECCION EN LAS EN PROGRESO
-        /// 
-        /// #Uso
-        /// 
-        /// La funcion es de uso interno del sistema, recibe un u64 y retorna un Result<usize,ErrorSistema> 
-        /// 
-        /// #Funcionalidad
-        /// 
-        /// la eleccion busca entre todas las elecciones actuales del sistema alguna con el mismo id que el recibido, si la encuentra devuelve la posicion de la misma
-        /// 
-        /// #Errores
-        /// 
-        /// El unico caso de error se da si la eleccion buscada no esta en la lista
-        fn get_index_eleccion(&self, eleccion_id: u64) -> Result<usize, ErrorSistema>
+        ///PERMITE AL ADMIN APROBAR UN VOTANTE A UNA ELECCION IDENTIFICANDOLO POR SU ACCOUNTID
+        #[ink(message)]
+        pub fn aprobar_votante_eleccion_por_cuenta(&mut self, eleccion_id: u64, votante_id: AccountId) -> Result<(), ErrorInterfaz>
         {
-            for i in 0 .. self.elecciones.len()
-            {
-                if self.elecciones[i].eleccion_id == eleccion_id 
-                {
-                    return Ok(i);
-                }
+            self.aprobar_votante_eleccion_por_cuenta_priv(eleccion_id, votante_id)
+        }
+
+        fn aprobar_votante_eleccion_por_cuenta_priv(&mut self, eleccion_id: u64, votante_id: AccountId) -> Result<(), ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
             }
 
-            Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionFinalizada))
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+            if let Err(error) = self.validar_eleccion_fuera_de_evento(eleccion_id) {
+                return Err(ErrorInterfaz::new(error))
+            }
+            let votante_index = match self.validar_votante_en_pendientes(IdentificadorUsuario::Cuenta(votante_id), eleccion_index) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            self.aprobar_votante(votante_index, eleccion_index);
+            Ok(())
         }
 
-        ///SE CONSULTA SI LA ELECCION ESTA EN EL ESTADO DESEADO EN CASO CONTRARIO SE INFORMA EL ESTADO ACTUAL DE LA ELECCION
+        ///PERMITE AL ADMIN ASIGNAR UN PESO AL VOTO DE UN VOTANTE APROBADO EN UNA ELECCION
         /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe dos u64 y un usize, retorna un Result<(),ErrorSistema>
+        /// La funcion recibe el id de la eleccion, el dni del votante y el peso a asignarle, retorna un Result<(),ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion revisa si el id de eleccion proporcionado portenece a una eleccion en el estado recibido
+        /// Se valida que quien invoca la funcion sea el admin, que la eleccion este en periodo de inscripcion y que el dni pertenezca a un votante
+        /// aprobado en ella. Si se cumplen las condiciones cada voto que emita ese votante sumara el peso asignado al candidato elegido,
+        /// los votantes sin peso asignado votan con peso 1.
         /// 
         /// #Errores
         /// 
-        /// Los casos de error se dan cuando el estado de la eleccion no es el buscado
+        /// Los casos de error se dan cuando quien invoca no es el admin, cuando la eleccion no existe o no esta en periodo de inscripcion,
+        /// cuando el dni no pertenece a un votante aprobado en la eleccion o cuando el peso recibido es 0
         /// 
         /// ...
-        fn consultar_estado_eleccion(&mut self, estado_buscado: EstadoEleccion, eleccion_index: usize, timestamp: u64) -> Result<(), ErrorSistema>
+        #[ink(message)]
+        pub fn asignar_peso_votante(&mut self, eleccion_id: u64, votante_dni: String, peso: u64) -> Result<(), ErrorInterfaz>
         {
-            let estado_eleccion = self.elecciones[eleccion_index].get_estado_eleccion(timestamp);
+            self.asignar_peso_votante_priv(eleccion_id, votante_dni, peso)
+        }
 
-            if estado_buscado == estado_eleccion {
-                return Ok(());
+        fn asignar_peso_votante_priv(&mut self, eleccion_id: u64, votante_dni: String, peso: u64) -> Result<(), ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
             }
 
-            match estado_eleccion {
-                EstadoEleccion::PeriodoInscripcion => Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionEnProcesoInscripcion)),
-                EstadoEleccion::PeriodoVotacion => Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionEnProcesoVotacion)),
-                EstadoEleccion::Cerrada => Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionCerrada)),
-                EstadoEleccion::Finalizada => Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionFinalizada)),
+            if peso == 0 {
+                return Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::PesoInvalido)))
             }
+
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+            let votante_index = match self.validar_votante_en_aprobados(IdentificadorUsuario::Dni(calcular_hash_dni(&votante_dni, &self.sal_dni)), eleccion_index) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let votante_id = self.padron_de_eleccion(eleccion_index).0[votante_index].account_id;
+            self.elecciones[eleccion_index].asignar_peso(votante_id, peso);
+            Ok(())
         }
 
-        // VALIDA QUE UN USUARIO NO EXISTA EN NINGUNA COLA DE ESPERA O LISTA DE VOTANTES/CANDIDATOS 
+
+        /// PERMITE AL USUARIO VOTAR EN UNA ELECCION EN LA QUE ESTE ACREDITADO, FALTA REVISAR LA EXISTENCIA DEL CANDIDATO
         /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId y un usize y retorna un Result<(),ErrorSistema>
+        /// La funcion recibe el id de una eleccion y el dni del candidato a votar, retorna un Result<(), ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion valida que un usuario no exista en ninguna lista de una eleccion
+        /// Se valida que quien invoca a la funcion sea un usuario aprobado que el id de la eleccion pertenezca a una eleccion en periodo de votacion,
+        /// luego de esto se valida que el usuario este aprobado como votante en esa eleccion y que el candidato este postulado, si se cumplen estas condiciones
+        /// se registra el voto.
         /// 
         /// #Errores
         /// 
-        /// Los casos de error se dan cuando el accountId ingresado pertenece a alguna lista dentro de la eleccion ya sea de pendientes a confirmacion o de usuarios confirmados
+        /// Los casos de error pueden darse si quien invoca la funcion es el admin o si el usuario no esta aprobado como votante en la eleccion, 
+        /// si la eleccion no esta en periodo de votacion o si el candidato no esta postulado y aprobado, o se retiro o fue descalificado
         /// 
-        /// ...      
-        fn validar_inexistencia_de_usuario_en_eleccion(&self, caller_id: AccountId, eleccion_index: usize) -> Result<(), ErrorSistema>
+        /// ...
+        #[ink(message)]
+        pub fn votar_eleccion(&mut self, eleccion_id: u64, candidato_dni: String) -> Result<(), ErrorInterfaz>
         {
-            let e = &self.elecciones[eleccion_index];
+            self.votar_eleccion_priv(eleccion_id, candidato_dni)
+        }
 
-            if e.peticiones_votantes.iter().any(|p| p.account_id == caller_id) {
-                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteEnPendiente))
-            } else if e.votantes_aprobados.iter().any(|p| p.account_id == caller_id) {
-                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteActualmenteAprobado))
-            } else if e.peticiones_candidatos.iter().any(|p| p.account_id == caller_id) {
-                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoEnPendiente))
-            } else if e.candidatos_aprobados.iter().any(|p| p.account_id == caller_id) {
-                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoActualmenteAprobado))
-            } else {
-                Ok(())
+        fn votar_eleccion_priv(&mut self, eleccion_id: u64, candidato_dni: String) -> Result<(), ErrorInterfaz>
+        {
+            let eleccion_index = match self.validar_votante_habilitado(eleccion_id) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let candidato_index = match self.validar_candidato_activo(IdentificadorUsuario::Dni(calcular_hash_dni(&candidato_dni, &self.sal_dni)), eleccion_index) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            match self.registrar_voto_a_candidato(candidato_index, eleccion_index) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(ErrorInterfaz::new(error))
             }
         }
 
-        /// VALIDA QUE UN USUARIO ESTE VALIDADO COMO VOTANTE
+        /// PERMITE AL USUARIO VOTAR A UN CANDIDATO IDENTIFICANDOLO POR SU ACCOUNTID
         /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un AccountId y un usize y retorna un Result<(),ErrorSistema>
+        /// La funcion recibe el id de una eleccion y el AccountId del candidato a votar, retorna un Result<(), ErrorInterfaz>
         /// 
-        /// #Funcinalidad
+        /// #Funcionalidad
         /// 
-        /// La funcion valida que el AccountId recibido pertenezca a la eleccion que se encuentra en la posicion recibida y este aprobado como votante en ella
+        /// Es equivalente a votar_eleccion, pero evita que el votante tenga que enviar el dni del candidato en su voto
         /// 
         /// #Errores
         /// 
-        /// Los casos de error se dan cuando el accountId recibido no pertenece a un votante aprobado en la eleccion
+        /// Los casos de error son los mismos que los de votar_eleccion
         /// 
         /// ...
-        fn validar_votante_aprobado_en_eleccion(&self, votante_id: AccountId, eleccion_index: usize) -> Result<(), ErrorSistema>
+        #[ink(message)]
+        pub fn votar_eleccion_por_cuenta(&mut self, eleccion_id: u64, candidato_id: AccountId) -> Result<(), ErrorInterfaz>
         {
-            if self.elecciones[eleccion_index].votantes_aprobados.iter().any(|v| v.account_id == votante_id) { return Ok(()); }
+            self.votar_eleccion_por_cuenta_priv(eleccion_id, candidato_id)
+        }
 
+        fn votar_eleccion_por_cuenta_priv(&mut self, eleccion_id: u64, candidato_id: AccountId) -> Result<(), ErrorInterfaz>
+        {
+            let eleccion_index = match self.validar_votante_habilitado(eleccion_id) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
 
-            return match self.elecciones[eleccion_index].peticiones_votantes.iter().any(|v| v.account_id == votante_id) {
-                true  => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoAprobado)),
-                false => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoExiste))
+            let candidato_index = match self.validar_candidato_activo(IdentificadorUsuario::Cuenta(candidato_id), eleccion_index) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
             };
+
+            match self.registrar_voto_a_candidato(candidato_index, eleccion_index) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(ErrorInterfaz::new(error))
+            }
         }
 
-        ///EL SISTEMA VALIDA QUE EL CANDIDATO A APROBAR ESTE EN LA LISTA DE CANDIDATOS PENDIENTES
+        /// PERMITE AL USUARIO VOTAR A UN CANDIDATO IDENTIFICANDOLO POR SU NUMERO DE CANDIDATO EN LA ELECCION
         /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un String y un usize y retorna un Result<usize,ErrorSistema>
+        /// La funcion recibe el id de una eleccion y el numero del candidato a votar, retorna un Result<(), ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion busca el string recibido en la eleccion recibida y retorna la pocision del candidato con su dni= al String recibido
+        /// Cada candidato aprobado recibe un numero, comenzando desde 1 en el orden en que fue aprobado, que no cambia durante la eleccion
+        /// y se puede consultar en los datos de la eleccion. Votar por numero evita enviar datos del candidato y no requiere buscarlo.
         /// 
         /// #Errores
         /// 
-        /// Los casos de error se dan si el String pretenece a un candidato ya aprobado o no pertenece a ningun candidato
+        /// Los casos de error son los mismos que los de votar_eleccion, ademas de cuando el numero no pertenece a ningun candidato aprobado
         /// 
         /// ...
-        fn validar_candidato_en_pendientes(&self, candidato_dni: String, eleccion_index: usize) -> Result<usize, ErrorSistema>
+        #[ink(message)]
+        pub fn votar_eleccion_por_numero(&mut self, eleccion_id: u64, numero_candidato: u32) -> Result<(), ErrorInterfaz>
         {
-            if let Some(index) = self.get_candidato_pendiente(candidato_dni.clone(), eleccion_index) { return Ok(index) }
+            self.votar_eleccion_por_numero_priv(eleccion_id, numero_candidato)
+        }
 
-            match self.get_candidato_aprobado(candidato_dni, eleccion_index).is_some() {
-                true  => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoActualmenteAprobado)),
-                false => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste)),
+        fn votar_eleccion_por_numero_priv(&mut self, eleccion_id: u64, numero_candidato: u32) -> Result<(), ErrorInterfaz>
+        {
+            let eleccion_index = match self.validar_votante_habilitado(eleccion_id) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let candidato_index = match self.validar_numero_candidato(numero_candidato, eleccion_index) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            match self.registrar_voto_a_candidato(candidato_index, eleccion_index) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(ErrorInterfaz::new(error))
             }
         }
 
-        ///EL SISTEMA VALIDA QUE EL VOTANTE A APROBAR ESTE EN LA LISTA DE VOTANTES PENDIENTES
+        /// LE PERMITE A UN CANDIDATO APROBADO RETIRAR SU CANDIDATURA DE UNA ELECCION
         /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un String y un usize y retorna un Result<usize,ErrorSistema>
+        /// La funcion recibe el id de la eleccion y retorna un Result<(),ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion busca el string recibido en la eleccion recibida y retorna la pocision del votante con su dni= al String recibido
+        /// Si quien invoca la funcion es un candidato aprobado en una eleccion que todavia no cerro, el candidato queda retirado y deja de poder ser votado.
+        /// Los votos que ya recibio se anulan o se conservan al finalizar la eleccion segun lo configurado por el admin, y no puede resultar ganador.
         /// 
         /// #Errores
         /// 
-        /// Los casos de error se dan si el String pretenece a un votante ya aprobado o no pertenece a ningun votante
+        /// Los casos de error se dan si quien invoca no es un usuario aprobado, si la eleccion no existe o ya cerro, si no es un candidato aprobado
+        /// en la eleccion o si ya se retiro o fue descalificado
         /// 
         /// ...
-        fn validar_votante_en_pendientes(&self, votante_dni: String, eleccion_index: usize) -> Result<usize, ErrorSistema>
+        #[ink(message)]
+        pub fn retirar_candidatura(&mut self, eleccion_id: u64) -> Result<(), ErrorInterfaz>
         {
-            if let Some(index) = self.get_votante_pendiente(votante_dni.clone(), eleccion_index) { return Ok(index) }
+            self.retirar_candidatura_priv(eleccion_id)
+        }
 
-            match self.get_votante_aprobado(votante_dni, eleccion_index).is_some() {
-                true  => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteActualmenteAprobado)),
-                false => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoExiste)),
-            }
+        fn retirar_candidatura_priv(&mut self, eleccion_id: u64) -> Result<(), ErrorInterfaz>
+        {
+            let caller_user = match self.validar_caller_como_usuario_aprobado(Self::env().caller()) {
+                Ok(user) => user,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let eleccion_index = match self.validar_eleccion_no_cerrada(eleccion_id, Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let candidato_index = match self.validar_candidato_activo(IdentificadorUsuario::Cuenta(caller_user.account_id), eleccion_index) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            self.dar_de_baja_candidato(candidato_index, eleccion_index, EstadoCandidato::Retirado);
+            Ok(())
         }
 
-    
-        ///EL SISTEMA VALIDA QUE EL CANDIDATO SE ENCUENTRE EN LA LISTA DE CANDIDATOS APROBADOS
+        /// PERMITE A UN CANDIDATO HACER PUBLICO SU DNI COMPLETO EN UNA ELECCION
         /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un String y un usize y retorna un Result<usize,ErrorSistema>
+        /// La funcion recibe el id de la eleccion y el dni del candidato que invoca la funcion, retorna un Result<(),ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion busca el string recibido en la eleccion recibida y retorna la pocision del candidato con su dni= al String recibido
+        /// Por defecto los dni se muestran enmascarados, dejando visibles solo sus ultimos 3 caracteres. Si quien invoca la funcion es un candidato,
+        /// pendiente o aprobado, de una eleccion que todavia no cerro, su dni pasa a mostrarse completo en la eleccion y en sus resultados.
+        /// El sistema solo guarda el hash del dni, por lo que el candidato debe ingresarlo y este se verifica contra el hash registrado.
+        /// El dni ingresado viaja en texto plano en la transaccion, como en el resto de los mensajes que reciben un dni.
         /// 
         /// #Errores
         /// 
-        /// Los casos de error se dan si el String pretenece a un candidato no aprobado o no pertenece a ningun candidato
+        /// Los casos de error se dan si quien invoca no es un usuario aprobado, si la eleccion no existe o ya cerro, si el dni ingresado
+        /// no es el del candidato o si no es candidato en la eleccion
         /// 
         /// ...
-        fn validar_candidato_aprobado(&self, candidato_dni: String, eleccion_index: usize) -> Result<usize, ErrorSistema>
+        #[ink(message)]
+        pub fn publicar_dni_candidato(&mut self, eleccion_id: u64, dni: String) -> Result<(), ErrorInterfaz>
+        {
+            self.publicar_dni_candidato_priv(eleccion_id, dni)
+        }
+
+        fn publicar_dni_candidato_priv(&mut self, eleccion_id: u64, dni: String) -> Result<(), ErrorInterfaz>
         {
-            if let Some(index) = self.get_candidato_aprobado(candidato_dni.clone(), eleccion_index) { return Ok(index) }
+            let caller_user = match self.validar_caller_como_usuario_aprobado(Self::env().caller()) {
+                Ok(user) => user,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
 
-            match self.get_candidato_pendiente(candidato_dni, eleccion_index).is_some() {
-                true  => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoAprobado)),
-                false => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste)),
+            let eleccion_index = match self.validar_eleccion_no_cerrada(eleccion_id, Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            if calcular_hash_dni(&dni, &self.sal_dni) != caller_user.dni_hash {
+                return Err(ErrorInterfaz::new(ErrorSistema::DniIncorrecto))
             }
+
+            let candidato = IdentificadorUsuario::Cuenta(caller_user.account_id);
+            if self.get_candidato_aprobado(candidato, eleccion_index).is_none() && self.get_candidato_pendiente(candidato, eleccion_index).is_none() {
+                return Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste)))
+            }
+
+            self.elecciones[eleccion_index].publicar_dni(caller_user.account_id, dni);
+            Ok(())
         }
 
-        ///EL SISTEMA BUSCA AL CANDIDATO APROBADO EN LA LISTA Y DEVUELVE SU POSICION EN EL VECTOR
-        ///
+        /// PERMITE AL ADMIN DESCALIFICAR A UN CANDIDATO APROBADO DE UNA ELECCION
+        /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un String y un usize y retorna un Option<usize>
+        /// La funcion recibe el id de la eleccion, el dni del candidato y el motivo de la descalificacion, retorna un Result<(),ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion busca en la lista de candidatos aprobados de una eleccion uno que tenga el dni = al string recibido y retorna su posicion,
-        /// si no lo encuntra retorna un None
+        /// Si quien invoca la funcion es el admin y el candidato esta aprobado en una eleccion que todavia no cerro, el candidato queda descalificado
+        /// con el motivo indicado y deja de poder ser votado. Sus votos se tratan igual que los de un candidato retirado.
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si quien invoca no es el admin, si la eleccion no existe o ya cerro, si el dni no pertenece a un candidato aprobado
+        /// o si el candidato ya se retiro o fue descalificado
         /// 
         /// ...
-        fn get_candidato_aprobado(&self, candidato_dni: String, eleccion_index: usize) -> Option<usize> 
+        #[ink(message)]
+        pub fn descalificar_candidato(&mut self, eleccion_id: u64, candidato_dni: String, motivo: String) -> Result<(), ErrorInterfaz>
         {
-            for i in 0 .. self.elecciones[eleccion_index].candidatos_aprobados.len() {
-                if self.elecciones[eleccion_index].candidatos_aprobados[i].dni == candidato_dni { return Some(i); }
+            self.descalificar_candidato_priv(eleccion_id, candidato_dni, motivo)
+        }
+
+        fn descalificar_candidato_priv(&mut self, eleccion_id: u64, candidato_dni: String, motivo: String) -> Result<(), ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
             }
 
-            None
+            let eleccion_index = match self.validar_eleccion_no_cerrada(eleccion_id, Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let candidato_index = match self.validar_candidato_activo(IdentificadorUsuario::Dni(calcular_hash_dni(&candidato_dni, &self.sal_dni)), eleccion_index) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            self.dar_de_baja_candidato(candidato_index, eleccion_index, EstadoCandidato::Descalificado(motivo));
+            Ok(())
         }
 
-        ///EL SISTEMA BUSCA AL VOTANTE APROBADO EN LA LISTA Y DEVUELVE SU POSICION EN EL VECTOR
-        ///
+        /// PERMITE AL ADMIN CONFIGURAR QUE SUCEDE CON LOS VOTOS DE LOS CANDIDATOS RETIRADOS O DESCALIFICADOS
+        /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un String y un usize y retorna un Option<usize>
+        /// La funcion recibe el id de la eleccion y el tratamiento a aplicar a los votos, retorna un Result<(),ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion busca en la lista de votantes aprobados de una eleccion uno que tenga el dni = al string recibido y retorna su posicion,
-        /// si no lo encuntra retorna un None
+        /// El tratamiento se aplica al finalizar la eleccion. Si los votos se anulan, se descuentan de los candidatos dados de baja y se cuentan como votos anulados,
+        /// si se conservan, quedan en los resultados del candidato pero este no puede resultar ganador. Por defecto los votos se conservan.
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si quien invoca no es el admin, si la eleccion no existe o si ya no esta en periodo de inscripcion,
+        /// ya que el tratamiento no puede cambiarse una vez que comenzo la votacion
         /// 
         /// ...
-        fn get_votante_aprobado(&self, votante_dni: String, eleccion_index: usize) -> Option<usize> 
+        #[ink(message)]
+        pub fn configurar_votos_de_bajas(&mut self, eleccion_id: u64, tratamiento: TratamientoVotos) -> Result<(), ErrorInterfaz>
         {
-            for i in 0 .. self.elecciones[eleccion_index].votantes_aprobados.len() {
-                if self.elecciones[eleccion_index].votantes_aprobados[i].dni == votante_dni { return Some(i); }
+            self.configurar_votos_de_bajas_priv(eleccion_id, tratamiento)
+        }
+
+        fn configurar_votos_de_bajas_priv(&mut self, eleccion_id: u64, tratamiento: TratamientoVotos) -> Result<(), ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
             }
 
-            None
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion, Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            self.elecciones[eleccion_index].tratamiento_votos_bajas = tratamiento;
+            Ok(())
         }
 
-        
-        ///EL SISTEMA BUSCA A UN CANDIDATO EN LA LISTA DE CANDIDATOS PENDIENTES Y DEVUELVE SU POSICION EN EL VECTOR
-        ///
+        //////////////////// LISTAS ////////////////////
+
+        /// LE PERMITE A UN USUARIO APROBADO REGISTRAR UNA LISTA EN UNA ELECCION POR LISTAS
+        /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un String y un usize y retorna un Option<usize>
+        /// La funcion recibe el id de la eleccion, el nombre de la lista y los AccountId de sus miembros, retorna un Result<(),ErrorInterfaz>
         /// 
         /// #Funcionalidad
         /// 
-        /// La funcion busca en la lista de candidatos pendientes de una eleccion uno que tenga el dni = al string recibido y retorna su posicion,
-        /// si no lo encuntra retorna un None
+        /// Quien invoca la funcion queda como lider de la lista y es agregado como su primer miembro. Si la eleccion se vota por listas y esta en periodo
+        /// de inscripcion, y todos los miembros son usuarios aprobados que no participan de la eleccion, la lista queda a la espera de que el admin la apruebe.
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si el lider o algun miembro no es un usuario aprobado, si la eleccion no existe, no esta en periodo de inscripcion
+        /// o no se vota por listas, si ya existe una lista con ese nombre en la eleccion o si algun miembro ya participa de la eleccion
         /// 
         /// ...
-        fn get_candidato_pendiente(&self, candidato_dni: String, eleccion_index: usize) -> Option<usize>  
+        #[ink(message)]
+        pub fn registrar_lista(&mut self, eleccion_id: u64, nombre_lista: String, miembros: Vec<AccountId>) -> Result<(), ErrorInterfaz>
         {
-            for i in 0 .. self.elecciones[eleccion_index].peticiones_candidatos.len() {
-                if self.elecciones[eleccion_index].peticiones_candidatos[i].dni == candidato_dni { return Some(i); }
+            self.registrar_lista_priv(eleccion_id, nombre_lista, miembros)
+        }
+
+        fn registrar_lista_priv(&mut self, eleccion_id: u64, nombre_lista: String, miembros: Vec<AccountId>) -> Result<(), ErrorInterfaz>
+        {
+            let lider = match self.validar_caller_como_usuario_aprobado(Self::env().caller()) {
+                Ok(user) => user,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            if let Err(error) = self.validar_metodo_votacion(MetodoVotacion::Listas, eleccion_index) {
+                return Err(ErrorInterfaz::new(error))
             }
 
-            None
+            if self.get_lista_pendiente(&nombre_lista, eleccion_index).is_some() || self.get_lista_aprobada(&nombre_lista, eleccion_index).is_some() {
+                return Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::ListaYaExiste)))
+            }
+
+            let mut lista = Lista::new(nombre_lista, lider.account_id);
+            lista.miembros.push(lider);
+            for miembro_id in miembros {
+                if lista.miembros.iter().any(|m| m.account_id == miembro_id) { continue; }
+
+                let miembro = match self.validar_caller_como_usuario_aprobado(miembro_id) {
+                    Ok(user) => user,
+                    Err(error) => return Err(ErrorInterfaz::new(error))
+                };
+                lista.miembros.push(miembro);
+            }
+
+            for miembro in lista.miembros.iter() {
+                if let Err(error) = self.validar_inexistencia_de_usuario_en_eleccion(miembro.account_id, eleccion_index) {
+                    return Err(ErrorInterfaz::new(error))
+                }
+            }
+
+            self.elecciones[eleccion_index].peticiones_listas.push(lista);
+            Ok(())
         }
 
-        ///EL SISTEMA BUSCA A UN VOTANTE EN LA LISTA DE VOTANTES PENDIENTES Y DEVUELVE SU POSICION EN EL VECTOR
-        ///
+        /// PERMITE AL ADMIN RECUPERAR LAS LISTAS PENDIENTES DE APROBACION DE UNA ELECCION
+        /// 
         /// #Uso
         /// 
-        /// La funcion es de uso interno del sistema, recibe un String y un usize y retorna un Option<usize>
+        /// La funcion recibe el id de la eleccion y retorna un Result<Vec<Lista>,ErrorInterfaz>
         /// 
-        /// #Funcionalidad
+        /// #Errores
         /// 
-        /// La funcion busca en la lista de votantes pendientes de una eleccion uno que tenga el dni = al string recibido y retorna su posicion,
-        /// si no lo encuntra retorna un None
+        /// Los casos de error se dan cuando quien la invoca no es el admin o cuando la eleccion no existe o no esta en periodo de inscripcion
         /// 
         /// ...
-        fn get_votante_pendiente(&self, votante_dni: String, eleccion_index: usize) -> Option<usize>  
+        #[ink(message)]
+        pub fn get_listas_pendientes(&mut self, eleccion_id: u64) -> Result<Vec<Lista>, ErrorInterfaz>
         {
-            for i in 0 .. self.elecciones[eleccion_index].peticiones_votantes.len() {
-                if self.elecciones[eleccion_index].peticiones_votantes[i].dni == votante_dni { return Some(i); }
-            }
-
-            None
+            self.get_listas_pendientes_priv(eleccion_id)
         }
-    }
 
+        fn get_listas_pendientes_priv(&mut self, eleccion_id: u64) -> Result<Vec<Lista>, ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
+            }
 
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
 
-    #[derive(Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    pub enum ErrorSistema
-    {
-        UsuarioYaRegistrado,
-        AdminYaRegistrado,
-        UsuarioYaRegistradoEnPeticiones,
-        NoExisteUsuario,
-        UsuarioNoAprobado,
-        NoSePoseenPermisos,
-        AccionUnicaDeUsuarios,
-        RepresentacionLimiteAlcanzada,
-        FechaInicioInvalida(ErrorFecha),
-        FechaCierreInvalida(ErrorFecha),
-        FechaInicioPasada,
-        FechaCierrePasada,
-        FechaCierreAntesInicio,
-        EleccionInvalida,
-        VotanteYaVoto,
-        ResultadosNoDisponibles,
-        ErrorDeEleccion(ErrorEleccion),
-    }
+            Ok( self.elecciones[eleccion_index].peticiones_listas.clone() )
+        }
 
-    impl ToString for ErrorSistema {
-        fn to_string(&self) -> String 
+        /// PERMITE AL ADMIN APROBAR UNA LISTA EN UNA ELECCION
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion recibe el id de la eleccion y el nombre de la lista a aprobar, retorna un Result<(),ErrorInterfaz>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Se valida que quien invoca la funcion sea el admin, que la eleccion este en periodo de inscripcion y que la lista este pendiente de aprobacion,
+        /// la lista se aprueba como una unidad junto a todos sus miembros y se habilita para recibir votos
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan cuando quien invoca no es el admin, cuando la eleccion no existe o no esta en periodo de inscripcion
+        /// y cuando la lista no existe o ya fue aprobada
+        /// 
+        /// ...
+        #[ink(message)]
+        pub fn aprobar_lista_eleccion(&mut self, eleccion_id: u64, nombre_lista: String) -> Result<(), ErrorInterfaz>
         {
-            match self {
-                ErrorSistema::UsuarioYaRegistrado => "El usuario ya se encuentra registrado y aprobado en el sistema".to_owned(),
-                ErrorSistema::AdminYaRegistrado => "Los administradores se registran al momento de instanciar el sistema, ó de delegar su rol.".to_owned(),
-                ErrorSistema::UsuarioYaRegistradoEnPeticiones => "El usuario ya se encuentra registrado en la cola de aprobación del sistema, deberá esperar a ser aprobado.".to_owned(),
-                ErrorSistema::NoExisteUsuario => "El usuario no existe en el sistema.".to_owned(),
-                ErrorSistema::UsuarioNoAprobado => "Usted se encuentra dentro de la cola de peticiones del sistema, debe esperar a ser aceptado.".to_owned(),
-                ErrorSistema::NoSePoseenPermisos => "Solo el administrador puede realizar esta accion.".to_owned(),
-                ErrorSistema::AccionUnicaDeUsuarios => "Solo los usuarios pueden realizar esta accion.".to_owned(),
-                ErrorSistema::RepresentacionLimiteAlcanzada => "La máxima representación del tipo de dato fue alcanzada. Contacte al administrador para mantenimiento urgente.".to_owned(),
-                ErrorSistema::FechaInicioInvalida(error) => error.to_string(),
-                ErrorSistema::FechaCierreInvalida(error) => error.to_string(),
-                ErrorSistema::FechaInicioPasada => "La fecha de incio de la eleccion es anterior al dia actual.".to_owned(),
-                ErrorSistema::FechaCierrePasada => "La fecha de cierre de la eleccion es anterior al dia actual.".to_owned(),
-                ErrorSistema::FechaCierreAntesInicio => "La fecha de cierre de la eleccion es anterior a la fecha de inicio.".to_owned(),
-                ErrorSistema::EleccionInvalida => "La elección ingresada no existe.".to_owned(),
-                ErrorSistema::VotanteYaVoto => "El votante ya ha votado.".to_owned(),
-                ErrorSistema::ErrorDeEleccion(error) => error.to_string(),
-                ErrorSistema::ResultadosNoDisponibles => "Los resultados de la elección no están disponibles.".to_owned(),
-            }
+            self.aprobar_lista_eleccion_priv(eleccion_id, nombre_lista)
         }
-    }
 
-    #[derive(Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    pub struct ErrorInterfaz {
-        error: ErrorSistema,
-        texto: String
-    }
+        fn aprobar_lista_eleccion_priv(&mut self, eleccion_id: u64, nombre_lista: String) -> Result<(), ErrorInterfaz>
+        {
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
+            }
 
-    impl ErrorInterfaz {
-        fn new(error: ErrorSistema) -> Self
+            let eleccion_index = match self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoInscripcion,  Self::env().block_timestamp()) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            let lista_index = match self.validar_lista_en_pendientes(&nombre_lista, eleccion_index) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
+
+            self.aprobar_lista(lista_index, eleccion_index);
+            Ok(())
+        }
+
+        /// PERMITE AL USUARIO VOTAR A UNA LISTA EN UNA ELECCION EN LA QUE ESTE ACREDITADO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion recibe el id de una eleccion y el nombre de la lista a votar, retorna un Result<(), ErrorInterfaz>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Se valida que quien invoca a la funcion sea un votante aprobado que todavia no voto en una eleccion en periodo de votacion
+        /// y que la lista este aprobada, si se cumplen estas condiciones se registra el voto a la lista.
+        /// 
+        /// #Errores
+        /// 
+        /// Los mismos casos de error que votar_eleccion, ademas de cuando la lista no existe o no fue aprobada
+        /// 
+        /// ...
+        #[ink(message)]
+        pub fn votar_lista(&mut self, eleccion_id: u64, nombre_lista: String) -> Result<(), ErrorInterfaz>
         {
-            let texto = error.to_string();
-            ErrorInterfaz { error, texto }
+            self.votar_lista_priv(eleccion_id, nombre_lista)
         }
-    }
 
-    //////////////////////////////// ELECCIONES ////////////////////////////////
+        fn votar_lista_priv(&mut self, eleccion_id: u64, nombre_lista: String) -> Result<(), ErrorInterfaz>
+        {
+            let eleccion_index = match self.validar_votante_habilitado(eleccion_id) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
 
+            let lista_index = match self.validar_lista_aprobada(&nombre_lista, eleccion_index) {
+                Ok(index) => index,
+                Err(error) => return Err(ErrorInterfaz::new(error))
+            };
 
-    #[derive(Clone, Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    pub struct EleccionInterfaz
-    {
-        eleccion_id: u64,
-        cargo: String,
+            match self.registrar_voto_a_lista(lista_index, eleccion_index) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(ErrorInterfaz::new(error))
+            }
+        }
 
-        fecha_inicio: Fecha,
-        fecha_cierre: Fecha,
+        //////////////////////////////////////// PRIVATES ////////////////////////////////////////
 
-        estado_eleccion: EstadoEleccion,
-        candidatos_aprobados: Vec<Usuario>,
-        resultados: Option<Vec<CandidatoVotos>>
-    }
+        //////////////////// SISTEMA ////////////////////
 
-    impl EleccionInterfaz {
-        fn new(
-            eleccion_id: u64,
-            cargo: String,
-            fecha_inicio: Fecha,
-            fecha_cierre: Fecha,
-            estado_eleccion: EstadoEleccion,
-            candidatos_aprobados: Vec<Usuario>,
-            resultados: Option<Vec<CandidatoVotos>>
-        ) -> Self {
-            EleccionInterfaz {
-                eleccion_id,
-                cargo,
-                fecha_inicio,
-                fecha_cierre,
-                estado_eleccion,
-                candidatos_aprobados,
-                resultados
-            }
-        }
-        ///CREAR UNA ELECCION INTERFAZ A PARTIR DE UNA ELECCION INTERNA DEL SISTEMA
-        fn from_eleccion(estado_eleccion: EstadoEleccion, eleccion: Eleccion, resultados: Option<Vec<CandidatoVotos>>) -> EleccionInterfaz {
-            EleccionInterfaz::new(
-                eleccion.eleccion_id,
-                eleccion.cargo,
-                eleccion.fecha_inicio_interfaz,
-                eleccion.fecha_cierre_interfaz,
-                estado_eleccion,
-                eleccion.candidatos_aprobados,
-                resultados
-            )
+        ///SE APRUEBA UN USUARIO EN EL SISTEMA
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion busca el AccountId proporcionado en la lista de peticiones de registro del sistema y se guarda la posicion del mismo
+        /// despues elemina al user de la lista de peticiones y lo agrega a la lista de usuarios validos
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores ya que se filtran anteriormente
+        /// 
+        /// ...
+        fn aprobar_usuario(&mut self, usuario_account_id: AccountId)
+        {
+            let index = self.get_usuario_en_peticiones_del_sistema(usuario_account_id);
+            let user = self.peticiones_registro.remove(index.unwrap()); // Unwrap porque ya sé que existe en el vec
+            self.usuarios_registados.push(user);
         }
-    }
-
 
+        /// SE AGREGA UN USUARIO A LA COLA DE ESPERA DEL SISTEMA
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un Usuario
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion agrega al usuario recibido a la lista de peticiones de registro del sistema y registra su dni en el indice
+        /// 
+        /// ...
+        fn registrar_en_cola_de_sistema(&mut self, user: Usuario) {
+            self.indexar_dni(user.dni_hash, user.account_id);
+            self.peticiones_registro.push(user);
+        }
 
+        /// SE QUITA A UN USUARIO DE LA COLA DE ESPERA DEL SISTEMA Y SE REGISTRA EL RETIRO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores ya que se filtran anteriormente
+        /// 
+        /// ...
+        fn retirar_peticion_sistema(&mut self, usuario_account_id: AccountId)
+        {
+            let index = self.get_usuario_en_peticiones_del_sistema(usuario_account_id);
+            let user = self.peticiones_registro.remove(index.unwrap()); // Unwrap porque ya sé que existe en el vec
+            self.indice_dni.retain(|r| r.account_id != usuario_account_id);
+            self.historial_retiros.push(RetiroPeticion::new(user, TipoPeticion::Sistema, Self::env().block_timestamp()));
+        }
 
-    #[derive(Clone, Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    pub struct Eleccion
-    {
-        eleccion_id: u64, // Número alto de representación para un futuro sustento
-
-        cargo: String, // Decido String en vez de ENUM debido a la inmensa cantidad de cargos posibles, al fin y al cabo, quien se encarga de esto es el administrador electoral
-
-        fecha_inicio: Timestamp, // Dato pedido del profe
-        fecha_cierre: Timestamp,
-
-        fecha_inicio_interfaz: Fecha,
-        fecha_cierre_interfaz: Fecha,
+        /// SE AGREGA UN CAMBIO DE PERFIL A LA COLA DE REVISION, REEMPLAZANDO EL QUE EL USUARIO TUVIERA PENDIENTE
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el Usuario con los datos propuestos
+        /// 
+        /// ...
+        fn registrar_cambio_perfil(&mut self, propuesta: Usuario)
+        {
+            match self.peticiones_cambio_perfil.iter_mut().find(|u| u.account_id == propuesta.account_id) {
+                Some(pendiente) => *pendiente = propuesta,
+                None => self.peticiones_cambio_perfil.push(propuesta),
+            }
+        }
 
-        votos: Vec<CandidatoVotos>, // No se deben poder getterar hasta que el Timestamp de cierre haya sido alcanzado
+        /// SE REEMPLAZAN LOS DATOS DE UN USUARIO EN EL SISTEMA Y EN LAS ELECCIONES ACTIVAS
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el Usuario con los datos aprobados
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Se actualiza el usuario registrado y cada copia del mismo en las elecciones que todavia no fueron finalizadas,
+        /// las elecciones finiquitadas conservan los datos con los que se cerraron
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores ya que se filtran anteriormente
+        /// 
+        /// ...
+        fn aplicar_cambio_perfil(&mut self, usuario: Usuario)
+        {
+            if let Some(index) = self.get_usuario_registrado_en_sistema(usuario.account_id) {
+                self.usuarios_registados[index] = usuario.clone();
+            }
+            self.indexar_dni(usuario.dni_hash, usuario.account_id);
 
-        candidatos_aprobados: Vec<Usuario>,
-        peticiones_candidatos: Vec<Usuario>,
+            for eleccion in self.elecciones.iter_mut() {
+                eleccion.actualizar_usuario(&usuario);
+            }
+            for evento in self.eventos_elecciones.iter_mut() {
+                evento.actualizar_usuario(&usuario);
+            }
+        }
 
-        votantes_aprobados: Vec<Usuario>,
-        peticiones_votantes: Vec<Usuario>,
-        votantes_votados: Vec<Usuario>
-    }
+        /// ASOCIA UN DNI A UNA CUENTA EN EL INDICE, REEMPLAZANDO EL DNI QUE LA CUENTA TUVIERA ANTERIORMENTE
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el dni y el AccountId. Que el dni este disponible se valida anteriormente
+        /// 
+        /// ...
+        fn indexar_dni(&mut self, dni_hash: Hash, account_id: AccountId)
+        {
+            self.indice_dni.retain(|r| r.account_id != account_id);
+            self.indice_dni.push(RegistroDni::new(dni_hash, account_id));
+        }
 
-    impl Eleccion {
-        pub fn new(
-            eleccion_id: u64,
-            cargo: String,
-            fecha_inicio: Timestamp,
-            fecha_cierre: Timestamp,
-            fecha_inicio_interfaz: Fecha,
-            fecha_cierre_interfaz: Fecha,
-        ) -> Self {
-            Eleccion {
-                eleccion_id,
-                cargo,
-                fecha_inicio,
-                fecha_cierre,
-                fecha_inicio_interfaz,
-                fecha_cierre_interfaz,
+        /// SE MUEVE UN USUARIO DEL SISTEMA DE UNA CUENTA A OTRA
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el AccountId actual y el nuevo
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Se actualiza la cuenta del usuario en la lista de usuarios aprobados o de peticiones del sistema, en su cambio de perfil pendiente y en el indice de dni
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores ya que se filtran anteriormente
+        /// 
+        /// ...
+        fn reasignar_cuenta_en_sistema(&mut self, cuenta_actual: AccountId, nueva_cuenta: AccountId)
+        {
+            self.usuarios_registados.iter_mut()
+                .chain(self.peticiones_registro.iter_mut())
+                .chain(self.peticiones_cambio_perfil.iter_mut())
+                .filter(|u| u.account_id == cuenta_actual)
+                .for_each(|u| u.account_id = nueva_cuenta);
+
+            if let Some(registro) = self.indice_dni.iter_mut().find(|r| r.account_id == cuenta_actual) {
+                registro.account_id = nueva_cuenta;
+            }
+        }
 
-                votos: Vec::new(),
+        /// SE MUEVE UN USUARIO DE UNA CUENTA A OTRA EN EL SISTEMA Y EN TODAS LAS ELECCIONES ACTIVAS
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el AccountId actual y el nuevo
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Ademas de la cuenta en el sistema, se migran las peticiones, aprobaciones, perfiles, pesos, listas y votos registrados del usuario
+        /// en las elecciones que todavia no fueron finalizadas y en el padron de los eventos y de las plantillas. Las elecciones finiquitadas conservan la cuenta
+        /// con la que se cerraron, por lo que la migracion se registra para poder seguir al usuario desde ellas
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores ya que se filtran anteriormente
+        /// 
+        /// ...
+        fn migrar_cuenta(&mut self, cuenta_actual: AccountId, nueva_cuenta: AccountId)
+        {
+            self.reasignar_cuenta_en_sistema(cuenta_actual, nueva_cuenta);
 
-                candidatos_aprobados: Vec::new(),
-                peticiones_candidatos: Vec::new(),
-                votantes_aprobados: Vec::new(),
-                peticiones_votantes: Vec::new(),
-                votantes_votados: Vec::new(),
+            for eleccion in self.elecciones.iter_mut() {
+                eleccion.migrar_cuenta(cuenta_actual, nueva_cuenta);
+            }
+            for evento in self.eventos_elecciones.iter_mut() {
+                evento.migrar_cuenta(cuenta_actual, nueva_cuenta);
             }
+            for plantilla in self.plantillas_eleccion.iter_mut() {
+                plantilla.padron.iter_mut()
+                    .filter(|cuenta| **cuenta == cuenta_actual)
+                    .for_each(|cuenta| *cuenta = nueva_cuenta);
+            }
+
+            self.migraciones_cuentas.push(MigracionCuenta::new(cuenta_actual, nueva_cuenta, Self::env().block_timestamp()));
         }
 
+        /// DEVUELVE LA CUENTA ACTUAL DE UN USUARIO QUE FIGURA CON LA CUENTA RECIBIDA EN UNA ELECCION CERRADA EN EL TIMESTAMP RECIBIDO
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Se aplican en orden las migraciones posteriores al cierre, las anteriores ya se reflejan en la eleccion porque seguia activa
+        /// 
+        /// ...
+        fn cuenta_migrada_desde(&self, account_id: AccountId, cierre: Timestamp) -> AccountId
+        {
+            self.migraciones_cuentas.iter()
+                .filter(|m| m.timestamp >= cierre)
+                .fold(account_id, |cuenta, m| if m.cuenta_anterior == cuenta { m.nueva_cuenta } else { cuenta })
+        }
 
-        ///DEVUELVE UN ESTADOELECCION EN BASE A UN TIMESTAMP RECIBIDO
-        fn get_estado_eleccion(&self, timestamp: u64) -> EstadoEleccion
+        /// SE QUITA A UN USUARIO DEL SISTEMA Y DE LAS PETICIONES PENDIENTES DE LAS ELECCIONES ACTIVAS
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Se quita al usuario de las listas del sistema y del indice de dni. En las elecciones activas se quitan sus peticiones de votante y candidato,
+        /// junto con su perfil, y las listas pendientes que integra, al igual que sus peticiones en el padron de los eventos. Las aprobaciones y los votantes que ya votaron no se modifican
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores ya que se filtran anteriormente
+        /// 
+        /// ...
+        fn eliminar_usuario_del_sistema(&mut self, usuario_account_id: AccountId)
         {
-            if self.fecha_inicio > timestamp {
-                EstadoEleccion::PeriodoInscripcion
-            } else if self.fecha_cierre <= timestamp {
-                EstadoEleccion::Cerrada
-            } else {
-                EstadoEleccion::PeriodoVotacion
+            self.usuarios_registados.retain(|u| u.account_id != usuario_account_id);
+            self.peticiones_registro.retain(|u| u.account_id != usuario_account_id);
+            self.peticiones_cambio_perfil.retain(|u| u.account_id != usuario_account_id);
+            self.usuarios_suspendidos.retain(|id| *id != usuario_account_id);
+            self.indice_dni.retain(|r| r.account_id != usuario_account_id);
+
+            for eleccion in self.elecciones.iter_mut() {
+                eleccion.quitar_peticiones_de_usuario(usuario_account_id);
+            }
+            for evento in self.eventos_elecciones.iter_mut() {
+                evento.quitar_peticiones_de_usuario(usuario_account_id);
             }
-        }
-        pub fn get_eleccion_votos(&self) -> Vec<CandidatoVotos> {
-            self.votos.clone()
-        }
-        // pub fn get_dimf_votantes_aprobados(&self)-> usize{
-        //     self.votantes_aprobados.len()
-        // }
-        pub fn get_votantes_aprobados(&self) -> Vec<Usuario> {
-            self.votantes_aprobados.clone()
-        }
-        pub fn get_votantes_registrados(&self) -> Vec<Usuario> {
-            self.peticiones_votantes.clone()
-        }
-        pub fn get_id(&self) -> u64 {
-            self.eleccion_id
-        }
-        pub fn get_cargo(&self) -> String {
-            self.cargo.clone()
-        }
-         pub fn set_votantes_registrados(&mut self, usuarios: Vec<Usuario>){
-            self.peticiones_votantes = usuarios;
         }
 
-        pub fn set_votantes_aprobados(&mut self, usuarios: Vec<Usuario>){
-            self.votantes_aprobados = usuarios;
-        }
+        /// EN CASO DE QUE EL ADMIN ID NO ESTA REGISTRADO LO REGISTRA
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId, un nombre y un dni
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion revisa que el accountId recibido este registrado o en peticiones del sistema, si no se encuntra en ninguna lista
+        /// lo registra y valida si esta en la lista de peticiones solo lo valida.
+        /// 
+        /// ...
+        fn corregir_estado_nuevo_admin(&mut self, new_admin_id: AccountId, new_admin_nombre: String, new_admin_dni: String)
+        {
+            if self.get_usuario_registrado_en_sistema(new_admin_id).is_some() { return; }
 
-        pub fn set_votos(&mut self, votos: Vec<CandidatoVotos>){
-            self.votos = votos;
+            if self.get_usuario_en_peticiones_del_sistema(new_admin_id).is_none()
+            {
+                let new_user = Usuario::new(new_admin_id, new_admin_nombre, new_admin_dni, self.sal_dni);
+                self.registrar_en_cola_de_sistema(new_user);
+            }
+
+            self.aprobar_usuario(new_admin_id);
         }
-    }
 
+        //////////////////// ELECCIONES ////////////////////
 
-    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    pub enum EstadoEleccion { PeriodoInscripcion, PeriodoVotacion, Cerrada, Finalizada }
-
-
-    #[derive(Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    pub enum ErrorEleccion
-    {
-        NoExisteEleccion,
-
-        EleccionEnProcesoInscripcion,
-        EleccionEnProcesoVotacion,
-        EleccionCerrada,
-        EleccionFinalizada,
-
-        CandidatoActualmenteAprobado,
-        CandidatoEnPendiente,
-        CandidatoNoAprobado,
-        CandidatoNoExiste,
-
-        VotanteActualmenteAprobado,
-        VotanteEnPendiente,
-        VotanteNoAprobado,
-        VotanteNoExiste,
-    }
-
-    impl ToString for ErrorEleccion {
-        fn to_string(&self) -> String
+        /// CREA UNA ELECCION CON EL METODO DE VOTACION RECIBIDO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el cargo, las fechas, el quorum y el metodo de votacion de la eleccion y retorna un Result<(),ErrorInterfaz>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Si el usuario que invoca la funcion es el admin, y las fechas de inicio no es anterior al dia de la fecha y la de cierre no es anterior a la de inicio
+        /// se valida el incremento a los id de eleccion para evitar desbordes, se crea la nueva eleccion y se agrega a la lista de elecciones actuales.
+        /// 
+        /// #Errores
+        /// 
+        /// Se devuelve un ErrorInterfaz si quien invoca la funcion no es el admin, si las fechas no son validas o si el quorum es un porcentaje mayor a 100
+        /// 
+        /// ...
+        fn crear_eleccion(&mut self, cargo: String, fecha_inicio: Fecha, fecha_cierre: Fecha, quorum: Option<Quorum>, metodo_votacion: MetodoVotacion) -> Result<(), ErrorInterfaz>
         {
-            match self {
-                ErrorEleccion::NoExisteEleccion => "La id de elección ingresada no existe.".to_owned(),
-                ErrorEleccion::EleccionEnProcesoInscripcion => "La elección ingresada se encuentra en período de inscripción.".to_owned(),
-                ErrorEleccion::EleccionEnProcesoVotacion => "La elección ingresada se encuentra en período de votación.".to_owned(),
-                ErrorEleccion::EleccionCerrada => "La elección ingresada se encuentra cerrada.".to_owned(),
-                ErrorEleccion::EleccionFinalizada => "La eleccion ingresada se encuentra finalizada.".to_owned(),
-                ErrorEleccion::CandidatoActualmenteAprobado => "El candidato ingresado ya se encuentra actualmente aprobado.".to_owned(),
-                ErrorEleccion::CandidatoEnPendiente => "El candidato ingresado ya se encuentra en la cola de peticiones para candidato y debe esperar a ser aprobado".to_owned(),
-                ErrorEleccion::CandidatoNoAprobado => "El candidato ingresado está en espera de aprobación.".to_owned(),
-                ErrorEleccion::CandidatoNoExiste => "El candidato ingresado no existe en la elección.".to_owned(),
-                ErrorEleccion::VotanteActualmenteAprobado => "El votante ingresado ya se encuentra actualmente aprobado.".to_owned(),
-                ErrorEleccion::VotanteEnPendiente => "El votante ingresado ya se encuentra en la cola de peticiones para votante y debe esperar a ser aprobado".to_owned(),
-                ErrorEleccion::VotanteNoAprobado => "El votante ingresado no fue aprobado para esta elección, no tendrá permiso para votar.".to_owned(),
-                ErrorEleccion::VotanteNoExiste => "El votante ingresado no existe en la elección.".to_owned(),
+            if let Err(error) = self.validar_permisos(Self::env().caller()) {
+                return Err(ErrorInterfaz::new(error))
             }
-        }
-    }
-
-    //////////////////////////////// VOTOS Y RESULTADOS ////////////////////////////////
-
-    #[derive(Clone, Debug, PartialEq)]
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    pub struct CandidatoVotos {
-        candidato_nombre: String,
-        candidato_dni: String,
-        votos_recaudados: u64,
-    }
 
-    impl CandidatoVotos {
-        pub fn new(candidato_nombre: String, candidato_dni: String) -> Self {
-            CandidatoVotos {
-                candidato_nombre,
-                candidato_dni,
-                votos_recaudados: 0,
+            if let Err(error) = fecha_inicio.validar_fecha() {
+                return Err(ErrorInterfaz::new(ErrorSistema::FechaInicioInvalida(error)));
             }
-        }
-        pub fn get_votos_recaudados(&self) -> u64 {
-            self.votos_recaudados
-        }
-        pub fn set_votos_recaudados(&mut self, cantidad: u64){
-            self.votos_recaudados = cantidad;
-        }
-    }
 
-    //////////////////////////////// USUARIOS ////////////////////////////////
-    
-
-    #[derive(Clone, Debug)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    pub enum Rol { Votante, Candidato }
+            if let Err(error) = fecha_cierre.validar_fecha() {
+                return Err(ErrorInterfaz::new(ErrorSistema::FechaCierreInvalida(error)));
+            }
 
+            if fecha_cierre.fecha_pasada(fecha_inicio.to_timestamp()) {
+                return Err(ErrorInterfaz::new(ErrorSistema::FechaCierreAntesInicio));
+            }
 
-    #[derive(Clone, Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    pub struct Usuario
-    {
-        account_id: AccountId,
-        nombre: String,
-        dni: String,
-    }
+            if fecha_inicio.fecha_pasada(Self::env().block_timestamp()) {
+                return Err(ErrorInterfaz::new(ErrorSistema::FechaInicioPasada));
+            }
 
-    impl Usuario {
-        pub fn new(account_id: AccountId, nombre: String, dni: String) -> Self {
-            Usuario {
-                account_id,
-                nombre,
-                dni,
+            if fecha_cierre.fecha_pasada(Self::env().block_timestamp()) {
+                return Err(ErrorInterfaz::new(ErrorSistema::FechaCierrePasada));
             }
-        }
-    }
 
-    ////////////////////////////// Fecha /////////////////////////////
+            if let Some(Quorum::Porcentaje(porcentaje)) = quorum {
+                if porcentaje > 100 {
+                    return Err(ErrorInterfaz::new(ErrorSistema::QuorumInvalido));
+                }
+            }
+            
+            let mut eleccion = Eleccion::new(
+                self.elecciones_conteo_id,
+                cargo,
+                fecha_inicio.to_timestamp(),
+                fecha_cierre.to_timestamp(),
+                fecha_inicio,
+                fecha_cierre,
+            );
+            eleccion.quorum = quorum;
+            eleccion.metodo_votacion = metodo_votacion;
 
-    #[derive(Clone, Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    pub struct Fecha { dia: u8, mes: u8, año: u32, hora: u8, min: u8, seg: u8 } // Año como unsigned debido a que sí o sí se tratarán fechas mayores a la actual
+            if let Err(error) = self.check_add_elecciones_id() {
+                return Err(ErrorInterfaz::new(error))
+            }
 
+            self.elecciones.push(eleccion);
 
-    impl Fecha 
-    {
-        pub fn new(dia: u8, mes: u8, año: u32, hora: u8, min: u8, seg: u8) -> Self
-        { 
-            Fecha { dia, mes, año, hora, min, seg }
+            Ok(())
         }
-        ///VALIDA QUE LA FECHA INGRESADA EN NUMEROS SEA UNA FECHA CORRECTA Y EXISTENTE, TENIENDO EN CUENTA AÑOS BISIESTOS
-        ///
-        ///#Uso
-        ///Llamar a esta funcion sobre una fecha devuelve un Result con un Ok vacio, en caso de ser valida, o un ErrorFecha en caso de no serlo.
-        ///
-        ///#Funcionalidad
-        ///Chequea que el mes este dentro del rango 1-12, que el dia este dentro de su rango dependiendo del mes actual y si el año es bisiesto o no.
-        ///Tambien chequea que la hora, los minutos, y los segundos esten dentro de sus correspondientes rangos.
-        ///
-        ///#Errores
-        ///Devuelve variantes de ErrorFecha, dependiendo del primer error encontrado. Esto quiere decir que si hay mas de un error en una fecha ingresada,
-        ///devolvera solo el primer error. Si se valida nuevamente la fecha con el error solucionado, el siguiente error se devolvera, si es que queda alguno.
-        ///Los nombres de los errores siguen el patron Dia/Mes/Hora/Min/Seg Invalido, dependiendo de cual es el campo invalido.
-        ///El año no puede ser invalido, por lo que no hay error que lo represente.
-        fn validar_fecha(&self) -> Result<(), ErrorFecha>
+
+        /// DEVUELVE LOS VOTANTES APROBADOS EN LA ULTIMA ELECCION FINALIZADA CREADA A PARTIR DE UNA PLANTILLA
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Los votantes se devuelven con su cuenta actual, siguiendo las migraciones de cuenta posteriores al cierre de la eleccion
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores, si no hay elecciones finalizadas de la plantilla devuelve un Vec vacio
+        /// 
+        /// ...
+        fn get_votantes_ultima_eleccion_de_plantilla(&self, plantilla_id: u64) -> Vec<AccountId>
         {
-            if self.dia == 0 {
-                return Err(ErrorFecha::DiaInvalido);
+            match self.elecciones_finiquitadas.iter().rev().find(|e| e.plantilla_id == Some(plantilla_id)) {
+                Some(eleccion) => eleccion.votantes_aprobados.iter().map(|v| self.cuenta_migrada_desde(v.account_id, eleccion.fecha_cierre)).collect(),
+                None => Vec::new(),
             }
+        }
 
-            if !match self.mes {
-                1 | 3 | 5 | 7 | 8 | 10 | 12 => self.dia <= 31,
-                4 | 6 | 9 | 11 => self.dia <= 30,
-                2 => self.dia <= if self.es_bisiesto() { 29 } else { 28 },
-                _ => { return Err(ErrorFecha::MesInvalido); }
-            } {
-                return Err(ErrorFecha::DiaInvalido);
+        /// MARCA LA ULTIMA ELECCION CREADA COMO INSTANCIA DE UNA PLANTILLA Y APRUEBA COMO VOTANTES A LOS USUARIOS DEL PADRON
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Se agregan una sola vez los usuarios del padron que siguen aprobados en el sistema y no estan suspendidos, se ignoran el admin
+        /// y las cuentas que ya no pertenecen a ningun usuario. El padron se recibe con las cuentas actuales, ya que las migraciones
+        /// actualizan el padron de las plantillas y los votantes arrastrados se traducen al obtenerlos
+        /// 
+        /// ...
+        fn aprobar_padron_en_ultima_eleccion(&mut self, plantilla_id: u64, padron: Vec<AccountId>)
+        {
+            let votantes: Vec<Usuario> = self.usuarios_registados.iter()
+                .filter(|u| u.account_id != self.admin_id && !self.usuarios_suspendidos.contains(&u.account_id))
+                .filter(|u| padron.contains(&u.account_id))
+                .cloned()
+                .collect();
+
+            if let Some(eleccion) = self.elecciones.last_mut() {
+                eleccion.plantilla_id = Some(plantilla_id);
+                eleccion.votantes_aprobados = votantes;
             }
+        }
 
-            if self.hora > 23 {
-                return Err(ErrorFecha::HoraInvalida);
+        /// AGREGA A UN USUARIO A LA COLA DE ESPERA PARA SER VOTANTE O CANDIDATO EN UNA ELECCION
+        ///
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un Usuario, un Rol y usize, retorna un Result<(),ErrorSistema>
+        /// 
+        ///#Funcionalidad 
+        /// 
+        /// La funcion valida que el usuario no exista en la eleccion recibida y lo agrega a la lista de espera adecuada dependiendo de rol recibido
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si el usuario ya esta registrado y aprobado en esta eleccion ya sea como votante, como candidato o como miembro de una lista,
+        /// o si se postula como candidato en una eleccion por listas
+        /// 
+        /// ...
+        fn registrar_peticion_eleccion(&mut self, user: Usuario, rol: Rol, eleccion_index: usize) -> Result<(), ErrorSistema>
+        {
+            self.validar_inexistencia_de_usuario_en_eleccion(user.account_id, eleccion_index)?;
+
+            if let Rol::Candidato = rol {
+                self.validar_metodo_votacion(MetodoVotacion::Candidatos, eleccion_index)?;
             }
 
-            if self.min > 59 {
-                return Err(ErrorFecha::MinInvalido);
+            if let Rol::Votante = rol {
+                self.validar_eleccion_fuera_de_evento(self.elecciones[eleccion_index].eleccion_id)?;
             }
 
-            if self.seg > 59 {
-                return Err(ErrorFecha::SegInvalido);
+            match rol {
+                Rol::Votante => self.elecciones[eleccion_index]
+                    .peticiones_votantes
+                    .push(user),
+                Rol::Candidato => self.elecciones[eleccion_index]
+                    .peticiones_candidatos
+                    .push(user),
             }
 
             Ok(())
         }
 
+        ///CREA UNA LISTA DE LAS ELECCIONES EN CURSO DE LA FORMA QUE SE LEE EN LA INTERFAZ
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un time stamp y retorna un Vec<EleccionInterfaz>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion crea un Vec<EleccionInterfaz> usando el la lista de elecciones actuales del sistema
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores
+        /// 
+        /// ...
+        fn clonar_elecciones_actuales_a_interfaz(&self, timestamp: u64) -> Vec<EleccionInterfaz>
+        {
+            let mut vec: Vec<EleccionInterfaz> = Vec::new();
+
+            for i in 0..self.elecciones.len() {
+                vec.push(EleccionInterfaz::from_eleccion(
+                    self.elecciones[i].get_estado_eleccion(timestamp),
+                    self.elecciones[i].clone(),
+                    false
+                ));
+            }
 
-        ///DEVUELVE SI EL AÑO DE LA FECHA ES BISIESTO
-        ///
-        ///#Uso
-        ///Llamar a esta funcion sobre una fecha te devuelve un bool, donde true significa que el año si es bisiesto, y false que no
-        ///
-        ///#Funcionalidad
-        ///Para saber si un año es bisiesto, se ve si es divisible por 4 pero a si mismo no divisible por 100,
-        ///o si es divisible por 100 y por 400
-        fn es_bisiesto(&self) -> bool {
-            (self.año % 4 == 0 && self.año % 100 != 0) || (self.año % 100 == 0 && self.año % 400 == 0)
+            vec
         }
 
-
-        ///CONVIERTE LA FECHA A UNIX EPOCH TIMESTAMP EN MILISEGUNDOS
-        ///
-        ///#Uso
-        ///Llamar a esta funcion sobre una fecha devuelve un u64 que es la representacion de la fecha en Unix Epoch Timestamp.
-        ///Este formato guarda una fecha en forma de los milisegundos pasados desde el primero de enero de 1970 a las 00:00:00 UTC.
-        ///
-        ///#Funcionalidad
-        ///El algoritmo usado fue traducido de uno de los algoritmos presentados en este articulo:
-        ///https://blog.reverberate.org/2020/05/12/optimizing-date-algorithms.html
-        ///
-        ///Todos los datos de la fecha son pasados a u64 previamente a hacer las operaciones para simplificar el codigo siguiente
-        ///y evitar multiples conversiones en el medio.
-        ///
-        ///Se usan las funciones saturating para sumar, restar, dividir, y multiplicar. Estas funciones realizan las operaciones normales,
-        ///pero impiden el overflow y el underflow, simplemente limitando los valores al rango valido para u64.
-        ///Overflow y underflow solo deberia suceder en casos extremos (años tan altos que la tierra ni existiria)
-        ///, o hasta en algunas operaciones es imposible si la fecha fue validada.
-        ///Estas funciones fueron usadas para complacer al linter, que mira hasta los casos extremos.
-        ///Los casos donde no se obtiene el timestamp correcto, son los años anteriores a 1970, ya que eso seria un timestamp
-        ///negativo. En este caso, por las funciones que previenen el overflow y el underflow, todos los años anteriores a 1970 dan un
-        ///timestamp de 0, que es suficiente para este sistema.
-        ///En caso de necesitar años anteriores a 1970, el algoritmo esta capacitado, y solo se necesitaria cambiar los tipos de u64 a i64.
-        ///
-        ///El algoritmo en operaciones normales seria el siguiente:
-        ///
-        ///let año_ajustado = año + 4800;
-        ///let febreros = año_ajustado - if mes <= 2 { 1 } else { 0 };
-        ///let dias_intercalar = 1 + (febreros / 4) - (febreros / 100) + (febreros / 400);
-        ///let dias = 365 * año_ajustado + dias_intercalar + tabla[(mes - 1) as usize] + dia - 1;
-        ///((dias - 2472692) * 86400 + hora * 3600 + min * 60 + seg) * 1000
-        ///
-        ///Este algoritmo calcula la cantidad de febreros y en la variable de dias_intercalar se calculan 
-        ///los febreros que tienen dias extras al estar en años bisiesto. Todos estos dias se suman a los dias
-        ///normales que suceden en cada año pasado (años * 365), a el dia actual guardado en la fecha, y a la cantidad de dias
-        ///que van transcurriendo en el año, segun el mes, usando la tabla ya que estos valores se saben.
-        ///Luego de todos estos calculos, se devuelve los dias, horas, minutos, y segundos, todos pasados a segundos y luego pasados
-        ///a milisegundos.
-        fn to_timestamp(&self) -> u64
+        ///CREA UNA LISTA DE LAS ELECCIONES TERMINADAS DE LA FORMA QUE SE LEE ENE LA INTERFAZ
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un time stamp y retorna un Vec<EleccionInterfaz>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion crea un Vec<EleccionInterfaz> usando el la lista de elecciones pasadas del sistema
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores
+        /// 
+        /// ...
+        fn clonar_elecciones_historicas_a_interfaz(&self) -> Vec<EleccionInterfaz>
         {
-            let dia: u64 = self.dia.into();
-            let mes: u64 = self.mes.into();
-            let año: u64 = self.año.into();
-            let seg: u64 = self.seg.into();
-            let min: u64 = self.min.into();
-            let hora: u64 = self.hora.into();
+            let mut vec: Vec<EleccionInterfaz> = Vec::new();
 
-            let tabla = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+            for i in 0..self.elecciones_finiquitadas.len() {
+                let estado = match self.elecciones_finiquitadas[i].quorum_alcanzado {
+                    true => EstadoEleccion::Finalizada,
+                    false => EstadoEleccion::Invalidada,
+                };
+                vec.push(EleccionInterfaz::from_eleccion(
+                    estado,
+                    self.elecciones_finiquitadas[i].clone(),
+                    true
+                ));
+            }
 
-            let año_ajustado = año.saturating_add(4800);
-            let febreros = año_ajustado.saturating_sub(if mes <= 2 { 1 } else { 0 });
-            let dias_intercalar = 1_u64.saturating_add(febreros.saturating_div(4)).saturating_sub(febreros.saturating_div(100)).saturating_add(febreros.saturating_div(400));
-            let dias = año_ajustado.saturating_mul(365).saturating_add(dias_intercalar).saturating_add(tabla[(mes.saturating_sub(1)) as usize]).saturating_add(dia).saturating_sub(1);
-            dias.saturating_sub(2472692).saturating_mul(86400).saturating_add(hora.saturating_mul(3600)).saturating_add(min.saturating_mul(60)).saturating_add(seg).saturating_mul(1000)
+            vec
         }
 
+        ///APRUEBA UN CANDIDATO PARA PARTICIPAR EN UNA ELECCION
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe dos usize 
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion usa los usize proporcionados para buscar un candidato en la lista de candidatos pendientes y una eleccion en la lista de elecciones actuales,
+        /// lo elimina de esa lista y lo agrega a la lista de candidatos aprobados en la eleccion, tambien lo crea en la lista de votosxcandidato para que se lo pueda 
+        /// votar 
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores
+        /// 
+        /// ...
+        fn aprobar_candidato(&mut self, candidato_index: usize, eleccion_index: usize) 
+        {
+            let e = &mut self.elecciones[eleccion_index];
+
+            let candidato = e.peticiones_candidatos.remove(candidato_index);
+            e.candidatos_aprobados.push(candidato.clone());
 
-        ///DEVUELVE SI LA FECHA ES ANTERIOR A LA PASADA POR PARAMETRO
-        ///
-        ///#Uso
-        ///Llamar a esta funcion sobre una fecha, pasando un timestamp, devuelve un bool, el cual señaliza si la fecha es anterior
-        ///o igual a la fecha representada por el timestamp pasado. Esto muestra si es una fecha que ya paso cierta otra fecha.
-        ///
-        ///#Funcionalidad
-        ///Se compara el timestamp de la fecha (en milisegundos) con otro timestamp, para ver si el primero es menor o igual al segundo.
-        ///Los timestamps estan en formato Unix Epoch Timestamp. Este formato guarda una fecha en forma de los milisegundos pasados desde el 
-        ///primero de enero de 1970 a las 00:00:00 UTC.
-        fn fecha_pasada(&self, timestamp: u64) -> bool {
-            self.to_timestamp() <= timestamp
+            let candidato_votos = CandidatoVotos::new(candidato.nombre, candidato.dni_visible);
+            e.votos.push(candidato_votos);
         }
-    }
 
+        ///QUITA LA PETICION PENDIENTE DE UN USUARIO EN UNA ELECCION Y REGISTRA EL RETIRO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId, un usize y el Rol de la peticion a quitar
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Se quita al usuario de la cola de pendientes correspondiente a su rol, si era candidato tambien se descarta su perfil
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores ya que se filtran anteriormente
+        /// 
+        /// ...
+        fn retirar_peticion_eleccion(&mut self, usuario_account_id: AccountId, eleccion_index: usize, rol: Rol)
+        {
+            let timestamp = Self::env().block_timestamp();
+            let e = &mut self.elecciones[eleccion_index];
 
-    #[derive(PartialEq, Debug)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    pub enum ErrorFecha
-    {
-        DiaInvalido,
-        MesInvalido,
-        HoraInvalida,
-        MinInvalido,
-        SegInvalido,
-    }
+            let peticiones = match rol {
+                Rol::Votante => &mut e.peticiones_votantes,
+                Rol::Candidato => &mut e.peticiones_candidatos,
+            };
+            let index = peticiones.iter().position(|p| p.account_id == usuario_account_id).unwrap(); // Unwrap porque ya sé que existe en el vec
+            let user = peticiones.remove(index);
 
-    impl ToString for ErrorFecha {
-        fn to_string(&self) -> String {
-            match self {
-                ErrorFecha::DiaInvalido => "El día ingresado es invalido.".to_owned(),
-                ErrorFecha::MesInvalido => "El mes ingresado es invalido.".to_owned(),
-                ErrorFecha::HoraInvalida => "La hora ingresada es incorrecta.".to_owned(),
-                ErrorFecha::MinInvalido => "El minuto ingresado es incorrecto.".to_owned(),
-                ErrorFecha::SegInvalido => "El segundo ingresado es incorrecto.".to_owned()
+            if rol == Rol::Candidato {
+                e.perfiles_candidatos.retain(|p| p.account_id != usuario_account_id);
+            }
+
+            let eleccion_id = e.eleccion_id;
+            self.historial_retiros.push(RetiroPeticion::new(user, TipoPeticion::Eleccion(eleccion_id, rol), timestamp));
+        }
+
+        ///DA DE BAJA A UN CANDIDATO APROBADO DE UNA ELECCION
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe dos usize y el EstadoCandidato con el que se da de baja al candidato
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// El candidato se mantiene en la lista de candidatos aprobados, para no perder la relacion con sus votos, pero su estado en la lista de votos
+        /// deja de ser activo y se registra la baja en la eleccion
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores
+        /// 
+        /// ...
+        fn dar_de_baja_candidato(&mut self, candidato_index: usize, eleccion_index: usize, estado: EstadoCandidato)
+        {
+            let e = &mut self.elecciones[eleccion_index];
+
+            e.votos[candidato_index].estado = estado.clone();
+            let candidato = e.candidatos_aprobados[candidato_index].clone();
+            e.bajas_candidatos.push(BajaCandidato::new(candidato, estado));
+        }
+
+        ///APRUEBA UN VOTANTE PARA PARTICIPAR EN UNA ELECCION
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe dos usize 
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion usa los usize proporcionados para buscar un votante en la lista de votantes pendientes y una eleccion en la lista de elecciones actuales,
+        /// lo elimina de esa lista y lo agrega a la lista de votantes aprobados en la eleccion. 
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores
+        /// 
+        /// ...
+        fn aprobar_votante(&mut self, votante_index: usize, eleccion_index: usize) 
+        {
+            let e = &mut self.elecciones[eleccion_index];
+
+            let votante = e.peticiones_votantes.remove(votante_index);
+            e.votantes_aprobados.push(votante.clone());
+        }
+
+        
+        /// SE REGISTRA UN VOTO DE UN VOTANTE VALIDO A UN CANDIDATO VALIDO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe dos usize, retorna un Result<(),ErrorSistema>  
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion le permite a un votante valido votar por un candidato valido, sumandole un voto y el peso del votante en la eleccion,
+        /// luego de emitir el voto el votante es agregado a la lista de votantes que ya votaron de la eleccion
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan cuando el votante no es parte de los votantes habilitados a la eleccion o si ya se alcanzo el numero de votos maximos para un candidato
+        /// 
+        /// ...
+        fn registrar_voto_a_candidato(&mut self, candidato_index: usize, eleccion_index: usize) -> Result<(), ErrorSistema>
+        {
+            let peso = self.elecciones[eleccion_index].get_peso_votante(Self::env().caller());
+
+            if let Some(num) = self.elecciones[eleccion_index].votos[candidato_index].votos_recaudados.checked_add(1) { 
+                self.elecciones[eleccion_index].votos[candidato_index].votos_recaudados = num;
+            } else {
+                return Err(ErrorSistema::RepresentacionLimiteAlcanzada) 
+            }
+
+            if let Some(num) = self.elecciones[eleccion_index].votos[candidato_index].votos_ponderados.checked_add(peso) { 
+                self.elecciones[eleccion_index].votos[candidato_index].votos_ponderados = num;
+            } else {
+                return Err(ErrorSistema::RepresentacionLimiteAlcanzada) 
+            }
+
+            self.registrar_votante_como_votado(eleccion_index)
+        }
+
+        /// SE REGISTRA UN VOTO DE UN VOTANTE VALIDO A UNA LISTA APROBADA
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe dos usize, retorna un Result<(),ErrorSistema>  
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion le suma a la lista un voto y el peso del votante en la eleccion, luego el votante es agregado a la lista de votantes que ya votaron
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error son los mismos que los de registrar_voto_a_candidato
+        /// 
+        /// ...
+        fn registrar_voto_a_lista(&mut self, lista_index: usize, eleccion_index: usize) -> Result<(), ErrorSistema>
+        {
+            let peso = self.elecciones[eleccion_index].get_peso_votante(Self::env().caller());
+            let lista_votos = &mut self.elecciones[eleccion_index].votos_listas[lista_index];
+
+            lista_votos.votos_recaudados = lista_votos.votos_recaudados.checked_add(1).ok_or(ErrorSistema::RepresentacionLimiteAlcanzada)?;
+            lista_votos.votos_ponderados = lista_votos.votos_ponderados.checked_add(peso).ok_or(ErrorSistema::RepresentacionLimiteAlcanzada)?;
+
+            self.registrar_votante_como_votado(eleccion_index)
+        }
+
+        /// SE AGREGA A QUIEN INVOCA LA FUNCION A LA LISTA DE VOTANTES QUE YA VOTARON EN LA ELECCION
+        /// 
+        /// #Errores
+        /// 
+        /// El caso de error se da cuando quien invoca la funcion no es un votante aprobado en la eleccion
+        /// 
+        /// ...
+        fn registrar_votante_como_votado(&mut self, eleccion_index: usize) -> Result<(), ErrorSistema>
+        {
+            let (aprobados, _) = self.padron_de_eleccion(eleccion_index);
+            if let Some(votante) = aprobados.iter().find(|v| v.account_id == Self::env().caller()).cloned() {
+                self.elecciones[eleccion_index].votantes_votados.push(votante);
+                self.elecciones[eleccion_index].marcas_tiempo_votos.push(Self::env().block_timestamp());
+                self.votos_emitidos_totales = self.votos_emitidos_totales.saturating_add(1);
+            } else {
+                return Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoExiste))
             }
+
+            Ok(())
+        }
+
+        ///SUMA LA PARTICIPACION DE UNA ELECCION QUE SE ESTA FINALIZANDO AL ACUMULADO DEL SISTEMA
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe la eleccion que se esta finalizando
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Se calcula el porcentaje de votantes aprobados que votaron y se suma al acumulado, que junto con la cantidad de elecciones
+        /// finalizadas permite calcular la participacion promedio sin recorrerlas. Una eleccion sin votantes aprobados suma 0
+        /// 
+        /// #Errores
+        /// 
+        /// La funcion no maneja errores
+        /// 
+        /// ...
+        fn acumular_participacion(&mut self, eleccion: &Eleccion)
+        {
+            let aprobados = eleccion.votantes_aprobados.len() as u128;
+            let votados = eleccion.votantes_votados.len() as u128;
+            let participacion = votados.saturating_mul(100).checked_div(aprobados).unwrap_or(0);
+            self.suma_participacion_finiquitadas = self.suma_participacion_finiquitadas.saturating_add(participacion);
+        }
+
+        ///APRUEBA UNA LISTA PARA PARTICIPAR EN UNA ELECCION
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe dos usize 
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion mueve la lista de la cola de listas pendientes a las listas aprobadas de la eleccion y la crea en la lista de votos por lista
+        /// para que se la pueda votar
+        /// 
+        /// ...
+        fn aprobar_lista(&mut self, lista_index: usize, eleccion_index: usize)
+        {
+            let e = &mut self.elecciones[eleccion_index];
+
+            let lista = e.peticiones_listas.remove(lista_index);
+            e.votos_listas.push(ListaVotos::new(lista.nombre.clone()));
+            e.listas_aprobadas.push(lista);
+        }
+    
+
+
+
+        //////////////////////////////////////// VALIDACIONES ////////////////////////////////////////
+
+        //////////////////// SISTEMA ////////////////////
+
+        ///CONFIRMA LOS PERMISOS DEL ADMIN
+        /// 
+        /// #Uso
+        /// La funcion es de uso interno del sistema, recibe un AccountId y un String y retorna un Result<(),ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion valida que el AccountId recibido sea el admin del sistema
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si el AccountId recibido no es el admin, en  ese caso se devuelve el string recibido dentro del ErrorSistema
+        /// 
+        /// ...
+        fn validar_permisos(&self, caller_id: AccountId) -> Result<(), ErrorSistema> {
+            if !self.es_admin(caller_id) { return Err( ErrorSistema::NoSePoseenPermisos); }
+            Ok(())
+        }
+
+        ///INFORMA SI EL ID PROPORCIONADO ES EL ADMIN
+        fn es_admin(&self, caller_id: AccountId) -> bool { caller_id == self.admin_id }
+
+        /// VALIDA SI EL USUARIO ES EL ADMIN O ESTA APROBADO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Result<(),ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion valida que el AccountId recibido sea de el admin o de un usuario aprobado en el sistema
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si el AccountId recibido es de un usuario que auno no fue aprobado en el sistema o
+        /// de un usuario que aun no se registro en el sistema
+        /// 
+        /// ...
+        fn validar_caller_como_admin_o_usuario_aprobado(&self, caller_id: AccountId) -> Result<(), ErrorSistema>
+        {
+            if self.es_admin(caller_id) { return Ok(()) }
+
+            match self.validar_usuario(caller_id) {
+                Ok(_) => Ok(()),
+                Err(e) => Err(e),
+            }
+        }
+
+        /// VALIDA QUE EL USUARIO PUEDA CONSULTAR EL HISTORIAL DE PARTICIPACION SOLICITADO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el AccountId de quien consulta y el del usuario consultado y retorna un Result<(),ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion permite que cada usuario consulte su propio historial, y que el admin y los auditores consulten el de cualquier usuario
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si se consulta el historial de otro usuario sin ser el admin ni un auditor
+        /// 
+        /// ...
+        fn validar_permisos_historial(&self, caller_id: AccountId, usuario_id: AccountId) -> Result<(), ErrorSistema>
+        {
+            if caller_id == usuario_id || self.es_admin(caller_id) || self.auditores.contains(&caller_id) { return Ok(()) }
+            Err(ErrorSistema::NoSePoseenPermisos)
+        }
+
+        /// VALIDA QUE EL USUARIO ESTE APROBADO EN EL SISTEMA, DE SER EL CASO LO DEVUELVE
+        /// 
+        /// #Uso 
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y un string y retorna un Result<(),ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion valida que el AccountId recibido sea el de un usuario aprobado en el sistema
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si el AccountId proporcionado pertence a el adminm a un usuario no aprobado en el sistema o 
+        /// a un usuario no registrado en el sistema
+        /// 
+        /// ...
+        fn validar_caller_como_usuario_aprobado(&self, caller_id: AccountId) -> Result<Usuario, ErrorSistema>
+        {
+            if self.es_admin(caller_id) { return Err( ErrorSistema::AccionUnicaDeUsuarios); }
+
+            self.validar_usuario(caller_id)
+        }
+
+        /// VALIDA QUE UN USUARIO ESTE REGISTRADO EN EL SISTEMA
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Result<Usuario,ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion usa el AccountId recibido para buscar la posicion de un Usuario en el sistema y devuelve una copia de ese usuario
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error de esta funcion son los mismo que los de la fn validar_usuario_en_sistema, ademas de cuando el usuario esta suspendido
+        fn validar_usuario(&self, caller_id: AccountId) -> Result<Usuario, ErrorSistema>
+        {
+            let index = self.validar_usuario_en_sistema(caller_id)?;
+
+            if self.usuarios_suspendidos.contains(&caller_id) { return Err(ErrorSistema::UsuarioSuspendido); }
+
+            Ok(self.usuarios_registados[index].clone())
+        }
+
+        ///CONFIRMA LA NO EXISTENCIA DE UN USUARIO EN EL SISTEMA
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Result<(),ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion revisa en todas las lista del sistema para asegurarse de que el AccountId proporcionado no exista en ninguna
+        /// 
+        /// #Erroes
+        /// 
+        /// Los casos de error se dan cuando el AccountId se encuentra en alguna de las lista
+        /// 
+        /// ...
+        fn consultar_inexistencia_usuario_en_sistema(&self, caller_id: AccountId) -> Result<(), ErrorSistema>
+        {
+            if self.es_admin(caller_id) { return Err( ErrorSistema::AdminYaRegistrado); }
+
+            if self.existe_usuario_en_peticiones_del_sistema(caller_id) { return Err( ErrorSistema::UsuarioYaRegistradoEnPeticiones); }
+            if self.existe_usuario_registrado_en_sistema(caller_id) { return Err( ErrorSistema::UsuarioYaRegistrado); }
+
+            Ok(())
+        }
+
+        ///BUSCA UN USUARIO EN LA COLA DE PETICIONES DE REGISTRO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Result<(),ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion checkea que un AccountId este dentro de la lista de peticiones de registro del sistema
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan cuando el AccountId recibido ya forma parte de la lista de usuarios aprobados en el sistema o 
+        /// cuando el usuario no existe en el sistema
+        /// 
+        /// ...
+        fn consultar_peticion_sistema(&self, user_id: AccountId) -> Result<(), ErrorSistema>
+        {
+            if self.existe_usuario_en_peticiones_del_sistema(user_id) { return Ok(()) }
+
+            match self.existe_usuario_registrado_en_sistema(user_id) { 
+                true  => Err( ErrorSistema::UsuarioYaRegistrado),
+                false => Err( ErrorSistema::NoExisteUsuario)
+            }
+        }
+
+        ///INFORMA SI UN DETERMINADO USUARIO EXISTE EN LA COLA DE PETICIONES DE REGISTRO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un bool
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion retorna true cuando un usuario esta en la lista de peticiones de registro del sistema y false cuando no esta
+        /// 
+        /// ...
+        fn existe_usuario_en_peticiones_del_sistema(&self, caller_id: AccountId) -> bool {
+            self.peticiones_registro
+                .iter()
+                .any(|u| u.account_id == caller_id)
+        }
+
+        ///INFORMA SI UN DETERMINADO USUARIO EXISTE EN LA COLA DE USUARIOS REGISTRADOS EN EL SISTEMA
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un bool
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion retorna true cuando un usuario esta en la lista de usuarios registrados del sistema y false cuando no esta
+        fn existe_usuario_registrado_en_sistema(&self, caller_id: AccountId) -> bool {
+            self.usuarios_registados
+                .iter()
+                .any(|u| u.account_id == caller_id)
+        }
+
+        /// SE BUSCA Y RETORNA LA POSICION EN LA COLA DE UN USUARIO REGISTRADO ESPECIFICO EN CASO DE EXISTIR
+        /// 
+        /// #Use
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Option<usize>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion busca la posicion del AccountId recibido en la lista de usuarios registrados en el sistema y si el accountId existe retorna su posicion
+        /// en caso contrario retorna None
+        /// 
+        /// ...
+        fn get_usuario_registrado_en_sistema(&self, user_id: AccountId) -> Option<usize>
+        {
+            for i in 0 .. self.usuarios_registados.len() {
+                if self.usuarios_registados[i].account_id == user_id { return Some(i); }
+            }
+
+            None
+        }
+
+        /// SE BUSCA Y RETORNA LA POSICION EN LA COLA DE UN USUARIO NO REGISTRADO ESPECIFICO EN CASO DE EXISTIR
+        /// 
+        /// #Use
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Option<usize>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion busca la posicion del AccountId recibido en la lista de usuarios pendientes en el sistema y si el accountId existe retorna su posicion
+        /// en caso contrario retorna None
+        /// 
+        /// ...
+        fn get_usuario_en_peticiones_del_sistema(&self, user_id: AccountId) -> Option<usize>
+        {
+            for i in 0 .. self.peticiones_registro.len() {
+                if self.peticiones_registro[i].account_id == user_id { return Some(i); }
+            }
+
+            None
+        }
+
+        ///VALIDA QUE LA FIRMA RECIBIDA SEA LA DE UN ATESTADOR REGISTRADO SOBRE LOS DATOS DEL USUARIO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el Usuario a registrar y la firma ECDSA, retorna un Result<(),ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Se calcula el hash Blake2x256 de (account_id, dni, nombre) codificado en SCALE y se recupera la clave publica que lo firmo
+        /// 
+        /// #Errores
+        /// 
+        /// El caso de error se da cuando no se puede recuperar una clave publica de la firma o cuando la clave recuperada no es la de un atestador registrado
+        /// 
+        /// ...
+        fn validar_atestacion(&self, account_id: AccountId, dni: &str, nombre: &str, firma: &[u8; 65]) -> Result<(), ErrorSistema>
+        {
+            let mut mensaje_hash = <ink::env::hash::Blake2x256 as ink::env::hash::HashOutput>::Type::default();
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&(account_id, dni, nombre), &mut mensaje_hash);
+
+            let mut clave_publica = [0u8; 33];
+            if ink::env::ecdsa_recover(firma, &mensaje_hash, &mut clave_publica).is_err() {
+                return Err(ErrorSistema::AtestacionInvalida)
+            }
+
+            match self.atestadores.contains(&clave_publica) {
+                true  => Ok(()),
+                false => Err(ErrorSistema::AtestacionInvalida)
+            }
+        }
+
+        ///VALIDA QUE UN DNI NO PERTENEZCA A UNA CUENTA DISTINTA DE LA RECIBIDA
+        /// 
+        /// Se compara el hash del dni, ya que el indice no guarda los dni en texto plano
+        /// 
+        /// #Errores
+        /// 
+        /// El caso de error se da cuando el dni ya esta asociado a otra cuenta, aprobada o en peticiones
+        /// 
+        /// ...
+        fn validar_dni_disponible(&self, dni_hash: Hash, account_id: AccountId) -> Result<(), ErrorSistema>
+        {
+            match self.indice_dni.iter().any(|r| r.dni_hash == dni_hash && r.account_id != account_id) {
+                true  => Err(ErrorSistema::DniYaRegistrado),
+                false => Ok(())
+            }
+        }
+
+        ///VALIDA QUE UN DNI PUEDA REASIGNARSE Y DEVUELVE LA CUENTA A LA QUE PERTENECE ACTUALMENTE
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan cuando el dni no pertenece a ninguna cuenta o cuando pertenece al admin, que debe delegar su rol en su lugar
+        /// 
+        /// ...
+        fn validar_dni_reasignable(&self, dni_hash: Hash) -> Result<AccountId, ErrorSistema>
+        {
+            let cuenta = match self.indice_dni.iter().find(|r| r.dni_hash == dni_hash) {
+                Some(registro) => registro.account_id,
+                None => return Err(ErrorSistema::NoExisteUsuario)
+            };
+
+            self.validar_cuenta_de_usuario(cuenta)?;
+
+            Ok(cuenta)
+        }
+
+        ///VALIDA QUE UNA CUENTA PERTENEZCA A UN USUARIO DEL SISTEMA, APROBADO O EN PETICIONES, QUE NO SEA EL ADMIN
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan cuando la cuenta es la del admin, que debe delegar su rol en su lugar, o cuando no existe en el sistema
+        /// 
+        /// ...
+        fn validar_cuenta_de_usuario(&self, cuenta: AccountId) -> Result<(), ErrorSistema>
+        {
+            if self.es_admin(cuenta) { return Err(ErrorSistema::AccionUnicaDeUsuarios); }
+
+            match self.existe_usuario_registrado_en_sistema(cuenta) || self.existe_usuario_en_peticiones_del_sistema(cuenta) {
+                true  => Ok(()),
+                false => Err(ErrorSistema::NoExisteUsuario)
+            }
+        }
+
+        ///VALIDA QUE UN USUARIO TENGA UN CAMBIO DE PERFIL PENDIENTE Y DEVUELVE SU POSICION EN LA COLA
+        /// 
+        /// #Errores
+        /// 
+        /// El caso de error se da cuando el AccountId no tiene ningun cambio de perfil en la cola de revision
+        /// 
+        /// ...
+        fn validar_cambio_perfil_pendiente(&self, usuario_account_id: AccountId) -> Result<usize, ErrorSistema>
+        {
+            self.peticiones_cambio_perfil
+                .iter()
+                .position(|u| u.account_id == usuario_account_id)
+                .ok_or(ErrorSistema::CambioPerfilNoExiste)
+        }
+
+        ///VALIDA LA EXISTENCIA DE UN USUARIO EN EL SISTEMA
+        /// 
+        /// #Use
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y retorna un Result<usize,ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion busca la posicion en la lista de usuarios registrados en el sistema del AccountId recibido y la retorna
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan cuando el AccountId aun no fue aprobado en el sistema o cuando el AccountId no existe en el sistema
+        /// 
+        /// ...
+        fn validar_usuario_en_sistema(&self, caller_id: AccountId) -> Result<usize, ErrorSistema>
+        {
+            if let Some(index) = self.get_usuario_registrado_en_sistema(caller_id) { return Ok(index); }
+
+            match self.existe_usuario_en_peticiones_del_sistema(caller_id) {
+                true =>  Err( ErrorSistema::UsuarioNoAprobado),
+                false => Err( ErrorSistema::NoExisteUsuario)
+            }
+        }
+
+        //////////////////// ELECCIONES ////////////////////
+
+        ///INCREMENTA EN 1 EL NUMERO DE IDS DE ELECCIONES, EN CASO DE DESBORDE LO INFORMA
+        /// 
+        /// #Errores
+        /// 
+        /// El caso de error de la funcion se da cuando se alcanzo el numero maximo de representacion con un u64
+        /// 
+        /// ...
+        fn check_add_elecciones_id(&mut self) -> Result<(), ErrorSistema> 
+        {
+            if let Some(resultado) = self.elecciones_conteo_id.checked_add(1) {
+                self.elecciones_conteo_id = resultado;
+                Ok(())
+            } else {
+                Err(ErrorSistema::RepresentacionLimiteAlcanzada)
+            }
+        }
+
+        ///DEVUELVE LA POSICION EN LA LISTA DE ELECCIONES DE UNA ELECCION ESPECIFICA
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe dos u64 y un EstadoEleccion y retorna un Result<usize,ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion busca la posicion en la lista de elecciones actuales de el id de eleccion recibido (U64) y si esta en el EstadoEleccion deseado los retorna
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error de esta funcion son los de las funciones fn existe_eleccion y fn consultar_estado_eleccion
+        /// 
+        /// ...
+        fn validar_eleccion(&mut self,eleccion_id: u64,estado_buscado: EstadoEleccion,timestamp: u64,) -> Result<usize, ErrorSistema> 
+        {
+            let eleccion_index = self.existe_eleccion(eleccion_id)?;
+            self.consultar_estado_eleccion(estado_buscado, eleccion_index, timestamp)?;
+
+            Ok(eleccion_index)
+        }
+
+        ///VALIDA QUE LA ELECCION EXISTA Y NO ESTE CERRADA, ES DECIR QUE ESTE EN PERIODO DE INSCRIPCION O DE VOTACION
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error son los de fn existe_eleccion, ademas de cuando la eleccion ya esta cerrada
+        /// 
+        /// ...
+        fn validar_eleccion_no_cerrada(&self, eleccion_id: u64, timestamp: u64) -> Result<usize, ErrorSistema>
+        {
+            let eleccion_index = self.existe_eleccion(eleccion_id)?;
+
+            match self.elecciones[eleccion_index].get_estado_eleccion(timestamp) {
+                EstadoEleccion::PeriodoInscripcion | EstadoEleccion::PeriodoVotacion => Ok(eleccion_index),
+                _ => Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionCerrada)),
+            }
+        }
+
+        ///VALIDA LA EXISTENCIA DE UNA ELECCION Y DEVUELVE SU POSICION EN EL VEC
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un u64 y retorna un Result<usize,ErrorSistema> 
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion checkea que el u64 pertenezca a los id de eleccion posibles en el sistema y retorna la posicion de la eleccion en la lista de elecciones
+        /// del sistema
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si el u64 es mayor al el valor de eleccion_conteo_id del sistema, si este valor es 0 o si el id de la eleccion pertenece a una 
+        /// eleccion ya finalizada
+        /// 
+        /// ...
+        fn existe_eleccion(&self, eleccion_id: u64) -> Result<usize, ErrorSistema>
+        {
+            if eleccion_id > self.elecciones_conteo_id.saturating_sub(1) || self.elecciones_conteo_id == 0 { 
+                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::NoExisteEleccion))
+            } else {
+                self.get_index_eleccion(eleccion_id)
+            }
+        }
+
+        ///SE BUSCA LA POSICION DE UNA ELECCION EN LAS EN PROGRESO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un u64 y retorna un Result<usize,ErrorSistema> 
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// la eleccion busca entre todas las elecciones actuales del sistema alguna con el mismo id que el recibido, si la encuentra devuelve la posicion de la misma
+        /// 
+        /// #Errores
+        /// 
+        /// El unico caso de error se da si la eleccion buscada no esta en la lista
+        fn get_index_eleccion(&self, eleccion_id: u64) -> Result<usize, ErrorSistema>
+        {
+            for i in 0 .. self.elecciones.len()
+            {
+                if self.elecciones[i].eleccion_id == eleccion_id 
+                {
+                    return Ok(i);
+                }
+            }
+
+            Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionFinalizada))
+        }
+
+        ///SE CONSULTA SI LA ELECCION ESTA EN EL ESTADO DESEADO EN CASO CONTRARIO SE INFORMA EL ESTADO ACTUAL DE LA ELECCION
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe dos u64 y un usize, retorna un Result<(),ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion revisa si el id de eleccion proporcionado portenece a una eleccion en el estado recibido
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan cuando el estado de la eleccion no es el buscado
+        /// 
+        /// ...
+        fn consultar_estado_eleccion(&mut self, estado_buscado: EstadoEleccion, eleccion_index: usize, timestamp: u64) -> Result<(), ErrorSistema>
+        {
+            let estado_eleccion = self.elecciones[eleccion_index].get_estado_eleccion(timestamp);
+
+            if estado_buscado == estado_eleccion {
+                return Ok(());
+            }
+
+            match estado_eleccion {
+                EstadoEleccion::PeriodoInscripcion => Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionEnProcesoInscripcion)),
+                EstadoEleccion::PeriodoVotacion => Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionEnProcesoVotacion)),
+                EstadoEleccion::Cerrada => Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionCerrada)),
+                EstadoEleccion::Finalizada | EstadoEleccion::Invalidada => Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionFinalizada)),
+            }
+        }
+
+        ///VALIDA QUE UN USUARIO TENGA UNA PETICION PENDIENTE EN UNA ELECCION Y DEVUELVE EL ROL CON EL QUE SE POSTULO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y un usize y retorna un Result<Rol,ErrorSistema>
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan cuando el usuario ya fue aprobado como votante o candidato en la eleccion, o cuando no tiene ninguna peticion en ella
+        /// 
+        /// ...
+        fn validar_peticion_pendiente_en_eleccion(&self, usuario_account_id: AccountId, eleccion_index: usize) -> Result<Rol, ErrorSistema>
+        {
+            let e = &self.elecciones[eleccion_index];
+
+            if e.peticiones_votantes.iter().any(|p| p.account_id == usuario_account_id) {
+                Ok(Rol::Votante)
+            } else if e.peticiones_candidatos.iter().any(|p| p.account_id == usuario_account_id) {
+                Ok(Rol::Candidato)
+            } else if e.votantes_aprobados.iter().any(|p| p.account_id == usuario_account_id) {
+                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteActualmenteAprobado))
+            } else if e.candidatos_aprobados.iter().any(|p| p.account_id == usuario_account_id) {
+                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoActualmenteAprobado))
+            } else {
+                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::PeticionNoExiste))
+            }
+        }
+
+        // VALIDA QUE UN USUARIO NO EXISTA EN NINGUNA COLA DE ESPERA O LISTA DE VOTANTES/CANDIDATOS 
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y un usize y retorna un Result<(),ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion valida que un usuario no exista en ninguna lista de una eleccion
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan cuando el accountId ingresado pertenece a alguna lista dentro de la eleccion ya sea de pendientes a confirmacion o de usuarios confirmados
+        /// 
+        /// ...      
+        fn validar_inexistencia_de_usuario_en_eleccion(&self, caller_id: AccountId, eleccion_index: usize) -> Result<(), ErrorSistema>
+        {
+            let e = &self.elecciones[eleccion_index];
+
+            if e.peticiones_votantes.iter().any(|p| p.account_id == caller_id) {
+                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteEnPendiente))
+            } else if e.votantes_aprobados.iter().any(|p| p.account_id == caller_id) {
+                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteActualmenteAprobado))
+            } else if e.peticiones_candidatos.iter().any(|p| p.account_id == caller_id) {
+                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoEnPendiente))
+            } else if e.candidatos_aprobados.iter().any(|p| p.account_id == caller_id) {
+                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoActualmenteAprobado))
+            } else if e.peticiones_listas.iter().chain(e.listas_aprobadas.iter()).any(|l| l.miembros.iter().any(|m| m.account_id == caller_id)) {
+                Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::MiembroDeLista))
+            } else {
+                Ok(())
+            }
+        }
+
+        /// VALIDA QUE QUIEN INVOCA LA FUNCION PUEDA VOTAR EN UNA ELECCION Y DEVUELVE LA POSICION DE LA ELECCION
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un u64 y retorna un Result<usize,ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion valida que quien invoca sea un usuario aprobado, que la eleccion este en periodo de votacion y que el usuario sea un votante
+        /// aprobado en ella que todavia no voto
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si quien invoca es el admin o no es un usuario aprobado, si la eleccion no esta en periodo de votacion,
+        /// si el usuario ya voto o si no es un votante aprobado en la eleccion
+        /// 
+        /// ...
+        fn validar_votante_habilitado(&mut self, eleccion_id: u64) -> Result<usize, ErrorSistema>
+        {
+            let caller_user = self.validar_caller_como_usuario_aprobado(Self::env().caller())?;
+
+            let eleccion_index = self.validar_eleccion(eleccion_id, EstadoEleccion::PeriodoVotacion,  Self::env().block_timestamp())?;
+
+            if self.elecciones[eleccion_index].votantes_votados.iter().any(|v| v.account_id == caller_user.account_id) {
+                return Err(ErrorSistema::VotanteYaVoto)
+            }
+
+            self.validar_votante_aprobado_en_eleccion(caller_user.account_id, eleccion_index)?;
+
+            Ok(eleccion_index)
+        }
+
+        /// VALIDA LA EXISTENCIA DE UN EVENTO DE ELECCIONES Y LO DEVUELVE
+        /// 
+        /// #Errores
+        /// 
+        /// El caso de error se da cuando el id no pertenece a ningun evento
+        /// 
+        /// ...
+        fn validar_evento(&self, evento_id: u64) -> Result<EventoElecciones, ErrorSistema>
+        {
+            match self.eventos_elecciones.get(evento_id as usize) {
+                Some(evento) => Ok(evento.clone()),
+                None => Err(ErrorSistema::EventoNoExiste)
+            }
+        }
+
+        /// BUSCA EL EVENTO AL QUE PERTENECE UNA ELECCION, SI ES QUE PERTENECE A ALGUNO
+        fn evento_de_eleccion(&self, eleccion_id: u64) -> Option<&EventoElecciones>
+        {
+            self.eventos_elecciones.iter().find(|evento| evento.elecciones.contains(&eleccion_id))
+        }
+
+        /// VALIDA QUE UNA ELECCION NO PERTENEZCA A UN EVENTO, YA QUE LOS VOTANTES DE UN EVENTO SE REGISTRAN Y APRUEBAN EN EL PADRON DEL EVENTO
+        /// 
+        /// #Errores
+        /// 
+        /// El caso de error se da cuando la eleccion pertenece a un evento
+        /// 
+        /// ...
+        fn validar_eleccion_fuera_de_evento(&self, eleccion_id: u64) -> Result<(), ErrorSistema>
+        {
+            match self.evento_de_eleccion(eleccion_id) {
+                Some(_) => Err(ErrorSistema::EleccionDeEvento),
+                None => Ok(())
+            }
+        }
+
+        /// DEVUELVE LOS VOTANTES APROBADOS Y PENDIENTES DE UNA ELECCION, QUE EN LAS ELECCIONES DE UN EVENTO SON LOS DEL PADRON DEL EVENTO
+        fn padron_de_eleccion(&self, eleccion_index: usize) -> (&[Usuario], &[Usuario])
+        {
+            let e = &self.elecciones[eleccion_index];
+            match self.evento_de_eleccion(e.eleccion_id) {
+                Some(evento) => (&evento.votantes_aprobados, &evento.peticiones_votantes),
+                None => (&e.votantes_aprobados, &e.peticiones_votantes)
+            }
+        }
+
+        /// VALIDA QUE TODAS LAS ELECCIONES DE UN EVENTO ESTEN EN EL ESTADO BUSCADO Y DEVUELVE SUS POSICIONES EN LA LISTA DE ELECCIONES
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan cuando el evento no existe, o los de validar_eleccion para cualquiera de sus elecciones
+        /// 
+        /// ...
+        fn validar_evento_en_estado(&mut self, evento_id: u64, estado_buscado: EstadoEleccion, timestamp: u64) -> Result<Vec<usize>, ErrorSistema>
+        {
+            let evento = self.validar_evento(evento_id)?;
+
+            let mut elecciones_index = Vec::new();
+            for eleccion_id in evento.elecciones {
+                elecciones_index.push(self.validar_eleccion(eleccion_id, estado_buscado.clone(), timestamp)?);
+            }
+
+            Ok(elecciones_index)
+        }
+
+        /// VALIDA QUE LA ELECCION SE VOTE CON EL METODO DE VOTACION BUSCADO
+        /// 
+        /// #Errores
+        /// 
+        /// El caso de error se da cuando el metodo de votacion de la eleccion no es el buscado
+        /// 
+        /// ...
+        fn validar_metodo_votacion(&self, metodo_buscado: MetodoVotacion, eleccion_index: usize) -> Result<(), ErrorSistema>
+        {
+            if self.elecciones[eleccion_index].metodo_votacion == metodo_buscado { return Ok(()); }
+
+            Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::MetodoVotacionIncorrecto))
+        }
+
+        /// VALIDA QUE UN CANDIDATO PUEDA EDITAR SU PERFIL EN UNA ELECCION
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y un usize y retorna un Result<(),ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion valida que el AccountId recibido este en la cola de candidatos pendientes de la eleccion
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan cuando el candidato ya fue aprobado, con lo que su perfil esta congelado, o cuando no es candidato en la eleccion
+        /// 
+        /// ...
+        fn validar_perfil_editable(&self, candidato_id: AccountId, eleccion_index: usize) -> Result<(), ErrorSistema>
+        {
+            let e = &self.elecciones[eleccion_index];
+
+            if e.peticiones_candidatos.iter().any(|c| c.account_id == candidato_id) { return Ok(()); }
+
+            match e.candidatos_aprobados.iter().any(|c| c.account_id == candidato_id) {
+                true  => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::PerfilCongelado)),
+                false => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste))
+            }
+        }
+
+        /// VALIDA QUE UN USUARIO ESTE VALIDADO COMO VOTANTE
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un AccountId y un usize y retorna un Result<(),ErrorSistema>
+        /// 
+        /// #Funcinalidad
+        /// 
+        /// La funcion valida que el AccountId recibido pertenezca a la eleccion que se encuentra en la posicion recibida y este aprobado como votante en ella.
+        /// En las elecciones de un evento se valida contra el padron del evento, que puede incluir a los candidatos de la eleccion
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan cuando el accountId recibido no pertenece a un votante aprobado en la eleccion o es de un candidato de ella
+        /// 
+        /// ...
+        fn validar_votante_aprobado_en_eleccion(&self, votante_id: AccountId, eleccion_index: usize) -> Result<(), ErrorSistema>
+        {
+            let (aprobados, pendientes) = self.padron_de_eleccion(eleccion_index);
+            if aprobados.iter().any(|v| v.account_id == votante_id) {
+                if self.elecciones[eleccion_index].candidatos_aprobados.iter().any(|c| c.account_id == votante_id) {
+                    return Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoPuedeVotar))
+                }
+                return Ok(());
+            }
+
+
+            return match pendientes.iter().any(|v| v.account_id == votante_id) {
+                true  => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoAprobado)),
+                false => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoExiste))
+            };
+        }
+
+        ///EL SISTEMA VALIDA QUE EL CANDIDATO A APROBAR ESTE EN LA LISTA DE CANDIDATOS PENDIENTES
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el identificador de un usuario (hash de su dni o AccountId) y un usize y retorna un Result<usize,ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion busca al usuario identificado en la eleccion recibida y retorna la pocision del candidato
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si el usuario identificado es un candidato ya aprobado o no es ningun candidato
+        /// 
+        /// ...
+        fn validar_candidato_en_pendientes(&self, candidato: IdentificadorUsuario, eleccion_index: usize) -> Result<usize, ErrorSistema>
+        {
+            if let Some(index) = self.get_candidato_pendiente(candidato, eleccion_index) { return Ok(index) }
+
+            match self.get_candidato_aprobado(candidato, eleccion_index).is_some() {
+                true  => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoActualmenteAprobado)),
+                false => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste)),
+            }
+        }
+
+        ///EL SISTEMA VALIDA QUE EL VOTANTE A APROBAR ESTE EN LA LISTA DE VOTANTES PENDIENTES
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el identificador de un usuario (hash de su dni o AccountId) y un usize y retorna un Result<usize,ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion busca al usuario identificado en la eleccion recibida y retorna la pocision del votante
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si el usuario identificado es un votante ya aprobado o no es ningun votante
+        /// 
+        /// ...
+        fn validar_votante_en_pendientes(&self, votante: IdentificadorUsuario, eleccion_index: usize) -> Result<usize, ErrorSistema>
+        {
+            if let Some(index) = self.get_votante_pendiente(votante, eleccion_index) { return Ok(index) }
+
+            match self.get_votante_aprobado(votante, eleccion_index).is_some() {
+                true  => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteActualmenteAprobado)),
+                false => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoExiste)),
+            }
+        }
+
+        ///EL SISTEMA VALIDA QUE EL VOTANTE SE ENCUENTRE EN LA LISTA DE VOTANTES APROBADOS
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el identificador de un usuario (hash de su dni o AccountId) y un usize y retorna un Result<usize,ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion busca al usuario identificado en la eleccion recibida y retorna la pocision del votante aprobado
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si el usuario identificado es un votante no aprobado o no es ningun votante
+        /// 
+        /// ...
+        fn validar_votante_en_aprobados(&self, votante: IdentificadorUsuario, eleccion_index: usize) -> Result<usize, ErrorSistema>
+        {
+            if let Some(index) = self.get_votante_aprobado(votante, eleccion_index) { return Ok(index) }
+
+            match self.get_votante_pendiente(votante, eleccion_index).is_some() {
+                true  => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoAprobado)),
+                false => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoExiste)),
+            }
+        }
+
+    
+        ///EL SISTEMA VALIDA QUE EL CANDIDATO SE ENCUENTRE EN LA LISTA DE CANDIDATOS APROBADOS
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el identificador de un usuario (hash de su dni o AccountId) y un usize y retorna un Result<usize,ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion busca al usuario identificado en la eleccion recibida y retorna la pocision del candidato
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si el usuario identificado es un candidato no aprobado o no es ningun candidato
+        /// 
+        /// ...
+        fn validar_candidato_aprobado(&self, candidato: IdentificadorUsuario, eleccion_index: usize) -> Result<usize, ErrorSistema>
+        {
+            if let Some(index) = self.get_candidato_aprobado(candidato, eleccion_index) { return Ok(index) }
+
+            match self.get_candidato_pendiente(candidato, eleccion_index).is_some() {
+                true  => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoAprobado)),
+                false => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste)),
+            }
+        }
+
+        ///EL SISTEMA VALIDA QUE EL CANDIDATO ESTE APROBADO Y NO SE HAYA RETIRADO NI HAYA SIDO DESCALIFICADO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el identificador de un usuario (hash de su dni o AccountId) y un usize y retorna un Result<usize,ErrorSistema>
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error son los de validar_candidato_aprobado, ademas de cuando el candidato se retiro o fue descalificado
+        /// 
+        /// ...
+        fn validar_candidato_activo(&self, candidato: IdentificadorUsuario, eleccion_index: usize) -> Result<usize, ErrorSistema>
+        {
+            let index = self.validar_candidato_aprobado(candidato, eleccion_index)?;
+
+            if self.elecciones[eleccion_index].votos[index].estado != EstadoCandidato::Activo {
+                return Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoDadoDeBaja))
+            }
+
+            Ok(index)
+        }
+
+        ///EL SISTEMA VALIDA QUE EL NUMERO DE CANDIDATO PERTENEZCA A UN CANDIDATO APROBADO QUE SIGA ACTIVO
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe un u32 y un usize y retorna un Result<usize,ErrorSistema>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// El numero de candidato es su posicion en la lista de candidatos aprobados de la eleccion comenzando desde 1. Como los candidatos aprobados
+        /// nunca se quitan de la lista, el numero se mantiene estable durante toda la eleccion y se accede al candidato sin recorrer la lista.
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan cuando el numero no pertenece a ningun candidato aprobado o cuando el candidato se retiro o fue descalificado
+        /// 
+        /// ...
+        fn validar_numero_candidato(&self, numero_candidato: u32, eleccion_index: usize) -> Result<usize, ErrorSistema>
+        {
+            let index = match (numero_candidato as usize).checked_sub(1) {
+                Some(index) if index < self.elecciones[eleccion_index].votos.len() => index,
+                _ => return Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste))
+            };
+
+            if self.elecciones[eleccion_index].votos[index].estado != EstadoCandidato::Activo {
+                return Err(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoDadoDeBaja))
+            }
+
+            Ok(index)
+        }
+
+        ///EL SISTEMA BUSCA AL CANDIDATO APROBADO EN LA LISTA Y DEVUELVE SU POSICION EN EL VECTOR
+        ///
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el identificador de un usuario (hash de su dni o AccountId) y un usize y retorna un Option<usize>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion busca en la lista de candidatos aprobados de una eleccion uno que coincida con el identificador recibido y retorna su posicion,
+        /// si no lo encuntra retorna un None
+        /// 
+        /// ...
+        fn get_candidato_aprobado(&self, candidato: IdentificadorUsuario, eleccion_index: usize) -> Option<usize> 
+        {
+            for i in 0 .. self.elecciones[eleccion_index].candidatos_aprobados.len() {
+                if candidato.identifica(&self.elecciones[eleccion_index].candidatos_aprobados[i]) { return Some(i); }
+            }
+
+            None
+        }
+
+        ///EL SISTEMA BUSCA AL VOTANTE APROBADO EN LA LISTA Y DEVUELVE SU POSICION EN EL VECTOR
+        ///
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el identificador de un usuario (hash de su dni o AccountId) y un usize y retorna un Option<usize>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion busca en la lista de votantes aprobados de una eleccion uno que coincida con el identificador recibido y retorna su posicion,
+        /// si no lo encuntra retorna un None
+        /// 
+        /// ...
+        fn get_votante_aprobado(&self, votante: IdentificadorUsuario, eleccion_index: usize) -> Option<usize> 
+        {
+            let (aprobados, _) = self.padron_de_eleccion(eleccion_index);
+            aprobados.iter().position(|v| votante.identifica(v))
+        }
+
+        
+        ///EL SISTEMA BUSCA A UN CANDIDATO EN LA LISTA DE CANDIDATOS PENDIENTES Y DEVUELVE SU POSICION EN EL VECTOR
+        ///
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el identificador de un usuario (hash de su dni o AccountId) y un usize y retorna un Option<usize>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion busca en la lista de candidatos pendientes de una eleccion uno que coincida con el identificador recibido y retorna su posicion,
+        /// si no lo encuntra retorna un None
+        /// 
+        /// ...
+        fn get_candidato_pendiente(&self, candidato: IdentificadorUsuario, eleccion_index: usize) -> Option<usize>  
+        {
+            for i in 0 .. self.elecciones[eleccion_index].peticiones_candidatos.len() {
+                if candidato.identifica(&self.elecciones[eleccion_index].peticiones_candidatos[i]) { return Some(i); }
+            }
+
+            None
+        }
+
+        ///EL SISTEMA BUSCA A UN VOTANTE EN LA LISTA DE VOTANTES PENDIENTES Y DEVUELVE SU POSICION EN EL VECTOR
+        ///
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema, recibe el identificador de un usuario (hash de su dni o AccountId) y un usize y retorna un Option<usize>
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// La funcion busca en la lista de votantes pendientes de una eleccion uno que coincida con el identificador recibido y retorna su posicion,
+        /// si no lo encuntra retorna un None
+        /// 
+        /// ...
+        fn get_votante_pendiente(&self, votante: IdentificadorUsuario, eleccion_index: usize) -> Option<usize>  
+        {
+            for i in 0 .. self.elecciones[eleccion_index].peticiones_votantes.len() {
+                if votante.identifica(&self.elecciones[eleccion_index].peticiones_votantes[i]) { return Some(i); }
+            }
+
+            None
+        }
+
+        ///EL SISTEMA VALIDA QUE LA LISTA A APROBAR ESTE EN LA COLA DE LISTAS PENDIENTES Y DEVUELVE SU POSICION
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si la lista ya fue aprobada o no existe en la eleccion
+        /// 
+        /// ...
+        fn validar_lista_en_pendientes(&self, nombre_lista: &str, eleccion_index: usize) -> Result<usize, ErrorSistema>
+        {
+            if let Some(index) = self.get_lista_pendiente(nombre_lista, eleccion_index) { return Ok(index) }
+
+            match self.get_lista_aprobada(nombre_lista, eleccion_index).is_some() {
+                true  => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::ListaActualmenteAprobada)),
+                false => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::ListaNoExiste)),
+            }
+        }
+
+        ///EL SISTEMA VALIDA QUE LA LISTA ESTE APROBADA Y DEVUELVE SU POSICION EN LOS VOTOS POR LISTA
+        /// 
+        /// #Errores
+        /// 
+        /// Los casos de error se dan si la lista esta pendiente de aprobacion o no existe en la eleccion
+        /// 
+        /// ...
+        fn validar_lista_aprobada(&self, nombre_lista: &str, eleccion_index: usize) -> Result<usize, ErrorSistema>
+        {
+            if let Some(index) = self.elecciones[eleccion_index].votos_listas.iter().position(|l| l.lista_nombre == nombre_lista) { return Ok(index) }
+
+            match self.get_lista_pendiente(nombre_lista, eleccion_index).is_some() {
+                true  => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::ListaNoAprobada)),
+                false => Err( ErrorSistema::ErrorDeEleccion(ErrorEleccion::ListaNoExiste)),
+            }
+        }
+
+        ///EL SISTEMA BUSCA UNA LISTA EN LA COLA DE LISTAS PENDIENTES Y DEVUELVE SU POSICION EN EL VECTOR
+        fn get_lista_pendiente(&self, nombre_lista: &str, eleccion_index: usize) -> Option<usize>
+        {
+            self.elecciones[eleccion_index].peticiones_listas.iter().position(|l| l.nombre == nombre_lista)
+        }
+
+        ///EL SISTEMA BUSCA UNA LISTA EN LAS LISTAS APROBADAS Y DEVUELVE SU POSICION EN EL VECTOR
+        fn get_lista_aprobada(&self, nombre_lista: &str, eleccion_index: usize) -> Option<usize>
+        {
+            self.elecciones[eleccion_index].listas_aprobadas.iter().position(|l| l.nombre == nombre_lista)
+        }
+    }
+
+
+
+    #[derive(Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum ErrorSistema
+    {
+        UsuarioYaRegistrado,
+        AdminYaRegistrado,
+        UsuarioYaRegistradoEnPeticiones,
+        NoExisteUsuario,
+        UsuarioNoAprobado,
+        NoSePoseenPermisos,
+        AccionUnicaDeUsuarios,
+        RepresentacionLimiteAlcanzada,
+        FechaInicioInvalida(ErrorFecha),
+        FechaCierreInvalida(ErrorFecha),
+        FechaInicioPasada,
+        FechaCierrePasada,
+        FechaCierreAntesInicio,
+        EleccionInvalida,
+        VotanteYaVoto,
+        ResultadosNoDisponibles,
+        QuorumInvalido,
+        CambioPerfilNoExiste,
+        DniYaRegistrado,
+        UsuarioSuspendido,
+        UsuarioNoSuspendido,
+        AtestadorYaRegistrado,
+        AtestadorNoExiste,
+        AtestacionInvalida,
+        DniIncorrecto,
+        PlantillaNoExiste,
+        DuracionInvalida,
+        EventoNoExiste,
+        EventoSinElecciones,
+        EleccionFueraDeEvento,
+        EleccionRepetida,
+        EleccionDeEvento,
+        AuditorYaRegistrado,
+        AuditorNoExiste,
+        PaginacionInvalida,
+        SistemaNoRegistrado,
+        SistemaYaRegistrado,
+        LectorYaAutorizado,
+        LectorNoAutorizado,
+        IntervalosExcedidos,
+        ErrorDeEleccion(ErrorEleccion),
+    }
+
+    impl ToString for ErrorSistema {
+        fn to_string(&self) -> String 
+        {
+            match self {
+                ErrorSistema::UsuarioYaRegistrado => "El usuario ya se encuentra registrado y aprobado en el sistema".to_owned(),
+                ErrorSistema::AdminYaRegistrado => "Los administradores se registran al momento de instanciar el sistema, ó de delegar su rol.".to_owned(),
+                ErrorSistema::UsuarioYaRegistradoEnPeticiones => "El usuario ya se encuentra registrado en la cola de aprobación del sistema, deberá esperar a ser aprobado.".to_owned(),
+                ErrorSistema::NoExisteUsuario => "El usuario no existe en el sistema.".to_owned(),
+                ErrorSistema::UsuarioNoAprobado => "Usted se encuentra dentro de la cola de peticiones del sistema, debe esperar a ser aceptado.".to_owned(),
+                ErrorSistema::NoSePoseenPermisos => "Solo el administrador puede realizar esta accion.".to_owned(),
+                ErrorSistema::AccionUnicaDeUsuarios => "Solo los usuarios pueden realizar esta accion.".to_owned(),
+                ErrorSistema::RepresentacionLimiteAlcanzada => "La máxima representación del tipo de dato fue alcanzada. Contacte al administrador para mantenimiento urgente.".to_owned(),
+                ErrorSistema::FechaInicioInvalida(error) => error.to_string(),
+                ErrorSistema::FechaCierreInvalida(error) => error.to_string(),
+                ErrorSistema::FechaInicioPasada => "La fecha de incio de la eleccion es anterior al dia actual.".to_owned(),
+                ErrorSistema::FechaCierrePasada => "La fecha de cierre de la eleccion es anterior al dia actual.".to_owned(),
+                ErrorSistema::FechaCierreAntesInicio => "La fecha de cierre de la eleccion es anterior a la fecha de inicio.".to_owned(),
+                ErrorSistema::EleccionInvalida => "La elección ingresada no existe.".to_owned(),
+                ErrorSistema::VotanteYaVoto => "El votante ya ha votado.".to_owned(),
+                ErrorSistema::ErrorDeEleccion(error) => error.to_string(),
+                ErrorSistema::ResultadosNoDisponibles => "Los resultados de la elección no están disponibles.".to_owned(),
+                ErrorSistema::QuorumInvalido => "El quorum expresado en porcentaje no puede superar el 100%.".to_owned(),
+                ErrorSistema::CambioPerfilNoExiste => "El usuario no tiene un cambio de perfil pendiente de revisión.".to_owned(),
+                ErrorSistema::DniYaRegistrado => "El DNI ingresado ya pertenece a otra cuenta del sistema.".to_owned(),
+                ErrorSistema::UsuarioSuspendido => "El usuario se encuentra suspendido, debe esperar a ser reactivado por el administrador.".to_owned(),
+                ErrorSistema::UsuarioNoSuspendido => "El usuario no se encuentra suspendido.".to_owned(),
+                ErrorSistema::AtestadorYaRegistrado => "El atestador ingresado ya se encuentra registrado.".to_owned(),
+                ErrorSistema::AtestadorNoExiste => "El atestador ingresado no se encuentra registrado.".to_owned(),
+                ErrorSistema::AtestacionInvalida => "La firma no corresponde a un atestador de confianza para los datos ingresados.".to_owned(),
+                ErrorSistema::DniIncorrecto => "El DNI ingresado no coincide con el registrado por el usuario.".to_owned(),
+                ErrorSistema::PlantillaNoExiste => "La plantilla de eleccion ingresada no existe.".to_owned(),
+                ErrorSistema::DuracionInvalida => "La duracion de la eleccion debe ser mayor a 0.".to_owned(),
+                ErrorSistema::EventoNoExiste => "El evento de elecciones ingresado no existe.".to_owned(),
+                ErrorSistema::EventoSinElecciones => "El evento debe tener al menos una eleccion.".to_owned(),
+                ErrorSistema::EleccionFueraDeEvento => "La eleccion ingresada no pertenece al evento.".to_owned(),
+                ErrorSistema::EleccionRepetida => "Solo se puede emitir un voto por eleccion.".to_owned(),
+                ErrorSistema::EleccionDeEvento => "La eleccion pertenece a un evento, los votantes se registran y aprueban en el evento.".to_owned(),
+                ErrorSistema::AuditorYaRegistrado => "El auditor ingresado ya se encuentra registrado.".to_owned(),
+                ErrorSistema::AuditorNoExiste => "El auditor ingresado no se encuentra registrado.".to_owned(),
+                ErrorSistema::PaginacionInvalida => "La cantidad de resultados por pagina debe ser mayor a cero.".to_owned(),
+                ErrorSistema::SistemaNoRegistrado => "No hay un sistema de votacion registrado con ese nombre.".to_owned(),
+                ErrorSistema::SistemaYaRegistrado => "Ya hay un sistema de votacion registrado con ese nombre.".to_owned(),
+                ErrorSistema::LectorYaAutorizado => "La cuenta ingresada ya se encuentra autorizada.".to_owned(),
+                ErrorSistema::LectorNoAutorizado => "La cuenta ingresada no se encuentra autorizada.".to_owned(),
+                ErrorSistema::IntervalosExcedidos => "El periodo de votacion tiene demasiados intervalos, utilice un intervalo mayor.".to_owned(),
+            }
+        }
+    }
+
+    #[derive(Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct ErrorInterfaz {
+        error: ErrorSistema,
+        texto: String
+    }
+
+    impl ErrorInterfaz {
+        fn new(error: ErrorSistema) -> Self
+        {
+            let texto = error.to_string();
+            ErrorInterfaz { error, texto }
+        }
+    }
+
+    //////////////////////////////// ELECCIONES ////////////////////////////////
+
+
+    #[derive(Clone, Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct EleccionInterfaz
+    {
+        eleccion_id: u64,
+        cargo: String,
+
+        fecha_inicio: Fecha,
+        fecha_cierre: Fecha,
+
+        estado_eleccion: EstadoEleccion,
+        metodo_votacion: MetodoVotacion,
+        candidatos_aprobados: Vec<Usuario>, // Solo los candidatos activos
+        numeros_candidatos: Vec<u32>, // Numero con el que se puede votar a cada candidato activo, en el mismo orden
+        bajas_candidatos: Vec<BajaCandidato>,
+        perfiles_candidatos: Vec<PerfilCandidato>,
+        listas_aprobadas: Vec<Lista>,
+        resultados: Option<Vec<CandidatoVotos>>,
+        resultados_listas: Option<Vec<ListaVotos>>
+    }
+
+    impl EleccionInterfaz {
+        #[allow(clippy::too_many_arguments)]
+        fn new(
+            eleccion_id: u64,
+            cargo: String,
+            fecha_inicio: Fecha,
+            fecha_cierre: Fecha,
+            estado_eleccion: EstadoEleccion,
+            metodo_votacion: MetodoVotacion,
+            candidatos_aprobados: Vec<Usuario>,
+            numeros_candidatos: Vec<u32>,
+            bajas_candidatos: Vec<BajaCandidato>,
+            perfiles_candidatos: Vec<PerfilCandidato>,
+            listas_aprobadas: Vec<Lista>,
+            resultados: Option<Vec<CandidatoVotos>>,
+            resultados_listas: Option<Vec<ListaVotos>>
+        ) -> Self {
+            EleccionInterfaz {
+                eleccion_id,
+                cargo,
+                fecha_inicio,
+                fecha_cierre,
+                estado_eleccion,
+                metodo_votacion,
+                candidatos_aprobados,
+                numeros_candidatos,
+                bajas_candidatos,
+                perfiles_candidatos,
+                listas_aprobadas,
+                resultados,
+                resultados_listas
+            }
+        }
+        ///CREAR UNA ELECCION INTERFAZ A PARTIR DE UNA ELECCION INTERNA DEL SISTEMA
+        ///Solo se incluyen los candidatos aprobados que siguen activos, junto a su numero de candidato, y sus perfiles, y los resultados solo si se pide mostrarlos
+        fn from_eleccion(estado_eleccion: EstadoEleccion, eleccion: Eleccion, mostrar_resultados: bool) -> EleccionInterfaz {
+            let (numeros_candidatos, candidatos_activos): (Vec<u32>, Vec<Usuario>) = eleccion.candidatos_aprobados
+                .into_iter()
+                .enumerate()
+                .filter(|(_, c)| !eleccion.bajas_candidatos.iter().any(|b| b.candidato.account_id == c.account_id))
+                .map(|(i, c)| ((i as u32).saturating_add(1), c))
+                .unzip();
+            let perfiles_candidatos = eleccion.perfiles_candidatos
+                .into_iter()
+                .filter(|p| candidatos_activos.iter().any(|c| c.account_id == p.account_id))
+                .collect();
+
+            let (resultados, resultados_listas) = match mostrar_resultados {
+                true => (Some(eleccion.votos), Some(eleccion.votos_listas)),
+                false => (None, None),
+            };
+
+            EleccionInterfaz::new(
+                eleccion.eleccion_id,
+                eleccion.cargo,
+                eleccion.fecha_inicio_interfaz,
+                eleccion.fecha_cierre_interfaz,
+                estado_eleccion,
+                eleccion.metodo_votacion,
+                candidatos_activos,
+                numeros_candidatos,
+                eleccion.bajas_candidatos,
+                perfiles_candidatos,
+                eleccion.listas_aprobadas,
+                resultados,
+                resultados_listas
+            )
+        }
+    }
+
+
+
+
+    #[derive(Clone, Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Eleccion
+    {
+        eleccion_id: u64, // Número alto de representación para un futuro sustento
+
+        cargo: String, // Decido String en vez de ENUM debido a la inmensa cantidad de cargos posibles, al fin y al cabo, quien se encarga de esto es el administrador electoral
+
+        fecha_inicio: Timestamp, // Dato pedido del profe
+        fecha_cierre: Timestamp,
+
+        fecha_inicio_interfaz: Fecha,
+        fecha_cierre_interfaz: Fecha,
+
+        votos: Vec<CandidatoVotos>, // No se deben poder getterar hasta que el Timestamp de cierre haya sido alcanzado
+
+        candidatos_aprobados: Vec<Usuario>,
+        peticiones_candidatos: Vec<Usuario>,
+        perfiles_candidatos: Vec<PerfilCandidato>,
+
+        votantes_aprobados: Vec<Usuario>,
+        peticiones_votantes: Vec<Usuario>,
+        votantes_votados: Vec<Usuario>,
+        marcas_tiempo_votos: Vec<Timestamp>, // Momento de cada voto emitido, sin relacion con el candidato o lista votada
+
+        pesos_votantes: Vec<PesoVotante>, // Los votantes aprobados sin peso asignado votan con peso 1
+
+        quorum: Option<Quorum>,
+        quorum_alcanzado: bool, // Solo pasa a false al finalizar una eleccion que no alcanzo el quorum
+        ganador: Option<AccountId>, // Cuenta del candidato ganador, se registra al contar la eleccion
+
+        metodo_votacion: MetodoVotacion,
+        listas_aprobadas: Vec<Lista>,
+        peticiones_listas: Vec<Lista>,
+        votos_listas: Vec<ListaVotos>,
+
+        bajas_candidatos: Vec<BajaCandidato>,
+        tratamiento_votos_bajas: TratamientoVotos,
+        votos_anulados: u64,
+        votos_anulados_ponderados: u64,
+
+        plantilla_id: Option<u64>, // Plantilla a partir de la cual se creo la eleccion, si la hay
+    }
+
+    impl Eleccion {
+        pub fn new(
+            eleccion_id: u64,
+            cargo: String,
+            fecha_inicio: Timestamp,
+            fecha_cierre: Timestamp,
+            fecha_inicio_interfaz: Fecha,
+            fecha_cierre_interfaz: Fecha,
+        ) -> Self {
+            Eleccion {
+                eleccion_id,
+                cargo,
+                fecha_inicio,
+                fecha_cierre,
+                fecha_inicio_interfaz,
+                fecha_cierre_interfaz,
+
+                votos: Vec::new(),
+
+                candidatos_aprobados: Vec::new(),
+                peticiones_candidatos: Vec::new(),
+                perfiles_candidatos: Vec::new(),
+                votantes_aprobados: Vec::new(),
+                peticiones_votantes: Vec::new(),
+                votantes_votados: Vec::new(),
+                marcas_tiempo_votos: Vec::new(),
+
+                pesos_votantes: Vec::new(),
+
+                quorum: None,
+                quorum_alcanzado: true,
+                ganador: None,
+
+                metodo_votacion: MetodoVotacion::Candidatos,
+                listas_aprobadas: Vec::new(),
+                peticiones_listas: Vec::new(),
+                votos_listas: Vec::new(),
+
+                bajas_candidatos: Vec::new(),
+                tratamiento_votos_bajas: TratamientoVotos::Conservar,
+                votos_anulados: 0,
+                votos_anulados_ponderados: 0,
+
+                plantilla_id: None,
+            }
+        }
+
+
+        ///DEVUELVE UN ESTADOELECCION EN BASE A UN TIMESTAMP RECIBIDO
+        fn get_estado_eleccion(&self, timestamp: u64) -> EstadoEleccion
+        {
+            if self.fecha_inicio > timestamp {
+                EstadoEleccion::PeriodoInscripcion
+            } else if self.fecha_cierre <= timestamp {
+                EstadoEleccion::Cerrada
+            } else {
+                EstadoEleccion::PeriodoVotacion
+            }
+        }
+        pub fn get_eleccion_votos(&self) -> Vec<CandidatoVotos> {
+            self.votos.clone()
+        }
+        pub fn get_plantilla_id(&self) -> Option<u64> {
+            self.plantilla_id
+        }
+        // pub fn get_dimf_votantes_aprobados(&self)-> usize{
+        //     self.votantes_aprobados.len()
+        // }
+        pub fn get_votantes_aprobados(&self) -> Vec<Usuario> {
+            self.votantes_aprobados.clone()
+        }
+        pub fn get_votantes_registrados(&self) -> Vec<Usuario> {
+            self.peticiones_votantes.clone()
+        }
+        pub fn get_candidatos_aprobados(&self) -> Vec<Usuario> {
+            self.candidatos_aprobados.clone()
+        }
+        pub fn set_candidatos_aprobados(&mut self, usuarios: Vec<Usuario>){
+            self.candidatos_aprobados = usuarios;
+        }
+        pub fn get_candidatos_registrados(&self) -> Vec<Usuario> {
+            self.peticiones_candidatos.clone()
+        }
+        pub fn set_candidatos_registrados(&mut self, usuarios: Vec<Usuario>){
+            self.peticiones_candidatos = usuarios;
+        }
+        pub fn get_votantes_votados(&self) -> Vec<Usuario> {
+            self.votantes_votados.clone()
+        }
+        pub fn set_votantes_votados(&mut self, usuarios: Vec<Usuario>){
+            self.votantes_votados = usuarios;
+        }
+        pub fn get_fecha_inicio(&self) -> Timestamp {
+            self.fecha_inicio
+        }
+        pub fn get_fecha_cierre(&self) -> Timestamp {
+            self.fecha_cierre
+        }
+        pub fn get_fecha_inicio_interfaz(&self) -> Fecha {
+            self.fecha_inicio_interfaz.clone()
+        }
+        pub fn get_fecha_cierre_interfaz(&self) -> Fecha {
+            self.fecha_cierre_interfaz.clone()
+        }
+        pub fn get_marcas_tiempo_votos(&self) -> Vec<Timestamp> {
+            self.marcas_tiempo_votos.clone()
+        }
+        pub fn set_marcas_tiempo_votos(&mut self, marcas_tiempo_votos: Vec<Timestamp>){
+            self.marcas_tiempo_votos = marcas_tiempo_votos;
+        }
+        pub fn get_id(&self) -> u64 {
+            self.eleccion_id
+        }
+        pub fn get_cargo(&self) -> String {
+            self.cargo.clone()
+        }
+         pub fn set_votantes_registrados(&mut self, usuarios: Vec<Usuario>){
+            self.peticiones_votantes = usuarios;
+        }
+
+        pub fn set_votantes_aprobados(&mut self, usuarios: Vec<Usuario>){
+            self.votantes_aprobados = usuarios;
+        }
+
+        pub fn set_votos(&mut self, votos: Vec<CandidatoVotos>){
+            self.votos = votos;
+        }
+
+        pub fn set_pesos_votantes(&mut self, pesos: Vec<PesoVotante>){
+            self.pesos_votantes = pesos;
+        }
+
+        ///DEVUELVE EL PESO DEL VOTO DE UN VOTANTE, 1 SI EL ADMIN NO LE ASIGNO NINGUNO
+        pub fn get_peso_votante(&self, votante_id: AccountId) -> u64 {
+            match self.pesos_votantes.iter().find(|p| p.account_id == votante_id) {
+                Some(peso_votante) => peso_votante.peso,
+                None => 1,
+            }
+        }
+
+        ///DEVUELVE LA SUMA DE LOS PESOS DE TODOS LOS VOTANTES APROBADOS EN LA ELECCION
+        pub fn get_peso_total_votantes_aprobados(&self) -> u128 {
+            self.votantes_aprobados
+                .iter()
+                .fold(0_u128, |total, v| total.saturating_add(self.get_peso_votante(v.account_id) as u128))
+        }
+
+        pub fn get_quorum(&self) -> Option<Quorum> {
+            self.quorum.clone()
+        }
+
+        pub fn get_quorum_alcanzado(&self) -> bool {
+            self.quorum_alcanzado
+        }
+
+        pub fn set_quorum_alcanzado(&mut self, quorum_alcanzado: bool){
+            self.quorum_alcanzado = quorum_alcanzado;
+        }
+
+        ///INFORMA SI LA CANTIDAD DE VOTANTES QUE VOTARON ALCANZA EL QUORUM DE LA ELECCION
+        /// 
+        /// #Uso
+        /// 
+        /// La funcion es de uso interno del sistema y retorna un bool
+        /// 
+        /// #Funcionalidad
+        /// 
+        /// Si la eleccion no tiene quorum siempre se considera alcanzado. Si el quorum es absoluto se compara contra la cantidad de votantes que votaron,
+        /// y si es un porcentaje se compara contra el porcentaje de votantes aprobados que votaron
+        /// 
+        /// ...
+        fn alcanza_quorum(&self) -> bool {
+            let votados = self.votantes_votados.len() as u128;
+            match self.quorum {
+                None => true,
+                Some(Quorum::Absoluto(minimo)) => votados >= minimo as u128,
+                Some(Quorum::Porcentaje(porcentaje)) => {
+                    let aprobados = self.votantes_aprobados.len() as u128;
+                    votados.saturating_mul(100) >= aprobados.saturating_mul(porcentaje as u128)
+                }
+            }
+        }
+
+        pub fn get_metodo_votacion(&self) -> MetodoVotacion {
+            self.metodo_votacion.clone()
+        }
+
+        pub fn get_listas_aprobadas(&self) -> Vec<Lista> {
+            self.listas_aprobadas.clone()
+        }
+
+        pub fn set_listas_aprobadas(&mut self, listas: Vec<Lista>){
+            self.listas_aprobadas = listas;
+        }
+
+        pub fn get_peticiones_listas(&self) -> Vec<Lista> {
+            self.peticiones_listas.clone()
+        }
+
+        pub fn set_peticiones_listas(&mut self, listas: Vec<Lista>){
+            self.peticiones_listas = listas;
+        }
+
+        pub fn get_votos_listas(&self) -> Vec<ListaVotos> {
+            self.votos_listas.clone()
+        }
+
+        pub fn set_votos_listas(&mut self, votos_listas: Vec<ListaVotos>){
+            self.votos_listas = votos_listas;
+        }
+
+        pub fn get_bajas_candidatos(&self) -> Vec<BajaCandidato> {
+            self.bajas_candidatos.clone()
+        }
+
+        pub fn get_votos_anulados(&self) -> u64 {
+            self.votos_anulados
+        }
+
+        pub fn get_votos_anulados_ponderados(&self) -> u64 {
+            self.votos_anulados_ponderados
+        }
+
+        pub fn set_votos_anulados(&mut self, votos_anulados: u64, votos_anulados_ponderados: u64){
+            self.votos_anulados = votos_anulados;
+            self.votos_anulados_ponderados = votos_anulados_ponderados;
+        }
+
+        ///SI LA ELECCION ANULA LOS VOTOS DE LOS CANDIDATOS DADOS DE BAJA, SE LOS DESCUENTA Y SE LOS SUMA A LOS VOTOS ANULADOS
+        fn aplicar_tratamiento_votos_bajas(&mut self) {
+            if self.tratamiento_votos_bajas != TratamientoVotos::Anular { return; }
+
+            for candidato in self.votos.iter_mut().filter(|c| c.estado != EstadoCandidato::Activo) {
+                self.votos_anulados = self.votos_anulados.saturating_add(candidato.votos_recaudados);
+                self.votos_anulados_ponderados = self.votos_anulados_ponderados.saturating_add(candidato.votos_ponderados);
+                candidato.votos_recaudados = 0;
+                candidato.votos_ponderados = 0;
+            }
+        }
+
+        ///REGISTRA EN LA ELECCION EL PADRON DEL EVENTO AL QUE PERTENECE, SIN LOS CANDIDATOS QUE COMPITEN EN ELLA
+        fn registrar_padron_de_evento(&mut self, evento: &EventoElecciones) {
+            let votantes: Vec<Usuario> = evento.votantes_aprobados.iter()
+                .filter(|v| !self.candidatos_aprobados.iter().any(|c| c.account_id == v.account_id))
+                .cloned()
+                .collect();
+            self.votantes_aprobados = votantes;
+            self.peticiones_votantes = evento.peticiones_votantes.clone();
+        }
+
+        ///ORDENA LOS VOTOS DE MAYOR A MENOR JUNTO CON LOS CANDIDATOS APROBADOS, PARA QUE SIGAN ALINEADOS, Y REGISTRA LA CUENTA DEL GANADOR
+        fn contar_votos_candidatos(&mut self) -> Option<CandidatoVotos> {
+            let mut conteo: Vec<(Usuario, CandidatoVotos)> = self.candidatos_aprobados.drain(..).zip(self.votos.drain(..)).collect();
+            conteo.sort_by_key(|(_, votos)| votos.votos_ponderados);
+            conteo.reverse();
+            (self.candidatos_aprobados, self.votos) = conteo.into_iter().unzip();
+
+            let ganador = self.candidatos_aprobados.iter().zip(self.votos.iter())
+                .find(|(_, votos)| votos.estado == EstadoCandidato::Activo && votos.votos_ponderados > 0);
+            self.ganador = ganador.map(|(candidato, _)| candidato.account_id);
+            ganador.map(|(_, votos)| votos.clone())
+        }
+
+        pub fn get_perfiles_candidatos(&self) -> Vec<PerfilCandidato> {
+            self.perfiles_candidatos.clone()
+        }
+
+        ///REEMPLAZA LOS DATOS DE UN USUARIO EN TODAS LAS LISTAS DE LA ELECCION, INCLUYENDO EL NOMBRE Y DNI DE SUS VOTOS SI ES CANDIDATO
+        fn actualizar_usuario(&mut self, usuario: &Usuario) {
+            for (candidato, votos) in self.candidatos_aprobados.iter().zip(self.votos.iter_mut()) {
+                if candidato.account_id == usuario.account_id {
+                    votos.candidato_nombre = usuario.nombre.clone();
+                    votos.candidato_dni = usuario.dni_visible.clone();
+                }
+            }
+
+            self.usuarios_mut()
+                .filter(|u| u.account_id == usuario.account_id)
+                .for_each(|u| *u = usuario.clone());
+        }
+
+        ///MUESTRA EL DNI COMPLETO DE UN CANDIDATO EN TODAS LAS LISTAS DE LA ELECCION Y EN SUS VOTOS
+        fn publicar_dni(&mut self, account_id: AccountId, dni: String) {
+            for (candidato, votos) in self.candidatos_aprobados.iter().zip(self.votos.iter_mut()) {
+                if candidato.account_id == account_id {
+                    votos.candidato_dni = dni.clone();
+                }
+            }
+
+            self.usuarios_mut()
+                .filter(|u| u.account_id == account_id)
+                .for_each(|u| u.dni_visible = dni.clone());
+        }
+
+        ///MUEVE TODO LO QUE UNA CUENTA TIENE EN LA ELECCION A OTRA CUENTA, INCLUYENDO SU REGISTRO EN LOS VOTANTES QUE YA VOTARON
+        fn migrar_cuenta(&mut self, cuenta_actual: AccountId, nueva_cuenta: AccountId) {
+            self.usuarios_mut()
+                .filter(|u| u.account_id == cuenta_actual)
+                .for_each(|u| u.account_id = nueva_cuenta);
+
+            self.perfiles_candidatos.iter_mut()
+                .filter(|p| p.account_id == cuenta_actual)
+                .for_each(|p| p.account_id = nueva_cuenta);
+            if self.ganador == Some(cuenta_actual) {
+                self.ganador = Some(nueva_cuenta);
+            }
+            self.pesos_votantes.iter_mut()
+                .filter(|p| p.account_id == cuenta_actual)
+                .for_each(|p| p.account_id = nueva_cuenta);
+            self.listas_aprobadas.iter_mut()
+                .chain(self.peticiones_listas.iter_mut())
+                .filter(|l| l.lider == cuenta_actual)
+                .for_each(|l| l.lider = nueva_cuenta);
+        }
+
+        ///QUITA LAS PETICIONES PENDIENTES DE UNA CUENTA, SU PERFIL DE CANDIDATO Y LAS LISTAS PENDIENTES QUE INTEGRA
+        fn quitar_peticiones_de_usuario(&mut self, account_id: AccountId) {
+            let era_candidato_pendiente = self.peticiones_candidatos.iter().any(|u| u.account_id == account_id);
+
+            self.peticiones_votantes.retain(|u| u.account_id != account_id);
+            self.peticiones_candidatos.retain(|u| u.account_id != account_id);
+            self.peticiones_listas.retain(|l| !l.miembros.iter().any(|m| m.account_id == account_id));
+            if era_candidato_pendiente {
+                self.perfiles_candidatos.retain(|p| p.account_id != account_id);
+            }
+        }
+
+        ///RECORRE TODAS LAS COPIAS DE USUARIOS GUARDADAS EN LA ELECCION
+        fn usuarios_mut(&mut self) -> impl Iterator<Item = &mut Usuario> {
+            self.candidatos_aprobados.iter_mut()
+                .chain(self.peticiones_candidatos.iter_mut())
+                .chain(self.votantes_aprobados.iter_mut())
+                .chain(self.peticiones_votantes.iter_mut())
+                .chain(self.votantes_votados.iter_mut())
+                .chain(self.bajas_candidatos.iter_mut().map(|b| &mut b.candidato))
+                .chain(self.listas_aprobadas.iter_mut().chain(self.peticiones_listas.iter_mut()).flat_map(|l| l.miembros.iter_mut()))
+        }
+
+        ///ARMA LA PARTICIPACION DE UN USUARIO EN LA ELECCION, COMO VOTANTE Y COMO CANDIDATO O MIEMBRO DE UNA LISTA
+        fn participacion_de_usuario(&self, account_id: AccountId, finalizada: bool) -> Vec<ParticipacionUsuario> {
+            let mut participacion = Vec::new();
+            let es_usuario = |u: &Usuario| u.account_id == account_id;
+            let en_lista = |l: &Lista| l.miembros.iter().any(es_usuario);
+
+            let estado_votante = if self.votantes_votados.iter().any(es_usuario) {
+                Some(EstadoParticipacion::Voto)
+            } else if self.votantes_aprobados.iter().any(es_usuario) {
+                Some(EstadoParticipacion::Aprobado)
+            } else if self.peticiones_votantes.iter().any(es_usuario) {
+                Some(EstadoParticipacion::Solicitado)
+            } else {
+                None
+            };
+            if let Some(estado) = estado_votante {
+                let resultado = if finalizada { ResultadoParticipacion::Finalizada } else { ResultadoParticipacion::EnCurso };
+                participacion.push(ParticipacionUsuario::new(self.eleccion_id, self.cargo.clone(), Rol::Votante, estado, resultado));
+            }
+
+            let estado_candidato = if let Some(baja) = self.bajas_candidatos.iter().find(|b| es_usuario(&b.candidato)) {
+                Some(EstadoParticipacion::Baja(baja.estado.clone()))
+            } else if self.candidatos_aprobados.iter().any(es_usuario) || self.listas_aprobadas.iter().any(en_lista) {
+                Some(EstadoParticipacion::Aprobado)
+            } else if self.peticiones_candidatos.iter().any(es_usuario) || self.peticiones_listas.iter().any(en_lista) {
+                Some(EstadoParticipacion::Solicitado)
+            } else {
+                None
+            };
+            if let Some(estado) = estado_candidato {
+                let resultado = match finalizada {
+                    false => ResultadoParticipacion::EnCurso,
+                    true if self.es_ganador(account_id) => ResultadoParticipacion::Electo,
+                    true => ResultadoParticipacion::NoElecto,
+                };
+                participacion.push(ParticipacionUsuario::new(self.eleccion_id, self.cargo.clone(), Rol::Candidato, estado, resultado));
+            }
+
+            participacion
+        }
+
+        ///INFORMA SI EL USUARIO ES EL CANDIDATO GANADOR, O MIEMBRO DE LA LISTA GANADORA, DE UNA ELECCION YA CONTADA
+        fn es_ganador(&self, account_id: AccountId) -> bool {
+            if !self.quorum_alcanzado { return false; }
+
+            if let MetodoVotacion::Listas = self.metodo_votacion {
+                return match self.votos_listas.first() {
+                    Some(ganadora) => self.listas_aprobadas.iter()
+                        .any(|l| l.nombre == ganadora.lista_nombre && l.miembros.iter().any(|m| m.account_id == account_id)),
+                    None => false,
+                };
+            }
+
+            self.ganador == Some(account_id)
+        }
+
+        ///GUARDA EL PERFIL DE UN CANDIDATO, REEMPLAZANDO EL QUE TUVIERA ANTERIORMENTE
+        fn guardar_perfil(&mut self, perfil: PerfilCandidato) {
+            match self.perfiles_candidatos.iter_mut().find(|p| p.account_id == perfil.account_id) {
+                Some(perfil_actual) => *perfil_actual = perfil,
+                None => self.perfiles_candidatos.push(perfil),
+            }
+        }
+
+        ///ASIGNA EL PESO A UN VOTANTE, REEMPLAZANDO EL QUE TUVIERA ANTERIORMENTE
+        fn asignar_peso(&mut self, votante_id: AccountId, peso: u64) {
+            match self.pesos_votantes.iter_mut().find(|p| p.account_id == votante_id) {
+                Some(peso_votante) => peso_votante.peso = peso,
+                None => self.pesos_votantes.push(PesoVotante::new(votante_id, peso)),
+            }
+        }
+    }
+
+
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum EstadoEleccion { PeriodoInscripcion, PeriodoVotacion, Cerrada, Finalizada, Invalidada }
+
+    ///FORMA EN LA QUE SE POSTULAN Y SE VOTAN LAS OPCIONES DE UNA ELECCION
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum MetodoVotacion { Candidatos, Listas }
+
+    ///QUORUM MINIMO DE VOTANTES QUE DEBEN VOTAR PARA QUE UNA ELECCION SEA VALIDA
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum Quorum { Porcentaje(u8), Absoluto(u64) }
+
+    ///GRUPO DE ELECCIONES SIMULTANEAS QUE COMPARTEN FECHAS Y PADRON DE VOTANTES
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct EventoElecciones
+    {
+        evento_id: u64,
+        nombre: String,
+        elecciones: Vec<u64>, // Ids de las elecciones del evento
+        peticiones_votantes: Vec<Usuario>,
+        votantes_aprobados: Vec<Usuario>, // Padron unico del evento, las elecciones del evento no guardan una copia mientras estan en curso
+    }
+
+    impl EventoElecciones {
+        pub fn new(evento_id: u64, nombre: String, elecciones: Vec<u64>) -> Self {
+            EventoElecciones { evento_id, nombre, elecciones, peticiones_votantes: Vec::new(), votantes_aprobados: Vec::new() }
+        }
+        pub fn get_peticiones_votantes(&self) -> Vec<Usuario> {
+            self.peticiones_votantes.clone()
+        }
+        pub fn get_votantes_aprobados(&self) -> Vec<Usuario> {
+            self.votantes_aprobados.clone()
+        }
+
+        ///REEMPLAZA LOS DATOS DE UN USUARIO EN EL PADRON DEL EVENTO
+        fn actualizar_usuario(&mut self, usuario: &Usuario) {
+            self.peticiones_votantes.iter_mut()
+                .chain(self.votantes_aprobados.iter_mut())
+                .filter(|u| u.account_id == usuario.account_id)
+                .for_each(|u| *u = usuario.clone());
+        }
+
+        ///MUEVE EL REGISTRO DE UNA CUENTA EN EL PADRON DEL EVENTO A OTRA CUENTA
+        fn migrar_cuenta(&mut self, cuenta_actual: AccountId, nueva_cuenta: AccountId) {
+            self.peticiones_votantes.iter_mut()
+                .chain(self.votantes_aprobados.iter_mut())
+                .filter(|u| u.account_id == cuenta_actual)
+                .for_each(|u| u.account_id = nueva_cuenta);
+        }
+
+        ///QUITA LA PETICION PENDIENTE DE UNA CUENTA EN EL PADRON DEL EVENTO
+        fn quitar_peticiones_de_usuario(&mut self, account_id: AccountId) {
+            self.peticiones_votantes.retain(|u| u.account_id != account_id);
+        }
+    }
+
+    ///VOTO A UN CANDIDATO, POR SU NUMERO, EN UNA DE LAS ELECCIONES DE UN EVENTO
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct VotoEvento
+    {
+        eleccion_id: u64,
+        numero_candidato: u32,
+    }
+
+    impl VotoEvento {
+        pub fn new(eleccion_id: u64, numero_candidato: u32) -> Self {
+            VotoEvento { eleccion_id, numero_candidato }
+        }
+    }
+
+    ///DATOS QUE SE REPITEN EN CADA EDICION DE UNA ELECCION PERIODICA
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct PlantillaEleccion
+    {
+        plantilla_id: u64,
+        cargo: String,
+        duracion_segundos: u64, // Tiempo entre el inicio y el cierre de cada eleccion creada
+        metodo_votacion: MetodoVotacion,
+        quorum: Option<Quorum>,
+        padron: Vec<AccountId>, // Usuarios aprobados como votantes en cada eleccion creada
+    }
+
+    impl PlantillaEleccion {
+        pub fn new(plantilla_id: u64, cargo: String, duracion_segundos: u64, metodo_votacion: MetodoVotacion, quorum: Option<Quorum>, padron: Vec<AccountId>) -> Self {
+            PlantillaEleccion { plantilla_id, cargo, duracion_segundos, metodo_votacion, quorum, padron }
+        }
+    }
+
+
+    #[derive(Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum ErrorEleccion
+    {
+        NoExisteEleccion,
+
+        EleccionEnProcesoInscripcion,
+        EleccionEnProcesoVotacion,
+        EleccionCerrada,
+        EleccionFinalizada,
+
+        CandidatoActualmenteAprobado,
+        CandidatoEnPendiente,
+        CandidatoNoAprobado,
+        CandidatoNoExiste,
+        CandidatoDadoDeBaja,
+
+        VotanteActualmenteAprobado,
+        VotanteEnPendiente,
+        VotanteNoAprobado,
+        VotanteNoExiste,
+
+        PesoInvalido,
+        PerfilCongelado,
+        PeticionNoExiste,
+
+        MetodoVotacionIncorrecto,
+        MiembroDeLista,
+        ListaYaExiste,
+        ListaActualmenteAprobada,
+        ListaNoAprobada,
+        ListaNoExiste,
+        CandidatoNoPuedeVotar,
+    }
+
+    impl ToString for ErrorEleccion {
+        fn to_string(&self) -> String
+        {
+            match self {
+                ErrorEleccion::NoExisteEleccion => "La id de elección ingresada no existe.".to_owned(),
+                ErrorEleccion::EleccionEnProcesoInscripcion => "La elección ingresada se encuentra en período de inscripción.".to_owned(),
+                ErrorEleccion::EleccionEnProcesoVotacion => "La elección ingresada se encuentra en período de votación.".to_owned(),
+                ErrorEleccion::EleccionCerrada => "La elección ingresada se encuentra cerrada.".to_owned(),
+                ErrorEleccion::EleccionFinalizada => "La eleccion ingresada se encuentra finalizada.".to_owned(),
+                ErrorEleccion::CandidatoActualmenteAprobado => "El candidato ingresado ya se encuentra actualmente aprobado.".to_owned(),
+                ErrorEleccion::CandidatoEnPendiente => "El candidato ingresado ya se encuentra en la cola de peticiones para candidato y debe esperar a ser aprobado".to_owned(),
+                ErrorEleccion::CandidatoNoAprobado => "El candidato ingresado está en espera de aprobación.".to_owned(),
+                ErrorEleccion::CandidatoNoExiste => "El candidato ingresado no existe en la elección.".to_owned(),
+                ErrorEleccion::CandidatoDadoDeBaja => "El candidato ingresado se retiró o fue descalificado de la elección.".to_owned(),
+                ErrorEleccion::VotanteActualmenteAprobado => "El votante ingresado ya se encuentra actualmente aprobado.".to_owned(),
+                ErrorEleccion::VotanteEnPendiente => "El votante ingresado ya se encuentra en la cola de peticiones para votante y debe esperar a ser aprobado".to_owned(),
+                ErrorEleccion::VotanteNoAprobado => "El votante ingresado no fue aprobado para esta elección, no tendrá permiso para votar.".to_owned(),
+                ErrorEleccion::VotanteNoExiste => "El votante ingresado no existe en la elección.".to_owned(),
+                ErrorEleccion::PesoInvalido => "El peso asignado a un votante debe ser mayor a cero.".to_owned(),
+                ErrorEleccion::PerfilCongelado => "El candidato ya fue aprobado, su perfil no puede modificarse.".to_owned(),
+                ErrorEleccion::PeticionNoExiste => "El usuario no tiene una petición pendiente en la elección.".to_owned(),
+                ErrorEleccion::MetodoVotacionIncorrecto => "La elección ingresada no admite esta forma de postulación.".to_owned(),
+                ErrorEleccion::MiembroDeLista => "El usuario ingresado ya es miembro de una lista en esta elección.".to_owned(),
+                ErrorEleccion::ListaYaExiste => "Ya existe una lista con ese nombre en la elección.".to_owned(),
+                ErrorEleccion::ListaActualmenteAprobada => "La lista ingresada ya se encuentra actualmente aprobada.".to_owned(),
+                ErrorEleccion::ListaNoAprobada => "La lista ingresada está en espera de aprobación.".to_owned(),
+                ErrorEleccion::ListaNoExiste => "La lista ingresada no existe en la elección.".to_owned(),
+                ErrorEleccion::CandidatoNoPuedeVotar => "Un candidato no puede votar en la elección en la que compite.".to_owned(),
+            }
+        }
+    }
+
+    //////////////////////////////// VOTOS Y RESULTADOS ////////////////////////////////
+
+    #[derive(Clone, Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct CandidatoVotos {
+        candidato_nombre: String,
+        candidato_dni: String,
+        votos_recaudados: u64,
+        votos_ponderados: u64, // Suma de los pesos de los votantes que lo eligieron
+        estado: EstadoCandidato,
+    }
+
+    impl CandidatoVotos {
+        pub fn new(candidato_nombre: String, candidato_dni: String) -> Self {
+            CandidatoVotos {
+                candidato_nombre,
+                candidato_dni,
+                votos_recaudados: 0,
+                votos_ponderados: 0,
+                estado: EstadoCandidato::Activo,
+            }
+        }
+        pub fn get_candidato_nombre(&self) -> String {
+            self.candidato_nombre.clone()
+        }
+        pub fn get_estado(&self) -> EstadoCandidato {
+            self.estado.clone()
+        }
+        pub fn set_estado(&mut self, estado: EstadoCandidato){
+            self.estado = estado;
+        }
+        pub fn get_votos_recaudados(&self) -> u64 {
+            self.votos_recaudados
+        }
+        pub fn set_votos_recaudados(&mut self, cantidad: u64){
+            self.votos_recaudados = cantidad;
+        }
+        pub fn get_votos_ponderados(&self) -> u64 {
+            self.votos_ponderados
+        }
+        pub fn set_votos_ponderados(&mut self, cantidad: u64){
+            self.votos_ponderados = cantidad;
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum EstadoCandidato { Activo, Retirado, Descalificado(String) } // La descalificacion guarda el motivo
+
+    ///QUE SUCEDE CON LOS VOTOS DE UN CANDIDATO RETIRADO O DESCALIFICADO AL FINALIZAR LA ELECCION
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum TratamientoVotos { Anular, Conservar }
+
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct BajaCandidato
+    {
+        candidato: Usuario,
+        estado: EstadoCandidato,
+    }
+
+    impl BajaCandidato {
+        pub fn new(candidato: Usuario, estado: EstadoCandidato) -> Self {
+            BajaCandidato { candidato, estado }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct PesoVotante
+    {
+        account_id: AccountId,
+        peso: u64,
+    }
+
+    impl PesoVotante {
+        pub fn new(account_id: AccountId, peso: u64) -> Self {
+            PesoVotante { account_id, peso }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct ListaVotos {
+        lista_nombre: String,
+        votos_recaudados: u64,
+        votos_ponderados: u64,
+    }
+
+    impl ListaVotos {
+        pub fn new(lista_nombre: String) -> Self {
+            ListaVotos {
+                lista_nombre,
+                votos_recaudados: 0,
+                votos_ponderados: 0,
+            }
+        }
+        pub fn get_lista_nombre(&self) -> String {
+            self.lista_nombre.clone()
+        }
+        pub fn get_votos_recaudados(&self) -> u64 {
+            self.votos_recaudados
+        }
+        pub fn set_votos_recaudados(&mut self, cantidad: u64){
+            self.votos_recaudados = cantidad;
+        }
+        pub fn get_votos_ponderados(&self) -> u64 {
+            self.votos_ponderados
+        }
+        pub fn set_votos_ponderados(&mut self, cantidad: u64){
+            self.votos_ponderados = cantidad;
+        }
+    }
+
+    ///RESULTADO DE FINALIZAR Y CONTAR UNA ELECCION
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum ResultadoEleccion
+    {
+        Ganador(CandidatoVotos),
+        ListaGanadora(ListaVotos),
+        SinVotos,
+        QuorumNoAlcanzado,
+    }
+
+    ///PARTICIPACION DE UN USUARIO EN UNA ELECCION, COMO VOTANTE O COMO CANDIDATO
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct ParticipacionUsuario
+    {
+        eleccion_id: u64,
+        cargo: String,
+        rol: Rol, // Los miembros de una lista participan como candidatos
+        estado: EstadoParticipacion,
+        resultado: ResultadoParticipacion,
+    }
+
+    impl ParticipacionUsuario {
+        pub fn new(eleccion_id: u64, cargo: String, rol: Rol, estado: EstadoParticipacion, resultado: ResultadoParticipacion) -> Self {
+            ParticipacionUsuario { eleccion_id, cargo, rol, estado, resultado }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum EstadoParticipacion { Solicitado, Aprobado, Voto, Baja(EstadoCandidato) }
+
+    ///LOS VOTANTES SOLO VEN SI LA ELECCION FINALIZO, LOS CANDIDATOS VEN ADEMAS SI RESULTARON ELECTOS
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum ResultadoParticipacion { EnCurso, Finalizada, Electo, NoElecto }
+
+    ///ESTADO GENERAL DEL SISTEMA, PENSADO PARA SER CONSULTADO PERIODICAMENTE
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct EstadisticasSistema
+    {
+        usuarios_registrados: u64, // Incluye al admin
+        peticiones_registro: u64,
+        elecciones_en_inscripcion: u64,
+        elecciones_en_votacion: u64,
+        elecciones_cerradas: u64, // Cerradas pero aun no finalizadas
+        elecciones_finiquitadas: u64,
+        votos_emitidos: u64,
+        participacion_promedio: u128,
+    }
+
+    impl EstadisticasSistema {
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            usuarios_registrados: u64,
+            peticiones_registro: u64,
+            elecciones_en_inscripcion: u64,
+            elecciones_en_votacion: u64,
+            elecciones_cerradas: u64,
+            elecciones_finiquitadas: u64,
+            votos_emitidos: u64,
+            participacion_promedio: u128
+        ) -> Self {
+            EstadisticasSistema {
+                usuarios_registrados,
+                peticiones_registro,
+                elecciones_en_inscripcion,
+                elecciones_en_votacion,
+                elecciones_cerradas,
+                elecciones_finiquitadas,
+                votos_emitidos,
+                participacion_promedio
+            }
+        }
+    }
+
+    //////////////////////////////// USUARIOS ////////////////////////////////
+
+    ///LISTA O BOLETA QUE SE POSTULA COMO UNIDAD, INTEGRADA POR USUARIOS APROBADOS
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Lista
+    {
+        nombre: String,
+        lider: AccountId,
+        miembros: Vec<Usuario>, // El lider siempre es el primer miembro
+    }
+
+    impl Lista {
+        pub fn new(nombre: String, lider: AccountId) -> Self {
+            Lista {
+                nombre,
+                lider,
+                miembros: Vec::new(),
+            }
+        }
+        pub fn get_nombre(&self) -> String {
+            self.nombre.clone()
+        }
+        pub fn get_miembros(&self) -> Vec<Usuario> {
+            self.miembros.clone()
+        }
+        pub fn set_miembros(&mut self, miembros: Vec<Usuario>) {
+            self.miembros = miembros;
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct PerfilCandidato
+    {
+        account_id: AccountId,
+        partido: String, // Partido o lista por la que se presenta
+        biografia: String,
+        url_plataforma: String,
+        hash_manifiesto: Hash,
+    }
+
+    impl PerfilCandidato {
+        pub fn new(account_id: AccountId, partido: String, biografia: String, url_plataforma: String, hash_manifiesto: Hash) -> Self {
+            PerfilCandidato {
+                account_id,
+                partido,
+                biografia,
+                url_plataforma,
+                hash_manifiesto,
+            }
+        }
+    }
+    
+
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum Rol { Votante, Candidato }
+
+    ///PETICION QUE UN USUARIO PUEDE CANCELAR: LA DE REGISTRO AL SISTEMA O LA DE UN ROL EN UNA ELECCION
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum TipoPeticion { Sistema, Eleccion(u64, Rol) } // Id de la eleccion y rol solicitado
+
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct RetiroPeticion
+    {
+        usuario: Usuario,
+        peticion: TipoPeticion,
+        timestamp: Timestamp,
+    }
+
+    impl RetiroPeticion {
+        pub fn new(usuario: Usuario, peticion: TipoPeticion, timestamp: Timestamp) -> Self {
+            RetiroPeticion { usuario, peticion, timestamp }
+        }
+    }
+
+
+    ///FORMAS DE IDENTIFICAR A UN USUARIO DENTRO DE UNA ELECCION
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum IdentificadorUsuario
+    {
+        Dni(Hash),
+        Cuenta(AccountId),
+    }
+
+    impl IdentificadorUsuario {
+        fn identifica(&self, usuario: &Usuario) -> bool {
+            match self {
+                IdentificadorUsuario::Dni(dni_hash) => usuario.dni_hash == *dni_hash,
+                IdentificadorUsuario::Cuenta(account_id) => usuario.account_id == *account_id,
+            }
+        }
+    }
+
+    ///ENTRADA DEL INDICE DNI -> CUENTA DEL SISTEMA
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct RegistroDni
+    {
+        dni_hash: Hash,
+        account_id: AccountId,
+    }
+
+    impl RegistroDni {
+        pub fn new(dni_hash: Hash, account_id: AccountId) -> Self {
+            RegistroDni { dni_hash, account_id }
+        }
+    }
+
+    ///MIGRACION DE UN USUARIO DE UNA CUENTA A OTRA
+    #[derive(Clone, Debug, PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct MigracionCuenta
+    {
+        cuenta_anterior: AccountId,
+        nueva_cuenta: AccountId,
+        timestamp: Timestamp,
+    }
+
+    impl MigracionCuenta {
+        pub fn new(cuenta_anterior: AccountId, nueva_cuenta: AccountId, timestamp: Timestamp) -> Self {
+            MigracionCuenta { cuenta_anterior, nueva_cuenta, timestamp }
+        }
+    }
+
+    #[derive(Clone, Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Usuario
+    {
+        account_id: AccountId,
+        nombre: String,
+        dni_hash: Hash, // Hash con sal del dni, se usa para unicidad y busquedas. No oculta el dni, ver calcular_hash_dni
+        dni_visible: String, // Dni enmascarado, o completo si el candidato decidio publicarlo
+    }
+
+    impl Usuario {
+        pub fn new(account_id: AccountId, nombre: String, dni: String, sal_dni: Hash) -> Self {
+            Usuario {
+                account_id,
+                nombre,
+                dni_hash: calcular_hash_dni(&dni, &sal_dni),
+                dni_visible: enmascarar_dni(&dni),
+            }
+        }
+
+        pub fn get_account_id(&self) -> AccountId {
+            self.account_id
+        }
+        pub fn get_dni_visible(&self) -> String {
+            self.dni_visible.clone()
+        }
+    }
+
+    ///CALCULA EL HASH BLAKE2X256 DE UN DNI JUNTO CON LA SAL DEL SISTEMA
+    ///
+    /// El hash evita guardar el dni en texto plano y mostrarlo completo en las consultas, pero no lo mantiene en secreto: la sal esta
+    /// en el storage del contrato y un dni tiene alrededor de 8 digitos, asi que cualquiera puede recuperarlo probando todos los valores.
+    /// Ademas los mensajes que reciben un dni (registro, cambio de perfil, reasignacion, transferencia del admin, aprobaciones, pesos,
+    /// votos, descalificaciones y publicacion del dni de un candidato) lo reciben en texto plano, por lo que queda en la transaccion
+    fn calcular_hash_dni(dni: &str, sal_dni: &Hash) -> Hash {
+        let mut hash = <ink::env::hash::Blake2x256 as ink::env::hash::HashOutput>::Type::default();
+        ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&(sal_dni, dni), &mut hash);
+        Hash::from(hash)
+    }
+
+    ///REEMPLAZA POR '*' TODOS LOS CARACTERES DE UN DNI SALVO LOS ULTIMOS 3
+    fn enmascarar_dni(dni: &str) -> String {
+        let visibles = dni.chars().count().saturating_sub(3);
+        dni.chars().enumerate().map(|(i, c)| if i < visibles { '*' } else { c }).collect()
+    }
+
+    ////////////////////////////// Fecha /////////////////////////////
+
+    #[derive(Clone, Debug,PartialEq)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Fecha { dia: u8, mes: u8, año: u32, hora: u8, min: u8, seg: u8 } // Año como unsigned debido a que sí o sí se tratarán fechas mayores a la actual
+
+
+    impl Fecha 
+    {
+        pub fn new(dia: u8, mes: u8, año: u32, hora: u8, min: u8, seg: u8) -> Self
+        { 
+            Fecha { dia, mes, año, hora, min, seg }
+        }
+        ///VALIDA QUE LA FECHA INGRESADA EN NUMEROS SEA UNA FECHA CORRECTA Y EXISTENTE, TENIENDO EN CUENTA AÑOS BISIESTOS
+        ///
+        ///#Uso
+        ///Llamar a esta funcion sobre una fecha devuelve un Result con un Ok vacio, en caso de ser valida, o un ErrorFecha en caso de no serlo.
+        ///
+        ///#Funcionalidad
+        ///Chequea que el mes este dentro del rango 1-12, que el dia este dentro de su rango dependiendo del mes actual y si el año es bisiesto o no.
+        ///Tambien chequea que la hora, los minutos, y los segundos esten dentro de sus correspondientes rangos.
+        ///
+        ///#Errores
+        ///Devuelve variantes de ErrorFecha, dependiendo del primer error encontrado. Esto quiere decir que si hay mas de un error en una fecha ingresada,
+        ///devolvera solo el primer error. Si se valida nuevamente la fecha con el error solucionado, el siguiente error se devolvera, si es que queda alguno.
+        ///Los nombres de los errores siguen el patron Dia/Mes/Hora/Min/Seg Invalido, dependiendo de cual es el campo invalido.
+        ///El año no puede ser invalido, por lo que no hay error que lo represente.
+        fn validar_fecha(&self) -> Result<(), ErrorFecha>
+        {
+            if self.dia == 0 {
+                return Err(ErrorFecha::DiaInvalido);
+            }
+
+            if !match self.mes {
+                1 | 3 | 5 | 7 | 8 | 10 | 12 => self.dia <= 31,
+                4 | 6 | 9 | 11 => self.dia <= 30,
+                2 => self.dia <= if self.es_bisiesto() { 29 } else { 28 },
+                _ => { return Err(ErrorFecha::MesInvalido); }
+            } {
+                return Err(ErrorFecha::DiaInvalido);
+            }
+
+            if self.hora > 23 {
+                return Err(ErrorFecha::HoraInvalida);
+            }
+
+            if self.min > 59 {
+                return Err(ErrorFecha::MinInvalido);
+            }
+
+            if self.seg > 59 {
+                return Err(ErrorFecha::SegInvalido);
+            }
+
+            Ok(())
+        }
+
+
+        ///DEVUELVE SI EL AÑO DE LA FECHA ES BISIESTO
+        ///
+        ///#Uso
+        ///Llamar a esta funcion sobre una fecha te devuelve un bool, donde true significa que el año si es bisiesto, y false que no
+        ///
+        ///#Funcionalidad
+        ///Para saber si un año es bisiesto, se ve si es divisible por 4 pero a si mismo no divisible por 100,
+        ///o si es divisible por 100 y por 400
+        fn es_bisiesto(&self) -> bool {
+            (self.año % 4 == 0 && self.año % 100 != 0) || (self.año % 100 == 0 && self.año % 400 == 0)
+        }
+
+
+        ///CONVIERTE LA FECHA A UNIX EPOCH TIMESTAMP EN MILISEGUNDOS
+        ///
+        ///#Uso
+        ///Llamar a esta funcion sobre una fecha devuelve un u64 que es la representacion de la fecha en Unix Epoch Timestamp.
+        ///Este formato guarda una fecha en forma de los milisegundos pasados desde el primero de enero de 1970 a las 00:00:00 UTC.
+        ///
+        ///#Funcionalidad
+        ///El algoritmo usado fue traducido de uno de los algoritmos presentados en este articulo:
+        ///https://blog.reverberate.org/2020/05/12/optimizing-date-algorithms.html
+        ///
+        ///Todos los datos de la fecha son pasados a u64 previamente a hacer las operaciones para simplificar el codigo siguiente
+        ///y evitar multiples conversiones en el medio.
+        ///
+        ///Se usan las funciones saturating para sumar, restar, dividir, y multiplicar. Estas funciones realizan las operaciones normales,
+        ///pero impiden el overflow y el underflow, simplemente limitando los valores al rango valido para u64.
+        ///Overflow y underflow solo deberia suceder en casos extremos (años tan altos que la tierra ni existiria)
+        ///, o hasta en algunas operaciones es imposible si la fecha fue validada.
+        ///Estas funciones fueron usadas para complacer al linter, que mira hasta los casos extremos.
+        ///Los casos donde no se obtiene el timestamp correcto, son los años anteriores a 1970, ya que eso seria un timestamp
+        ///negativo. En este caso, por las funciones que previenen el overflow y el underflow, todos los años anteriores a 1970 dan un
+        ///timestamp de 0, que es suficiente para este sistema.
+        ///En caso de necesitar años anteriores a 1970, el algoritmo esta capacitado, y solo se necesitaria cambiar los tipos de u64 a i64.
+        ///
+        ///El algoritmo en operaciones normales seria el siguiente:
+        ///
+        ///let año_ajustado = año + 4800;
+        ///let febreros = año_ajustado - if mes <= 2 { 1 } else { 0 };
+        ///let dias_intercalar = 1 + (febreros / 4) - (febreros / 100) + (febreros / 400);
+        ///let dias = 365 * año_ajustado + dias_intercalar + tabla[(mes - 1) as usize] + dia - 1;
+        ///((dias - 2472692) * 86400 + hora * 3600 + min * 60 + seg) * 1000
+        ///
+        ///Este algoritmo calcula la cantidad de febreros y en la variable de dias_intercalar se calculan 
+        ///los febreros que tienen dias extras al estar en años bisiesto. Todos estos dias se suman a los dias
+        ///normales que suceden en cada año pasado (años * 365), a el dia actual guardado en la fecha, y a la cantidad de dias
+        ///que van transcurriendo en el año, segun el mes, usando la tabla ya que estos valores se saben.
+        ///Luego de todos estos calculos, se devuelve los dias, horas, minutos, y segundos, todos pasados a segundos y luego pasados
+        ///a milisegundos.
+        fn to_timestamp(&self) -> u64
+        {
+            let dia: u64 = self.dia.into();
+            let mes: u64 = self.mes.into();
+            let año: u64 = self.año.into();
+            let seg: u64 = self.seg.into();
+            let min: u64 = self.min.into();
+            let hora: u64 = self.hora.into();
+
+            let tabla = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+            let año_ajustado = año.saturating_add(4800);
+            let febreros = año_ajustado.saturating_sub(if mes <= 2 { 1 } else { 0 });
+            let dias_intercalar = 1_u64.saturating_add(febreros.saturating_div(4)).saturating_sub(febreros.saturating_div(100)).saturating_add(febreros.saturating_div(400));
+            let dias = año_ajustado.saturating_mul(365).saturating_add(dias_intercalar).saturating_add(tabla[(mes.saturating_sub(1)) as usize]).saturating_add(dia).saturating_sub(1);
+            dias.saturating_sub(2472692).saturating_mul(86400).saturating_add(hora.saturating_mul(3600)).saturating_add(min.saturating_mul(60)).saturating_add(seg).saturating_mul(1000)
+        }
+
+        ///CONVIERTE UN TIMESTAMP EN UNA FECHA, ES LA OPERACION INVERSA DE TO_TIMESTAMP
+        ///
+        ///#Uso
+        ///Recibe un timestamp en milisegundos en formato Unix Epoch Timestamp y devuelve la Fecha correspondiente. Los milisegundos se descartan.
+        ///
+        ///#Funcionalidad
+        ///Los dias transcurridos se cuentan desde el 1 de marzo del año 0, asi el 29 de febrero queda al final de cada año. Se calcula la era
+        ///(bloques de 400 años, que siempre tienen 146097 dias), el año dentro de la era descontando los dias de los años bisiestos, el dia del año,
+        ///y a partir de el el mes y el dia. Por ultimo los meses de enero y febrero se pasan al año siguiente.
+        fn from_timestamp(timestamp: u64) -> Fecha
+        {
+            let segundos = timestamp.saturating_div(1000);
+            let segundos_del_dia = segundos.checked_rem(86400).unwrap_or(0);
+
+            let dias = segundos.saturating_div(86400).saturating_add(719468);
+            let era = dias.saturating_div(146097);
+            let dia_de_era = dias.saturating_sub(era.saturating_mul(146097));
+            let año_de_era = dia_de_era.saturating_sub(dia_de_era.saturating_div(1460)).saturating_add(dia_de_era.saturating_div(36524)).saturating_sub(dia_de_era.saturating_div(146096)).saturating_div(365);
+            let dia_del_año = dia_de_era.saturating_sub(año_de_era.saturating_mul(365).saturating_add(año_de_era.saturating_div(4)).saturating_sub(año_de_era.saturating_div(100)));
+            let mes_desde_marzo = dia_del_año.saturating_mul(5).saturating_add(2).saturating_div(153);
+            let dia = dia_del_año.saturating_sub(mes_desde_marzo.saturating_mul(153).saturating_add(2).saturating_div(5)).saturating_add(1);
+            let mes = if mes_desde_marzo < 10 { mes_desde_marzo.saturating_add(3) } else { mes_desde_marzo.saturating_sub(9) };
+            let año = año_de_era.saturating_add(era.saturating_mul(400)).saturating_add(if mes <= 2 { 1 } else { 0 });
+
+            Fecha {
+                dia: dia as u8,
+                mes: mes as u8,
+                año: año as u32,
+                hora: segundos_del_dia.saturating_div(3600) as u8,
+                min: segundos_del_dia.checked_rem(3600).unwrap_or(0).saturating_div(60) as u8,
+                seg: segundos_del_dia.checked_rem(60).unwrap_or(0) as u8,
+            }
+        }
+
+
+        ///DEVUELVE SI LA FECHA ES ANTERIOR A LA PASADA POR PARAMETRO
+        ///
+        ///#Uso
+        ///Llamar a esta funcion sobre una fecha, pasando un timestamp, devuelve un bool, el cual señaliza si la fecha es anterior
+        ///o igual a la fecha representada por el timestamp pasado. Esto muestra si es una fecha que ya paso cierta otra fecha.
+        ///
+        ///#Funcionalidad
+        ///Se compara el timestamp de la fecha (en milisegundos) con otro timestamp, para ver si el primero es menor o igual al segundo.
+        ///Los timestamps estan en formato Unix Epoch Timestamp. Este formato guarda una fecha en forma de los milisegundos pasados desde el 
+        ///primero de enero de 1970 a las 00:00:00 UTC.
+        fn fecha_pasada(&self, timestamp: u64) -> bool {
+            self.to_timestamp() <= timestamp
+        }
+    }
+
+
+    #[derive(PartialEq, Debug)] #[ink::scale_derive(Encode, Decode, TypeInfo)] #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum ErrorFecha
+    {
+        DiaInvalido,
+        MesInvalido,
+        HoraInvalida,
+        MinInvalido,
+        SegInvalido,
+    }
+
+    impl ToString for ErrorFecha {
+        fn to_string(&self) -> String {
+            match self {
+                ErrorFecha::DiaInvalido => "El día ingresado es invalido.".to_owned(),
+                ErrorFecha::MesInvalido => "El mes ingresado es invalido.".to_owned(),
+                ErrorFecha::HoraInvalida => "La hora ingresada es incorrecta.".to_owned(),
+                ErrorFecha::MinInvalido => "El minuto ingresado es incorrecto.".to_owned(),
+                ErrorFecha::SegInvalido => "El segundo ingresado es incorrecto.".to_owned()
+            }
+        }
+    }
+
+
+
+    #[cfg(test)]
+    mod tests{
+
+        use super::*;
+
+        /////////////////TEST DEL SISTEMA (METODOS INK::MESSAGE)
+        #[ink::test]
+        fn test_sistema_registrar_validar(){
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AdminYaRegistrado)), sistema.registrarse_en_sistema_priv("julian".to_string(), "12345678".to_string()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Ok(()),sistema.registrarse_en_sistema("julian".to_string(), "12345678".to_string()));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioYaRegistradoEnPeticiones)),sistema.registrarse_en_sistema_priv("julian".to_string(), "12345678".to_string()));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.aprobar_usuario_sistema_priv(accounts.bob));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Ok(()),sistema.aprobar_usuario_sistema_priv(accounts.bob));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioYaRegistrado)),sistema.registrarse_en_sistema_priv("julian".to_string(), "12345678".to_string()));
+        }
+
+        #[allow(unused)]
+        #[ink::test]
+        fn test_sistema_obtener_peticiones()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            let mut sistema=SistemaVotacion::new("tobais".to_string(), "43107333".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "22222".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_en_sistema_priv("charlie".to_string(), "33333".to_string());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.get_peticiones_de_registro_sistema_priv());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Ok(sistema.peticiones_registro.clone()),sistema.get_peticiones_de_registro_sistema_priv());
+        }
+
+        #[ink::test]
+        fn test_delegar_admin()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            assert_eq!(Ok(()),sistema.delegar_admin_priv(accounts.bob, "bob".to_string(), "12345".to_string()));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.delegar_admin_priv(accounts.bob, "bob".to_string(), "12345".to_string()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Ok(()),sistema.delegar_admin_priv(accounts.django, "tobias".to_string(), "43107333".to_string()));
+        }
+
+        /////////////////TEST ELECCIONES (METODOS INK::MESSAGE)
+        #[ink::test]
+        fn test_crear_eleccion()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaInicioInvalida(ErrorFecha::DiaInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 0, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaInicioInvalida(ErrorFecha::MesInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 14, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaInicioInvalida(ErrorFecha::HoraInvalida))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 60, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaInicioInvalida(ErrorFecha::MinInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 70, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaInicioInvalida(ErrorFecha::SegInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 30, seg: 99 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaCierreInvalida(ErrorFecha::DiaInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 32, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaCierreInvalida(ErrorFecha::MesInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 14, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaCierreInvalida(ErrorFecha::HoraInvalida))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 60, min: 30, seg: 00 }, None));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaCierreInvalida(ErrorFecha::MinInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 70, seg: 00 }, None));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaCierreInvalida(ErrorFecha::SegInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 99 }, None));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaInicioPasada)),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 1, año: 1600, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaCierreAntesInicio)),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 1, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2000, hora: 20, min: 30, seg: 00 }, None));
+            assert_eq!(Ok(()),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+            sistema.elecciones_conteo_id= 18446744073709551615;
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::RepresentacionLimiteAlcanzada)),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+        }
+
+        #[ink::test]
+        fn test_setters_y_getters_elecciones(){
+            let mut elec = Eleccion::new(0, String::from("Un cargo"), Timestamp::default(), Timestamp::default(), Fecha::new(1, 1, 1, 1, 1, 1), Fecha::new(1, 1, 1, 1, 1, 1));
+            //Settear votantes aprobados
+            elec.set_votantes_aprobados(vec![Usuario::new(AccountId::from([0x1; 32]), "Pepe".to_owned(), "111".to_owned(), Hash::default())]);
+            assert_eq!(elec.get_votantes_aprobados(), vec![Usuario::new(AccountId::from([0x1; 32]), "Pepe".to_owned(), "111".to_owned(), Hash::default())]);
+            //Settear votantes registrados
+            elec.set_votantes_registrados(vec![Usuario::new(AccountId::from([0x1; 32]), "Pepe".to_owned(), "111".to_owned(), Hash::default())]);
+            assert_eq!(elec.get_votantes_registrados(), vec![Usuario::new(AccountId::from([0x1; 32]), "Pepe".to_owned(), "111".to_owned(), Hash::default())]);
+        }
+
+        #[allow(unused)]
+        #[ink::test]
+        fn test_get_elecciones()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
+            assert_eq!(Ok(sistema.clonar_elecciones_actuales_a_interfaz(0)),sistema.get_elecciones_actuales_priv());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioNoAprobado)),sistema.get_elecciones_actuales_priv());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema_priv(accounts.bob);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Ok(sistema.clonar_elecciones_actuales_a_interfaz(0)),sistema.get_elecciones_actuales_priv());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoExisteUsuario)),sistema.get_elecciones_actuales_priv());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:13,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            sistema.finalizar_y_contar_eleccion_priv(0);
+            assert_eq!(Ok(sistema.clonar_elecciones_historicas_a_interfaz()),sistema.get_elecciones_historial_priv());
+            assert_eq!(sistema.elecciones_finiquitadas,sistema.get_elecciones_finiquitadas_priv());
+            
+        }
+
+        #[allow(unused)]
+        #[ink::test]
+        fn test_registrarse_eleccion()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AccionUnicaDeUsuarios)),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoExisteUsuario)),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato));
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioNoAprobado)),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Ok(()),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.get_candidatos_pendientes_priv(0));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoExisteUsuario)),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante));
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioNoAprobado)),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Ok(vec![Usuario::new(accounts.bob,"bob".to_string(),"12345".to_string(), sistema.sal_dni)]),sistema.get_candidatos_pendientes_priv(0));
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::NoExisteEleccion))),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id, Rol::Votante));
+            assert_eq!(Ok(()),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.get_votantes_pendientes_priv(0));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteEnPendiente))),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Ok(vec![Usuario::new(accounts.alice,"alice".to_string(),"11111".to_string(), sistema.sal_dni)]),sistema.get_votantes_pendientes_priv(0));
+        }
+
+        #[allow(unused)]
+        #[ink::test]
+        fn test_aprobar_votante_candidato_en_eleccion()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Ok(()),sistema.aprobar_candidato_eleccion(sistema.elecciones_conteo_id-1, "12345".to_owned()));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoActualmenteAprobado))),sistema.aprobar_candidato_eleccion(sistema.elecciones_conteo_id-1, "12345".to_owned()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoActualmenteAprobado))),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Ok(()),sistema.aprobar_votante_eleccion(sistema.elecciones_conteo_id-1, "11111".to_owned()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteActualmenteAprobado))),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante));
+            
+        }
+
+        #[allow(unused)]
+        #[ink::test]
+        fn test_votar_finalizar_eleccion()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_candidato_eleccion(sistema.elecciones_conteo_id-1, "12345".to_owned());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_votante_eleccion(sistema.elecciones_conteo_id-1, "11111".to_owned());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionEnProcesoInscripcion))),sistema.votar_eleccion_priv(sistema.elecciones_conteo_id-1, "12345".to_owned()));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            assert_eq!(Ok(()),sistema.votar_eleccion_priv(sistema.elecciones_conteo_id-1, "12345".to_owned()));
+            assert_eq!(sistema.elecciones[0].get_marcas_tiempo_votos(), vec![Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp()]);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::VotanteYaVoto)),sistema.votar_eleccion_priv(sistema.elecciones_conteo_id-1, "12345".to_owned()));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:13,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionCerrada))),sistema.votar_eleccion_priv(sistema.elecciones_conteo_id-1, "12345".to_owned()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoExiste))),sistema.votar_eleccion_priv(sistema.elecciones_conteo_id-1, "12345".to_owned()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:13,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            assert_eq!(Ok(ResultadoEleccion::Ganador(CandidatoVotos{candidato_nombre:"bob".to_string(), candidato_dni:"**345".to_string(), votos_recaudados:1, votos_ponderados:1, estado:EstadoCandidato::Activo})),sistema.finalizar_y_contar_eleccion_priv(0));
+            // assert_eq!(Ok(vec![Usuario::new(accounts.alice,"alice".to_string(),"11111".to_string(), sistema.sal_dni)]),sistema.get_elecciones_terminadas_x(0));
+            // assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::EleccionInvalida)),sistema.get_elecciones_terminadas_x(4));
+        }
+
+        #[allow(unused)]
+        #[ink::test]
+        fn test_votos_ponderados()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_en_sistema_priv("charlie".to_string(), "22222".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            sistema.aprobar_usuario_sistema(accounts.charlie);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_candidato_eleccion(0, "12345".to_owned());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoAprobado))),sistema.asignar_peso_votante_priv(0, "11111".to_owned(), 10));
+            sistema.aprobar_votante_eleccion(0, "11111".to_owned());
+            sistema.aprobar_votante_eleccion(0, "22222".to_owned());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::PesoInvalido))),sistema.asignar_peso_votante_priv(0, "11111".to_owned(), 0));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoExiste))),sistema.asignar_peso_votante_priv(0, "99999".to_owned(), 10));
+            assert_eq!(Ok(()),sistema.asignar_peso_votante_priv(0, "11111".to_owned(), 10));
+            assert_eq!(sistema.elecciones[0].get_peso_votante(accounts.alice), 10);
+            assert_eq!(sistema.elecciones[0].get_peso_votante(accounts.charlie), 1);
+            assert_eq!(sistema.elecciones[0].get_peso_total_votantes_aprobados(), 11);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.asignar_peso_votante_priv(0, "11111".to_owned(), 20));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            assert_eq!(Ok(()),sistema.votar_eleccion_priv(0, "12345".to_owned()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(Ok(()),sistema.votar_eleccion_priv(0, "12345".to_owned()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionEnProcesoVotacion))),sistema.asignar_peso_votante_priv(0, "22222".to_owned(), 5));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:13,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            assert_eq!(Ok(ResultadoEleccion::Ganador(CandidatoVotos{candidato_nombre:"bob".to_string(), candidato_dni:"**345".to_string(), votos_recaudados:2, votos_ponderados:11, estado:EstadoCandidato::Activo})),sistema.finalizar_y_contar_eleccion_priv(0));
+        }
+
+        #[allow(unused)]
+        #[ink::test]
+        fn test_quorum_eleccion()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::QuorumInvalido)),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Some(Quorum::Porcentaje(101))));
+            assert_eq!(Ok(()),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Some(Quorum::Absoluto(2))));
+            assert_eq!(Ok(()),sistema.crear_nueva_eleccion_priv("Rey".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Some(Quorum::Porcentaje(50))));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_en_sistema_priv("charlie".to_string(), "22222".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            sistema.aprobar_usuario_sistema(accounts.charlie);
+            for eleccion_id in 0..2 {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+                sistema.registrarse_a_eleccion_priv(eleccion_id, Rol::Candidato);
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+                sistema.registrarse_a_eleccion_priv(eleccion_id, Rol::Votante);
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+                sistema.registrarse_a_eleccion_priv(eleccion_id, Rol::Votante);
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+                sistema.aprobar_candidato_eleccion(eleccion_id, "12345".to_owned());
+                sistema.aprobar_votante_eleccion(eleccion_id, "11111".to_owned());
+                sistema.aprobar_votante_eleccion(eleccion_id, "22222".to_owned());
+            }
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(()),sistema.votar_eleccion_priv(0, "12345".to_owned()));
+            assert_eq!(Ok(()),sistema.votar_eleccion_priv(1, "12345".to_owned()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:13,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            // 1 de 2 votantes no alcanza un quorum absoluto de 2, pero si alcanza el 50%
+            assert_eq!(Ok(ResultadoEleccion::QuorumNoAlcanzado),sistema.finalizar_y_contar_eleccion_priv(0));
+            assert!(!sistema.elecciones_finiquitadas[0].get_quorum_alcanzado());
+            assert_eq!(sistema.clonar_elecciones_historicas_a_interfaz()[0].estado_eleccion, EstadoEleccion::Invalidada);
+            assert_eq!(Ok(ResultadoEleccion::Ganador(CandidatoVotos{candidato_nombre:"bob".to_string(), candidato_dni:"**345".to_string(), votos_recaudados:1, votos_ponderados:1, estado:EstadoCandidato::Activo})),sistema.finalizar_y_contar_eleccion_priv(1));
+            assert!(sistema.elecciones_finiquitadas[1].get_quorum_alcanzado());
+        }
+
+        #[allow(unused)]
+        #[ink::test]
+        fn test_perfil_candidato()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
+            assert_eq!(Ok(()),sistema.editar_perfil_candidato_priv(0, "Partido A".to_string(), "Bio".to_string(), "https://a.org".to_string(), Hash::from([0x1; 32])));
+            assert_eq!(Ok(()),sistema.editar_perfil_candidato_priv(0, "Partido B".to_string(), "Bio".to_string(), "https://b.org".to_string(), Hash::from([0x2; 32])));
+            let perfil = PerfilCandidato::new(accounts.bob, "Partido B".to_string(), "Bio".to_string(), "https://b.org".to_string(), Hash::from([0x2; 32]));
+            assert_eq!(sistema.elecciones[0].get_perfiles_candidatos(), vec![perfil.clone()]);
+            // El perfil de un candidato pendiente no se muestra en la interfaz
+            assert_eq!(sistema.clonar_elecciones_actuales_a_interfaz(0)[0].perfiles_candidatos, Vec::new());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste))),sistema.editar_perfil_candidato_priv(0, "Partido C".to_string(), "Bio".to_string(), "https://c.org".to_string(), Hash::from([0x3; 32])));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_candidato_eleccion(0, "12345".to_owned());
+            assert_eq!(sistema.clonar_elecciones_actuales_a_interfaz(0)[0].perfiles_candidatos, vec![perfil]);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::PerfilCongelado))),sistema.editar_perfil_candidato_priv(0, "Partido C".to_string(), "Bio".to_string(), "https://c.org".to_string(), Hash::from([0x3; 32])));
+        }
+
+        #[allow(unused)]
+        #[ink::test]
+        fn test_baja_candidatos()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
+            sistema.crear_nueva_eleccion_priv("Rey".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            sistema.registrarse_en_sistema_priv("eve".to_string(), "54321".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_en_sistema_priv("charlie".to_string(), "22222".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.eve);
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            sistema.aprobar_usuario_sistema(accounts.charlie);
+            for eleccion_id in 0..2 {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+                sistema.registrarse_a_eleccion_priv(eleccion_id, Rol::Candidato);
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+                sistema.registrarse_a_eleccion_priv(eleccion_id, Rol::Candidato);
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+                sistema.registrarse_a_eleccion_priv(eleccion_id, Rol::Votante);
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+                sistema.registrarse_a_eleccion_priv(eleccion_id, Rol::Votante);
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+                sistema.aprobar_candidato_eleccion(eleccion_id, "12345".to_owned());
+                sistema.aprobar_candidato_eleccion(eleccion_id, "54321".to_owned());
+                sistema.aprobar_votante_eleccion(eleccion_id, "11111".to_owned());
+                sistema.aprobar_votante_eleccion(eleccion_id, "22222".to_owned());
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.descalificar_candidato_priv(1, "54321".to_owned(), "Fraude".to_string()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Ok(()),sistema.configurar_votos_de_bajas_priv(1, TratamientoVotos::Anular));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(()),sistema.votar_eleccion_priv(0, "12345".to_owned()));
+            assert_eq!(Ok(()),sistema.votar_eleccion_priv(1, "54321".to_owned()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // Con la votacion en curso el tratamiento ya no puede cambiarse
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionEnProcesoVotacion))),sistema.configurar_votos_de_bajas_priv(0, TratamientoVotos::Anular));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste))),sistema.retirar_candidatura_priv(0));
+            // Bob se retira de la eleccion 0, donde sus votos se conservan
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Ok(()),sistema.retirar_candidatura_priv(0));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoDadoDeBaja))),sistema.retirar_candidatura_priv(0));
+            // Eve es descalificada de la eleccion 1, donde sus votos se anulan
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Ok(()),sistema.descalificar_candidato_priv(1, "54321".to_owned(), "Fraude".to_string()));
+            let interfaz = sistema.clonar_elecciones_actuales_a_interfaz(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            assert_eq!(interfaz[0].candidatos_aprobados.len(), 1);
+            assert_eq!(interfaz[0].bajas_candidatos[0].estado, EstadoCandidato::Retirado);
+            assert_eq!(interfaz[1].bajas_candidatos[0].estado, EstadoCandidato::Descalificado("Fraude".to_string()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoDadoDeBaja))),sistema.votar_eleccion_priv(0, "12345".to_owned()));
+            assert_eq!(Ok(()),sistema.votar_eleccion_priv(1, "12345".to_owned()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:13,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionCerrada))),sistema.descalificar_candidato_priv(0, "54321".to_owned(), "Fraude".to_string()));
+            // Bob conserva su voto pero no puede ganar, y eve no recibio votos
+            assert_eq!(Ok(ResultadoEleccion::SinVotos),sistema.finalizar_y_contar_eleccion_priv(0));
+            assert_eq!(Ok(ResultadoEleccion::Ganador(CandidatoVotos{candidato_nombre:"bob".to_string(), candidato_dni:"**345".to_string(), votos_recaudados:1, votos_ponderados:1, estado:EstadoCandidato::Activo})),sistema.finalizar_y_contar_eleccion_priv(1));
+            let eleccion_anulada = &sistema.elecciones_finiquitadas[1];
+            assert_eq!(eleccion_anulada.get_votos_anulados(), 1);
+            assert!(eleccion_anulada.get_eleccion_votos().iter().all(|c| c.estado == EstadoCandidato::Activo || c.votos_recaudados == 0));
+        }
+
+        #[allow(unused)]
+        #[ink::test]
+        fn test_cancelar_peticiones()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoExisteUsuario)),sistema.cancelar_peticion_sistema_priv());
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "1234".to_string());
+            // Bob se equivoco de DNI, cancela y se vuelve a registrar
+            assert_eq!(Ok(()),sistema.cancelar_peticion_sistema_priv());
+            assert_eq!(Ok(()),sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioYaRegistrado)),sistema.cancelar_peticion_sistema_priv());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::PeticionNoExiste))),sistema.cancelar_peticion_eleccion_priv(0));
+            // Bob se postulo como candidato por error, cancela y se registra como votante
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
+            sistema.editar_perfil_candidato_priv(0, "Partido A".to_string(), "Bio".to_string(), "https://a.org".to_string(), Hash::from([0x1; 32]));
+            assert_eq!(Ok(()),sistema.cancelar_peticion_eleccion_priv(0));
+            assert_eq!(sistema.elecciones[0].get_perfiles_candidatos(), Vec::new());
+            assert_eq!(Ok(()),sistema.registrarse_a_eleccion_priv(0, Rol::Votante));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_votante_eleccion(0, "12345".to_owned());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteActualmenteAprobado))),sistema.cancelar_peticion_eleccion_priv(0));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.get_historial_retiros_priv());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            let timestamp = Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp();
+            assert_eq!(Ok(vec![
+                RetiroPeticion::new(Usuario::new(accounts.bob, "bob".to_string(), "1234".to_string(), sistema.sal_dni), TipoPeticion::Sistema, timestamp),
+                RetiroPeticion::new(Usuario::new(accounts.bob, "bob".to_string(), "12345".to_string(), sistema.sal_dni), TipoPeticion::Eleccion(0, Rol::Candidato), timestamp),
+            ]),sistema.get_historial_retiros_priv());
         }
-    }
-
-
 
-    #[cfg(test)]
-    mod tests{
-
-        use super::*;
-
-        /////////////////TEST DEL SISTEMA (METODOS INK::MESSAGE)
+        #[allow(unused)]
         #[ink::test]
-        fn test_sistema_registrar_validar(){
+        fn test_cambio_perfil()
+        {
             let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
             let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AdminYaRegistrado)), sistema.registrarse_en_sistema_priv("julian".to_string(), "12345678".to_string()));
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            assert_eq!(Ok(()),sistema.registrarse_en_sistema("julian".to_string(), "12345678".to_string()));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioYaRegistradoEnPeticiones)),sistema.registrarse_en_sistema_priv("julian".to_string(), "12345678".to_string()));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.aprobar_usuario_sistema_priv(accounts.bob));
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
-            assert_eq!(Ok(()),sistema.aprobar_usuario_sistema_priv(accounts.bob));
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AccionUnicaDeUsuarios)),sistema.proponer_cambio_perfil_priv("tobi".to_string(), "43107333".to_string()));
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioYaRegistrado)),sistema.registrarse_en_sistema_priv("julian".to_string(), "12345678".to_string()));
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_candidato_eleccion(0, "12345".to_owned());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Ok(()),sistema.proponer_cambio_perfil_priv("bob".to_string(), "99999".to_string()));
+            assert_eq!(Ok(()),sistema.proponer_cambio_perfil_priv("roberto".to_string(), "54321".to_string()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(()),sistema.proponer_cambio_perfil_priv("alicia".to_string(), "11111".to_string()));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.aprobar_cambio_perfil_priv(accounts.alice));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            let nuevo_bob = Usuario::new(accounts.bob, "roberto".to_string(), "54321".to_string(), sistema.sal_dni);
+            assert_eq!(Ok(vec![nuevo_bob.clone(), Usuario::new(accounts.alice, "alicia".to_string(), "11111".to_string(), sistema.sal_dni)]),sistema.get_peticiones_cambio_perfil_priv());
+            assert_eq!(Ok(()),sistema.aprobar_cambio_perfil_priv(accounts.bob));
+            assert_eq!(Ok(()),sistema.rechazar_cambio_perfil_priv(accounts.alice));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::CambioPerfilNoExiste)),sistema.rechazar_cambio_perfil_priv(accounts.alice));
+            assert_eq!(Ok(Vec::new()),sistema.get_peticiones_cambio_perfil_priv());
+            // Los cambios aprobados se propagan al sistema y a las elecciones activas
+            assert!(sistema.usuarios_registados.contains(&nuevo_bob));
+            assert!(sistema.usuarios_registados.contains(&Usuario::new(accounts.alice, "alice".to_string(), "11111".to_string(), sistema.sal_dni)));
+            assert_eq!(sistema.elecciones[0].candidatos_aprobados, vec![nuevo_bob]);
+            assert_eq!(sistema.elecciones[0].votos[0].candidato_dni, "**321".to_string());
         }
 
         #[allow(unused)]
         #[ink::test]
-        fn test_sistema_obtener_peticiones()
+        fn test_dni_unico()
         {
             let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
-            let mut sistema=SistemaVotacion::new("tobais".to_string(), "43107333".to_string());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::DniYaRegistrado)),sistema.registrarse_en_sistema_priv("bob".to_string(), "43107333".to_string()));
+            assert_eq!(Ok(()),sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string()));
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            sistema.registrarse_en_sistema_priv("alice".to_string(), "22222".to_string());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::DniYaRegistrado)),sistema.registrarse_en_sistema_priv("alice".to_string(), "12345".to_string()));
+            assert_eq!(Ok(()),sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::DniYaRegistrado)),sistema.proponer_cambio_perfil_priv("alice".to_string(), "12345".to_string()));
+            // Un dni liberado al cancelar la peticion puede volver a usarse
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
-            sistema.registrarse_en_sistema_priv("charlie".to_string(), "33333".to_string());
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.get_peticiones_de_registro_sistema_priv());
+            sistema.registrarse_en_sistema_priv("charlie".to_string(), "22222".to_string());
+            sistema.cancelar_peticion_sistema_priv();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            assert_eq!(Ok(()),sistema.registrarse_en_sistema_priv("eve".to_string(), "22222".to_string()));
+            // Bob pierde su clave y el admin reasigna su dni a la cuenta de frank
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.reasignar_dni_priv("12345".to_string(), accounts.frank));
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
-            assert_eq!(Ok(sistema.peticiones_registro.clone()),sistema.get_peticiones_de_registro_sistema_priv());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoExisteUsuario)),sistema.reasignar_dni_priv("99999".to_string(), accounts.frank));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AccionUnicaDeUsuarios)),sistema.reasignar_dni_priv("43107333".to_string(), accounts.frank));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioYaRegistrado)),sistema.reasignar_dni_priv("12345".to_string(), accounts.alice));
+            assert_eq!(Ok(()),sistema.reasignar_dni_priv("12345".to_string(), accounts.frank));
+            assert!(sistema.usuarios_registados.contains(&Usuario::new(accounts.frank, "bob".to_string(), "12345".to_string(), sistema.sal_dni)));
+            assert!(!sistema.existe_usuario_registrado_en_sistema(accounts.bob));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::DniYaRegistrado)),sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string()));
         }
 
+        #[allow(unused)]
         #[ink::test]
-        fn test_delegar_admin()
+        fn test_migrar_cuenta()
         {
             let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
             let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
-            assert_eq!(Ok(()),sistema.delegar_admin_priv(accounts.bob, "bob".to_string(), "12345".to_string()));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.delegar_admin_priv(accounts.bob, "bob".to_string(), "12345".to_string()));
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
+            sistema.crear_nueva_eleccion_priv("Rey".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            assert_eq!(Ok(()),sistema.delegar_admin_priv(accounts.django, "tobias".to_string(), "43107333".to_string()));
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
+            sistema.registrarse_a_eleccion_priv(1, Rol::Candidato);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
+            sistema.registrarse_a_eleccion_priv(1, Rol::Votante);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_candidato_eleccion(0, "12345".to_owned());
+            sistema.aprobar_candidato_eleccion(1, "12345".to_owned());
+            sistema.aprobar_votante_eleccion(0, "11111".to_owned());
+            sistema.aprobar_votante_eleccion(1, "11111".to_owned());
+            sistema.asignar_peso_votante_priv(1, "11111".to_owned(), 3);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(()),sistema.votar_eleccion_priv(0, "12345".to_owned()));
+            // Alice pierde su clave y el admin migra su identidad a la cuenta de frank
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AccionUnicaDeUsuarios)),sistema.migrar_cuenta_usuario_priv(accounts.django, accounts.frank));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoExisteUsuario)),sistema.migrar_cuenta_usuario_priv(accounts.eve, accounts.frank));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioYaRegistrado)),sistema.migrar_cuenta_usuario_priv(accounts.alice, accounts.bob));
+            assert_eq!(Ok(()),sistema.migrar_cuenta_usuario_priv(accounts.alice, accounts.frank));
+            assert_eq!(sistema.elecciones[1].get_peso_votante(accounts.frank), 3);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoExisteUsuario)),sistema.votar_eleccion_priv(1, "12345".to_owned()));
+            // La nueva cuenta no puede votar de nuevo donde la anterior ya voto
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.frank);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::VotanteYaVoto)),sistema.votar_eleccion_priv(0, "12345".to_owned()));
+            assert_eq!(Ok(()),sistema.votar_eleccion_priv(1, "12345".to_owned()));
+            assert_eq!(sistema.elecciones[0].votantes_votados, vec![Usuario::new(accounts.frank, "alice".to_string(), "11111".to_string(), sistema.sal_dni)]);
         }
 
-        /////////////////TEST ELECCIONES (METODOS INK::MESSAGE)
+        #[allow(unused)]
         #[ink::test]
-        fn test_crear_eleccion()
+        fn test_suspender_y_eliminar_usuarios()
         {
             let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
             let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaInicioInvalida(ErrorFecha::DiaInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 0, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaInicioInvalida(ErrorFecha::MesInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 14, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaInicioInvalida(ErrorFecha::HoraInvalida))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 60, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaInicioInvalida(ErrorFecha::MinInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 70, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaInicioInvalida(ErrorFecha::SegInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 30, seg: 99 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaCierreInvalida(ErrorFecha::DiaInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 32, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaCierreInvalida(ErrorFecha::MesInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 14, año: 2001, hora: 20, min: 30, seg: 00 }));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaCierreInvalida(ErrorFecha::HoraInvalida))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 60, min: 30, seg: 00 }));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaCierreInvalida(ErrorFecha::MinInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 70, seg: 00 }));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaCierreInvalida(ErrorFecha::SegInvalido))),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 99 }));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaInicioPasada)),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 1, año: 1600, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::FechaCierreAntesInicio)),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 1, mes: 1, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2000, hora: 20, min: 30, seg: 00 }));
-            assert_eq!(Ok(()),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }));
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }));
-            sistema.elecciones_conteo_id= 18446744073709551615;
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_en_sistema_priv("charlie".to_string(), "22222".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            sistema.aprobar_usuario_sistema(accounts.charlie);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_candidato_eleccion(0, "12345".to_owned());
+            sistema.aprobar_votante_eleccion(0, "11111".to_owned());
+            // Suspension y reactivacion
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AccionUnicaDeUsuarios)),sistema.suspender_usuario_priv(accounts.django));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoExisteUsuario)),sistema.suspender_usuario_priv(accounts.eve));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioNoSuspendido)),sistema.reactivar_usuario_priv(accounts.alice));
+            assert_eq!(Ok(()),sistema.suspender_usuario_priv(accounts.alice));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioSuspendido)),sistema.suspender_usuario_priv(accounts.alice));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioSuspendido)),sistema.votar_eleccion_priv(0, "12345".to_owned()));
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::RepresentacionLimiteAlcanzada)),sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }));
+            assert_eq!(Ok(()),sistema.reactivar_usuario_priv(accounts.alice));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(()),sistema.votar_eleccion_priv(0, "12345".to_owned()));
+            // La eliminacion quita las peticiones pendientes pero conserva los votantes que ya votaron
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Ok(()),sistema.eliminar_usuario_priv(accounts.charlie));
+            assert_eq!(Ok(()),sistema.eliminar_usuario_priv(accounts.alice));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoExisteUsuario)),sistema.eliminar_usuario_priv(accounts.alice));
+            assert!(sistema.elecciones[0].peticiones_votantes.is_empty());
+            assert_eq!(sistema.elecciones[0].votantes_votados, vec![Usuario::new(accounts.alice, "alice".to_string(), "11111".to_string(), sistema.sal_dni)]);
+            assert!(!sistema.existe_usuario_registrado_en_sistema(accounts.alice));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            assert_eq!(Ok(()),sistema.registrarse_en_sistema_priv("eve".to_string(), "22222".to_string()));
         }
 
+        #[allow(unused)]
         #[ink::test]
-        fn test_setters_y_getters_elecciones(){
-            let mut elec = Eleccion::new(0, String::from("Un cargo"), Timestamp::default(), Timestamp::default(), Fecha::new(1, 1, 1, 1, 1, 1), Fecha::new(1, 1, 1, 1, 1, 1));
-            //Settear votantes aprobados
-            elec.set_votantes_aprobados(vec![Usuario::new(AccountId::from([0x1; 32]), "Pepe".to_owned(), "111".to_owned())]);
-            assert_eq!(elec.get_votantes_aprobados(), vec![Usuario::new(AccountId::from([0x1; 32]), "Pepe".to_owned(), "111".to_owned())]);
-            //Settear votantes registrados
-            elec.set_votantes_registrados(vec![Usuario::new(AccountId::from([0x1; 32]), "Pepe".to_owned(), "111".to_owned())]);
-            assert_eq!(elec.get_votantes_registrados(), vec![Usuario::new(AccountId::from([0x1; 32]), "Pepe".to_owned(), "111".to_owned())]);
+        fn test_registro_con_atestacion()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            // Firma del atestador sobre (bob, "12345", "bob")
+            let atestador: [u8; 33] = [2, 187, 80, 226, 216, 154, 78, 215, 6, 99, 208, 128, 101, 159, 224, 173, 75, 155, 195, 224, 108, 23, 162, 39, 67, 57, 102, 203, 89, 206, 238, 2, 13];
+            let firma: [u8; 65] = [88, 118, 71, 222, 239, 18, 170, 69, 206, 195, 165, 249, 25, 57, 140, 113, 17, 136, 9, 126, 125, 51, 226, 246, 132, 131, 7, 36, 77, 250, 154, 181, 94, 17, 114, 156, 113, 233, 47, 48, 231, 110, 25, 1, 93, 128, 191, 99, 62, 189, 209, 224, 122, 214, 93, 53, 101, 22, 20, 60, 120, 18, 65, 116, 1];
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.agregar_atestador_priv(atestador));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AtestacionInvalida)),sistema.registrarse_en_sistema_con_atestacion_priv("bob".to_string(), "12345".to_string(), firma));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Ok(()),sistema.agregar_atestador_priv(atestador));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AtestadorYaRegistrado)),sistema.agregar_atestador_priv(atestador));
+            // La firma no sirve para otros datos ni para otra cuenta
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AtestacionInvalida)),sistema.registrarse_en_sistema_con_atestacion_priv("bob".to_string(), "99999".to_string(), firma));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AtestacionInvalida)),sistema.registrarse_en_sistema_con_atestacion_priv("bob".to_string(), "12345".to_string(), firma));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Ok(()),sistema.registrarse_en_sistema_con_atestacion_priv("bob".to_string(), "12345".to_string(), firma));
+            assert!(sistema.existe_usuario_registrado_en_sistema(accounts.bob));
+            assert!(sistema.peticiones_registro.is_empty());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Ok(()),sistema.quitar_atestador_priv(atestador));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AtestadorNoExiste)),sistema.quitar_atestador_priv(atestador));
         }
 
         #[allow(unused)]
         #[ink::test]
-        fn test_get_elecciones()
+        fn test_dni_hasheado_y_enmascarado()
         {
             let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
             let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
-            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 });
-            assert_eq!(Ok(sistema.clonar_elecciones_actuales_a_interfaz(0)),sistema.get_elecciones_actuales_priv());
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
             sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioNoAprobado)),sistema.get_elecciones_actuales_priv());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
-            sistema.aprobar_usuario_sistema_priv(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            // Solo se guardan el hash con sal y el dni enmascarado
+            assert_eq!(sistema.usuarios_registados[0].get_dni_visible(), "*****333".to_string());
+            assert_eq!(sistema.usuarios_registados[1].dni_hash, calcular_hash_dni("12345", &sistema.sal_dni));
+            assert_eq!(sistema.usuarios_registados[1].get_dni_visible(), "**345".to_string());
+            assert_eq!(enmascarar_dni("12"), "12".to_string());
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            assert_eq!(Ok(sistema.clonar_elecciones_actuales_a_interfaz(0)),sistema.get_elecciones_actuales_priv());
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoExisteUsuario)),sistema.get_elecciones_actuales_priv());
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste))),sistema.aprobar_candidato_eleccion_priv(0, "**345".to_string()));
+            assert_eq!(Ok(()),sistema.aprobar_candidato_eleccion_priv(0, "12345".to_string()));
+            assert_eq!(Ok(()),sistema.aprobar_votante_eleccion_priv(0, "11111".to_string()));
+            assert_eq!(sistema.elecciones[0].votos[0].candidato_dni, "**345".to_string());
+            // Opt-in del candidato para mostrar su dni completo
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste))),sistema.publicar_dni_candidato_priv(0, "11111".to_string()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::DniIncorrecto)),sistema.publicar_dni_candidato_priv(0, "54321".to_string()));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::NoExisteEleccion))),sistema.publicar_dni_candidato_priv(1, "12345".to_string()));
+            assert_eq!(Ok(()),sistema.publicar_dni_candidato_priv(0, "12345".to_string()));
+            assert_eq!(sistema.elecciones[0].votos[0].candidato_dni, "12345".to_string());
+            assert_eq!(sistema.elecciones[0].candidatos_aprobados[0].get_dni_visible(), "12345".to_string());
+            assert_eq!(sistema.usuarios_registados[1].get_dni_visible(), "**345".to_string());
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(()),sistema.votar_eleccion_priv(0, "12345".to_string()));
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:13,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
-            sistema.finalizar_y_contar_eleccion_priv(0);
-            assert_eq!(Ok(sistema.clonar_elecciones_historicas_a_interfaz()),sistema.get_elecciones_historial_priv());
-            assert_eq!(sistema.elecciones_finiquitadas,sistema.get_elecciones_finiquitadas_priv());
-            
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionCerrada))),sistema.publicar_dni_candidato_priv(0, "12345".to_string()));
         }
 
         #[allow(unused)]
         #[ink::test]
-        fn test_registrarse_eleccion()
+        fn test_identificar_por_cuenta_y_numero()
         {
             let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
             let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
-            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 });
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AccionUnicaDeUsuarios)),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato));
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoExisteUsuario)),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato));
             sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioNoAprobado)),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato));
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
-            sistema.aprobar_usuario_sistema(accounts.bob);
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            assert_eq!(Ok(()),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.get_candidatos_pendientes_priv(0));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            sistema.registrarse_en_sistema_priv("eve".to_string(), "54321".to_string());
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoExisteUsuario)),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante));
             sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioNoAprobado)),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_en_sistema_priv("charlie".to_string(), "22222".to_string());
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
-            assert_eq!(Ok(vec![Usuario::new(accounts.bob,"bob".to_string(),"12345".to_string())]),sistema.get_candidatos_pendientes_priv(0));
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.eve);
             sistema.aprobar_usuario_sistema(accounts.alice);
+            sistema.aprobar_usuario_sistema(accounts.charlie);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::NoExisteEleccion))),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id, Rol::Votante));
-            assert_eq!(Ok(()),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.get_votantes_pendientes_priv(0));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteEnPendiente))),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante));
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.aprobar_candidato_eleccion_por_cuenta_priv(0, accounts.bob));
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
-            assert_eq!(Ok(vec![Usuario::new(accounts.alice,"alice".to_string(),"11111".to_string())]),sistema.get_votantes_pendientes_priv(0));
+            assert_eq!(Ok(()),sistema.aprobar_candidato_eleccion_por_cuenta_priv(0, accounts.bob));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoActualmenteAprobado))),sistema.aprobar_candidato_eleccion_por_cuenta_priv(0, accounts.bob));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste))),sistema.aprobar_candidato_eleccion_por_cuenta_priv(0, accounts.frank));
+            assert_eq!(Ok(()),sistema.aprobar_candidato_eleccion_priv(0, "54321".to_string()));
+            assert_eq!(Ok(()),sistema.aprobar_votante_eleccion_por_cuenta_priv(0, accounts.alice));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteActualmenteAprobado))),sistema.aprobar_votante_eleccion_por_cuenta_priv(0, accounts.alice));
+            assert_eq!(Ok(()),sistema.aprobar_votante_eleccion_por_cuenta_priv(0, accounts.charlie));
+            let interfaz = EleccionInterfaz::from_eleccion(EstadoEleccion::PeriodoInscripcion, sistema.elecciones[0].clone(), false);
+            assert_eq!(interfaz.numeros_candidatos, vec![1, 2]);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste))),sistema.votar_eleccion_por_numero_priv(0, 0));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste))),sistema.votar_eleccion_por_numero_priv(0, 3));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste))),sistema.votar_eleccion_por_cuenta_priv(0, accounts.charlie));
+            assert_eq!(Ok(()),sistema.votar_eleccion_por_numero_priv(0, 2));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.retirar_candidatura_priv(0);
+            let interfaz = EleccionInterfaz::from_eleccion(EstadoEleccion::PeriodoVotacion, sistema.elecciones[0].clone(), false);
+            assert_eq!(interfaz.numeros_candidatos, vec![2]);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoDadoDeBaja))),sistema.votar_eleccion_por_numero_priv(0, 1));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoDadoDeBaja))),sistema.votar_eleccion_por_cuenta_priv(0, accounts.bob));
+            assert_eq!(Ok(()),sistema.votar_eleccion_por_cuenta_priv(0, accounts.eve));
+            assert_eq!(sistema.elecciones[0].votos[1].votos_recaudados, 2);
         }
 
         #[allow(unused)]
         #[ink::test]
-        fn test_aprobar_votante_candidato_en_eleccion()
+        fn test_plantillas_eleccion()
         {
             let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
             let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
-            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 });
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
             sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_en_sistema_priv("charlie".to_string(), "22222".to_string());
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
             sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            sistema.aprobar_usuario_sistema(accounts.charlie);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::DuracionInvalida)),sistema.crear_plantilla_eleccion_priv("Emperador".to_string(), 0, MetodoVotacion::Candidatos, None, vec![accounts.alice]));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::QuorumInvalido)),sistema.crear_plantilla_eleccion_priv("Emperador".to_string(), 86400, MetodoVotacion::Candidatos, Some(Quorum::Porcentaje(150)), vec![accounts.alice]));
+            // El padron incluye al admin y a una cuenta que no es usuario, que se ignoran al crear la eleccion
+            assert_eq!(Ok(0),sistema.crear_plantilla_eleccion_priv("Emperador".to_string(), 86400, MetodoVotacion::Candidatos, Some(Quorum::Absoluto(1)), vec![accounts.alice, accounts.django, accounts.frank]));
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.crear_plantilla_eleccion_priv("Rey".to_string(), 86400, MetodoVotacion::Candidatos, None, Vec::new()));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.get_plantillas_eleccion_priv());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.crear_eleccion_desde_plantilla_priv(0, Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, false));
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
-            assert_eq!(Ok(()),sistema.aprobar_candidato_eleccion(sistema.elecciones_conteo_id-1, "12345".to_owned()));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoActualmenteAprobado))),sistema.aprobar_candidato_eleccion(sistema.elecciones_conteo_id-1, "12345".to_owned()));
+            assert_eq!(Ok(vec![PlantillaEleccion::new(0, "Emperador".to_string(), 86400, MetodoVotacion::Candidatos, Some(Quorum::Absoluto(1)), vec![accounts.alice, accounts.django, accounts.frank])]),sistema.get_plantillas_eleccion_priv());
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::PlantillaNoExiste)),sistema.crear_eleccion_desde_plantilla_priv(1, Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, false));
+            assert_eq!(Ok(()),sistema.crear_eleccion_desde_plantilla_priv(0, Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, true));
+            assert_eq!(sistema.elecciones[0].fecha_cierre_interfaz, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 });
+            assert_eq!(sistema.elecciones[0].cargo, "Emperador".to_string());
+            assert_eq!(sistema.elecciones[0].quorum, Some(Quorum::Absoluto(1)));
+            assert_eq!(sistema.elecciones[0].get_plantilla_id(), Some(0));
+            assert_eq!(sistema.elecciones[0].votantes_aprobados, vec![Usuario::new(accounts.alice, "alice".to_string(), "11111".to_string(), sistema.sal_dni)]);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoActualmenteAprobado))),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante));
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_votante_eleccion_priv(0, "22222".to_string());
+            sistema.aprobar_candidato_eleccion_priv(0, "12345".to_string());
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.votar_eleccion_priv(0, "12345".to_string());
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:13,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.finalizar_y_contar_eleccion_priv(0);
+            // Alice, del padron de la plantilla, y charlie, que solo se arrastra de la edicion anterior, migran de cuenta despues del cierre
+            let cuenta_charlie = AccountId::from([0x7; 32]);
+            assert_eq!(Ok(()),sistema.migrar_cuenta_usuario_priv(accounts.alice, accounts.eve));
+            assert_eq!(Ok(()),sistema.migrar_cuenta_usuario_priv(accounts.charlie, cuenta_charlie));
+            assert_eq!(Ok(vec![PlantillaEleccion::new(0, "Emperador".to_string(), 86400, MetodoVotacion::Candidatos, Some(Quorum::Absoluto(1)), vec![accounts.eve, accounts.django, accounts.frank])]),sistema.get_plantillas_eleccion_priv());
+            assert_eq!(sistema.elecciones_finiquitadas[0].votantes_aprobados[0].account_id, accounts.alice);
+            // La siguiente edicion arrastra a los votantes aprobados de la anterior con sus cuentas nuevas, sin repetirlos
+            assert_eq!(Ok(()),sistema.crear_eleccion_desde_plantilla_priv(0, Fecha { dia: 29, mes: 2, año: 2004, hora: 20, min: 30, seg: 00 }, true));
+            assert_eq!(sistema.elecciones[0].fecha_cierre_interfaz, Fecha { dia: 1, mes: 3, año: 2004, hora: 20, min: 30, seg: 00 });
+            assert_eq!(sistema.elecciones[0].votantes_aprobados.iter().map(|v| v.account_id).collect::<Vec<AccountId>>(), vec![accounts.eve, cuenta_charlie]);
+            assert_eq!(Ok(()),sistema.crear_eleccion_desde_plantilla_priv(0, Fecha { dia: 12, mes: 10, año: 2004, hora: 20, min: 30, seg: 00 }, false));
+            assert_eq!(sistema.elecciones[1].votantes_aprobados.iter().map(|v| v.account_id).collect::<Vec<AccountId>>(), vec![accounts.eve]);
+            assert_eq!(Fecha::from_timestamp(Fecha { dia: 31, mes: 12, año: 1999, hora: 23, min: 59, seg: 59 }.to_timestamp()), Fecha { dia: 31, mes: 12, año: 1999, hora: 23, min: 59, seg: 59 });
+        }
+
+        #[allow(unused)]
+        #[ink::test]
+        fn test_evento_elecciones()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            sistema.registrarse_en_sistema_priv("eve".to_string(), "54321".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_en_sistema_priv("charlie".to_string(), "22222".to_string());
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.eve);
             sistema.aprobar_usuario_sistema(accounts.alice);
+            sistema.aprobar_usuario_sistema(accounts.charlie);
+            let cargos = vec!["Presidente".to_string(), "Tesorero".to_string()];
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::EventoSinElecciones)),sistema.crear_evento_elecciones_priv("Asamblea".to_string(), Vec::new(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+            assert_eq!(Ok(0),sistema.crear_evento_elecciones_priv("Asamblea".to_string(), cargos.clone(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
+            assert_eq!(sistema.get_eventos_elecciones(), vec![EventoElecciones::new(0, "Asamblea".to_string(), vec![0, 1])]);
+            assert_eq!(sistema.elecciones[1].cargo, "Tesorero".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.crear_evento_elecciones_priv("Asamblea".to_string(), cargos, Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None));
+            sistema.registrarse_a_eleccion_priv(1, Rol::Candidato);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
+            // Un candidato tambien entra al padron del evento, solo no vota en la eleccion en la que compite
+            assert_eq!(Ok(()),sistema.registrarse_a_evento_priv(0));
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::EventoNoExiste)),sistema.registrarse_a_evento_priv(1));
+            assert_eq!(Ok(()),sistema.registrarse_a_evento_priv(0));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteEnPendiente))),sistema.registrarse_a_evento_priv(0));
+            assert_eq!(sistema.get_eventos_elecciones()[0].get_peticiones_votantes().len(), 2);
+            assert!(sistema.elecciones[1].peticiones_votantes.is_empty());
+            // Los votantes de una eleccion del evento no se registran ni se aprueban por separado
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::EleccionDeEvento)),sistema.registrarse_a_eleccion_priv(0, Rol::Votante));
+            assert_eq!(Ok(()),sistema.registrarse_a_eleccion_priv(2, Rol::Votante));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.aprobar_votante_evento_priv(0, accounts.alice));
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
-            assert_eq!(Ok(()),sistema.aprobar_votante_eleccion(sistema.elecciones_conteo_id-1, "11111".to_owned()));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::EleccionDeEvento)),sistema.aprobar_votante_eleccion_por_cuenta_priv(0, accounts.alice));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoExiste))),sistema.aprobar_votante_evento_priv(0, accounts.charlie));
+            assert_eq!(Ok(()),sistema.aprobar_votante_evento_priv(0, accounts.alice));
+            assert_eq!(Ok(()),sistema.aprobar_votante_evento_priv(0, accounts.eve));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteActualmenteAprobado))),sistema.aprobar_votante_evento_priv(0, accounts.alice));
+            assert_eq!(sistema.get_eventos_elecciones()[0].get_votantes_aprobados().iter().map(|v| v.account_id).collect::<Vec<AccountId>>(), vec![accounts.alice, accounts.eve]);
+            assert!(sistema.elecciones[0].votantes_aprobados.is_empty());
+            sistema.aprobar_candidato_eleccion_por_cuenta_priv(0, accounts.eve);
+            sistema.aprobar_candidato_eleccion_por_cuenta_priv(1, accounts.bob);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteActualmenteAprobado))),sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante));
-            
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::EleccionFueraDeEvento)),sistema.votar_evento_priv(0, vec![VotoEvento::new(0, 1), VotoEvento::new(2, 1)]));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::EleccionRepetida)),sistema.votar_evento_priv(0, vec![VotoEvento::new(0, 1), VotoEvento::new(0, 1)]));
+            // Si un voto es invalido no se registra ninguno
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoExiste))),sistema.votar_evento_priv(0, vec![VotoEvento::new(0, 1), VotoEvento::new(1, 5)]));
+            assert_eq!(sistema.elecciones[0].votos[0].votos_recaudados, 0);
+            assert_eq!(Ok(()),sistema.votar_evento_priv(0, vec![VotoEvento::new(0, 1), VotoEvento::new(1, 1)]));
+            assert_eq!(sistema.elecciones[0].votos[0].votos_recaudados, 1);
+            assert_eq!(sistema.elecciones[1].votos[0].votos_recaudados, 1);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::VotanteYaVoto)),sistema.votar_evento_priv(0, vec![VotoEvento::new(1, 1)]));
+            // Eve compite en la eleccion 0, pero vota en la otra eleccion del evento
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::CandidatoNoPuedeVotar))),sistema.votar_evento_priv(0, vec![VotoEvento::new(0, 1), VotoEvento::new(1, 1)]));
+            assert_eq!(Ok(()),sistema.votar_evento_priv(0, vec![VotoEvento::new(1, 1)]));
+            assert_eq!(sistema.elecciones[1].votos[0].votos_recaudados, 2);
+            // Al contar, cada eleccion registra su electorado: el padron del evento sin sus candidatos
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:13,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.finalizar_y_contar_eleccion_priv(0);
+            assert_eq!(sistema.elecciones_finiquitadas[0].votantes_aprobados.iter().map(|v| v.account_id).collect::<Vec<AccountId>>(), vec![accounts.alice]);
         }
 
         #[allow(unused)]
         #[ink::test]
-        fn test_votar_finalizar_eleccion()
+        fn test_historial_participacion()
         {
             let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
             let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
-            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 });
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
             sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            // Eve se registra con el mismo nombre y el mismo dni enmascarado que bob, el ganador se distingue por su cuenta
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "99345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_en_sistema_priv("charlie".to_string(), "22222".to_string());
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
             sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.eve);
+            sistema.aprobar_usuario_sistema(accounts.alice);
+            sistema.aprobar_usuario_sistema(accounts.charlie);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Candidato);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
-            sistema.aprobar_candidato_eleccion(sistema.elecciones_conteo_id-1, "12345".to_owned());
+            sistema.aprobar_candidato_eleccion_por_cuenta_priv(0, accounts.bob);
+            sistema.aprobar_candidato_eleccion_por_cuenta_priv(0, accounts.eve);
+            sistema.aprobar_votante_eleccion_por_cuenta_priv(0, accounts.alice);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(vec![ParticipacionUsuario::new(0, "Emperador".to_string(), Rol::Votante, EstadoParticipacion::Aprobado, ResultadoParticipacion::EnCurso)]),sistema.get_historial_participacion_priv(accounts.alice));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            sistema.votar_eleccion_por_cuenta_priv(0, accounts.eve);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:13,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.finalizar_y_contar_eleccion_priv(0);
+            // Cada usuario ve su propio historial
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(vec![ParticipacionUsuario::new(0, "Emperador".to_string(), Rol::Votante, EstadoParticipacion::Voto, ResultadoParticipacion::Finalizada)]),sistema.get_historial_participacion_priv(accounts.alice));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            assert_eq!(Ok(vec![ParticipacionUsuario::new(0, "Emperador".to_string(), Rol::Candidato, EstadoParticipacion::Aprobado, ResultadoParticipacion::Electo)]),sistema.get_historial_participacion_priv(accounts.eve));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Ok(vec![ParticipacionUsuario::new(0, "Emperador".to_string(), Rol::Candidato, EstadoParticipacion::Aprobado, ResultadoParticipacion::NoElecto)]),sistema.get_historial_participacion_priv(accounts.bob));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.frank);
+            assert_eq!(Ok(Vec::new()),sistema.get_historial_participacion_priv(accounts.frank));
+            // Solo el admin y los auditores ven el historial de otros usuarios
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.get_historial_participacion_priv(accounts.alice));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.agregar_auditor_priv(accounts.charlie));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert!(sistema.get_historial_participacion_priv(accounts.alice).is_ok());
+            assert_eq!(Ok(()),sistema.agregar_auditor_priv(accounts.charlie));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AuditorYaRegistrado)),sistema.agregar_auditor_priv(accounts.charlie));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(Ok(vec![ParticipacionUsuario::new(0, "Emperador".to_string(), Rol::Candidato, EstadoParticipacion::Aprobado, ResultadoParticipacion::NoElecto)]),sistema.get_historial_participacion_priv(accounts.bob));
+            assert_eq!(Ok(vec![ParticipacionUsuario::new(0, "Emperador".to_string(), Rol::Votante, EstadoParticipacion::Solicitado, ResultadoParticipacion::Finalizada)]),sistema.get_historial_participacion_priv(accounts.charlie));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Ok(()),sistema.quitar_auditor_priv(accounts.charlie));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::AuditorNoExiste)),sistema.quitar_auditor_priv(accounts.charlie));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::NoSePoseenPermisos)),sistema.get_historial_participacion_priv(accounts.bob));
+        }
+
+        #[allow(unused)]
+        #[ink::test]
+        fn test_estadisticas_sistema()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            sistema.crear_nueva_eleccion_priv("Emperador".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
+            sistema.crear_nueva_eleccion_priv("Virrey".to_string(), Fecha { dia: 12, mes: 10, año: 2002, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2002, hora: 20, min: 30, seg: 00 }, None);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            sistema.registrarse_en_sistema_priv("eve".to_string(), "54321".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_en_sistema_priv("charlie".to_string(), "22222".to_string());
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.eve);
             sistema.aprobar_usuario_sistema(accounts.alice);
+            assert_eq!(Ok(EstadisticasSistema::new(4, 1, 2, 0, 0, 0, 0, 0)),sistema.get_estadisticas_sistema_priv());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioNoAprobado)),sistema.get_estadisticas_sistema_priv());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Candidato);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            sistema.registrarse_a_eleccion_priv(sistema.elecciones_conteo_id-1, Rol::Votante);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
-            sistema.aprobar_votante_eleccion(sistema.elecciones_conteo_id-1, "11111".to_owned());
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionEnProcesoInscripcion))),sistema.votar_eleccion_priv(sistema.elecciones_conteo_id-1, "12345".to_owned()));
+            sistema.aprobar_candidato_eleccion_por_cuenta_priv(0, accounts.bob);
+            sistema.aprobar_votante_eleccion_por_cuenta_priv(0, accounts.eve);
+            sistema.aprobar_votante_eleccion_por_cuenta_priv(0, accounts.alice);
+            // Los votos se cuentan al emitirse
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
-            assert_eq!(Ok(()),sistema.votar_eleccion_priv(sistema.elecciones_conteo_id-1, "12345".to_owned()));
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::VotanteYaVoto)),sistema.votar_eleccion_priv(sistema.elecciones_conteo_id-1, "12345".to_owned()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            sistema.votar_eleccion_por_cuenta_priv(0, accounts.bob);
+            assert_eq!(Ok(EstadisticasSistema::new(4, 1, 1, 1, 0, 0, 1, 0)),sistema.get_estadisticas_sistema_priv());
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:13,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::EleccionCerrada))),sistema.votar_eleccion_priv(sistema.elecciones_conteo_id-1, "12345".to_owned()));
+            assert_eq!(Ok(EstadisticasSistema::new(4, 1, 1, 0, 1, 0, 1, 0)),sistema.get_estadisticas_sistema_priv());
+            // Al finalizar se acumula la participacion de la eleccion, un votante de dos
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.finalizar_y_contar_eleccion_priv(0);
+            assert_eq!(Ok(EstadisticasSistema::new(4, 1, 1, 0, 0, 1, 1, 50)),sistema.get_estadisticas_sistema_priv());
+        }
+
+        #[allow(unused)]
+        #[ink::test]
+        fn test_eleccion_por_listas()
+        {
+            let accounts=ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:1,mes:1,año:2000,hora:00,min:00,seg:00}.to_timestamp());
+            let mut sistema=SistemaVotacion::new("tobias".to_string(), "43107333".to_string());
+            sistema.crear_nueva_eleccion_por_listas_priv("Comision".to_string(), Fecha { dia: 12, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, Fecha { dia: 13, mes: 10, año: 2001, hora: 20, min: 30, seg: 00 }, None);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrarse_en_sistema_priv("bob".to_string(), "12345".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrarse_en_sistema_priv("charlie".to_string(), "22222".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_en_sistema_priv("alice".to_string(), "11111".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            sistema.registrarse_en_sistema_priv("eve".to_string(), "33333".to_string());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            sistema.aprobar_usuario_sistema(accounts.bob);
+            sistema.aprobar_usuario_sistema(accounts.charlie);
+            sistema.aprobar_usuario_sistema(accounts.alice);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::MetodoVotacionIncorrecto))),sistema.registrarse_a_eleccion_priv(0, Rol::Candidato));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::UsuarioNoAprobado)),sistema.registrar_lista_priv(0, "Lista Azul".to_string(), vec![accounts.eve]));
+            assert_eq!(Ok(()),sistema.registrar_lista_priv(0, "Lista Azul".to_string(), vec![accounts.charlie]));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::ListaYaExiste))),sistema.registrar_lista_priv(0, "Lista Azul".to_string(), Vec::new()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::MiembroDeLista))),sistema.registrarse_a_eleccion_priv(0, Rol::Votante));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrarse_a_eleccion_priv(0, Rol::Votante);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            let mut lista = Lista::new("Lista Azul".to_string(), accounts.bob);
+            lista.miembros = vec![Usuario::new(accounts.bob, "bob".to_string(), "12345".to_string(), sistema.sal_dni), Usuario::new(accounts.charlie, "charlie".to_string(), "22222".to_string(), sistema.sal_dni)];
+            assert_eq!(Ok(vec![lista.clone()]),sistema.get_listas_pendientes_priv(0));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::ListaNoExiste))),sistema.aprobar_lista_eleccion_priv(0, "Lista Roja".to_string()));
+            assert_eq!(Ok(()),sistema.aprobar_lista_eleccion_priv(0, "Lista Azul".to_string()));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::ListaActualmenteAprobada))),sistema.aprobar_lista_eleccion_priv(0, "Lista Azul".to_string()));
+            assert_eq!(sistema.clonar_elecciones_actuales_a_interfaz(0)[0].listas_aprobadas, vec![lista]);
+            sistema.aprobar_votante_eleccion(0, "11111".to_owned());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:12,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
-            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::VotanteNoExiste))),sistema.votar_eleccion_priv(sistema.elecciones_conteo_id-1, "12345".to_owned()));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::ErrorDeEleccion(ErrorEleccion::ListaNoExiste))),sistema.votar_lista_priv(0, "Lista Roja".to_string()));
+            assert_eq!(Ok(()),sistema.votar_lista_priv(0, "Lista Azul".to_string()));
+            assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::VotanteYaVoto)),sistema.votar_lista_priv(0, "Lista Azul".to_string()));
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(Fecha{dia:13,mes:10,año:2001,hora:21,min:00,seg:00}.to_timestamp());
-            assert_eq!(Ok(CandidatoVotos{candidato_nombre:"bob".to_string(), candidato_dni:"12345".to_string(), votos_recaudados:1}),sistema.finalizar_y_contar_eleccion_priv(0));
-            // assert_eq!(Ok(vec![Usuario::new(accounts.alice,"alice".to_string(),"11111".to_string())]),sistema.get_elecciones_terminadas_x(0));
-            // assert_eq!(Err(ErrorInterfaz::new(ErrorSistema::EleccionInvalida)),sistema.get_elecciones_terminadas_x(4));
+            assert_eq!(Ok(ResultadoEleccion::ListaGanadora(ListaVotos{lista_nombre:"Lista Azul".to_string(), votos_recaudados:1, votos_ponderados:1})),sistema.finalizar_y_contar_eleccion_priv(0));
         }
     }
 